@@ -0,0 +1,178 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on merkle proof length, enough for 2^32 recipients
+pub const MAX_PROOF_LENGTH: usize = 32;
+
+/// Airdrop Smart Contract
+/// Pays out season-end airdrops computed off-chain. Each round is a merkle root over
+/// `(recipient, amount)` leaves plus the tokens funding it; recipients claim their amount with
+/// a proof until the round expires, after which the owner claws back what is left.
+///
+/// A leaf is `keccak256(recipient ++ amount)`, with the 32-byte address followed by the
+/// amount's big-endian bytes. Parents hash their two children in ascending byte order, so
+/// proofs need no left/right flags.
+#[multiversx_sc::contract]
+pub trait Airdrop {
+    #[init]
+    fn init(&self) {}
+
+    /// Open a round for `merkle_root`, funded by the payment and claimable until `expiry_block`
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createRound)]
+    fn create_round(&self, merkle_root: ManagedByteArray<32>, expiry_block: u64) -> u32 {
+        require!(
+            expiry_block > self.blockchain().get_block_nonce(),
+            "Expiry must be in the future"
+        );
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0, "Only fungible tokens accepted");
+        require!(amount > 0, "Must fund the round");
+
+        let round_id = self.last_round_id().get() + 1;
+        let round = Round {
+            merkle_root,
+            token,
+            funded: amount.clone(),
+            remaining: amount,
+            expiry_block,
+            clawed_back: false,
+        };
+        self.rounds(round_id).set(&round);
+        self.last_round_id().set(round_id);
+
+        self.round_created_event(round_id, &round.token, &round.funded, expiry_block);
+
+        round_id
+    }
+
+    /// Claim `amount` from a round, proven by the sibling hashes from the caller's leaf up
+    #[endpoint(claim)]
+    fn claim(&self, round_id: u32, amount: BigUint, proof: MultiValueEncoded<ManagedByteArray<32>>) {
+        let mut round = self.existing_round(round_id);
+        require!(
+            self.blockchain().get_block_nonce() < round.expiry_block,
+            "Round expired"
+        );
+        require!(proof.len() <= MAX_PROOF_LENGTH, "Proof too long");
+
+        let caller = self.blockchain().get_caller();
+        let claimed_mapper = self.claimed(round_id, &caller);
+        require!(!claimed_mapper.get(), "Already claimed");
+
+        let mut leaf_data = caller.as_managed_buffer().clone();
+        leaf_data.append(&amount.to_bytes_be_buffer());
+        let mut hash = self.crypto().keccak256(&leaf_data);
+        for sibling in proof {
+            hash = self.hash_pair(&hash, &sibling);
+        }
+        require!(hash == round.merkle_root, "Invalid proof");
+        require!(amount <= round.remaining, "Round underfunded");
+
+        claimed_mapper.set(true);
+        round.remaining -= &amount;
+        self.rounds(round_id).set(&round);
+
+        self.send().direct(&caller, &round.token, 0, &amount);
+
+        self.claimed_event(round_id, &caller, &amount);
+    }
+
+    /// Return the unclaimed remainder of an expired round to the owner
+    #[only_owner]
+    #[endpoint(clawback)]
+    fn clawback(&self, round_id: u32) {
+        let mut round = self.existing_round(round_id);
+        require!(
+            self.blockchain().get_block_nonce() >= round.expiry_block,
+            "Round not expired"
+        );
+        require!(!round.clawed_back, "Already clawed back");
+
+        let remaining = core::mem::replace(&mut round.remaining, BigUint::zero());
+        round.clawed_back = true;
+        self.rounds(round_id).set(&round);
+
+        if remaining > 0 {
+            self.send()
+                .direct(&self.blockchain().get_caller(), &round.token, 0, &remaining);
+        }
+
+        self.clawed_back_event(round_id, &remaining);
+    }
+
+    // View functions
+    #[view(getRound)]
+    fn get_round(&self, round_id: u32) -> OptionalValue<Round<Self::Api>> {
+        if self.rounds(round_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.rounds(round_id).get())
+    }
+
+    #[view(getLastRoundId)]
+    fn get_last_round_id(&self) -> u32 {
+        self.last_round_id().get()
+    }
+
+    #[view(hasClaimed)]
+    fn has_claimed(&self, round_id: u32, recipient: ManagedAddress) -> bool {
+        self.claimed(round_id, &recipient).get()
+    }
+
+    // Private functions
+    fn existing_round(&self, round_id: u32) -> Round<Self::Api> {
+        require!(!self.rounds(round_id).is_empty(), "Round does not exist");
+        self.rounds(round_id).get()
+    }
+
+    fn hash_pair(&self, left: &ManagedByteArray<32>, right: &ManagedByteArray<32>) -> ManagedByteArray<32> {
+        let (first, second) = if left.to_byte_array() <= right.to_byte_array() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let mut data = first.as_managed_buffer().clone();
+        data.append(second.as_managed_buffer());
+        self.crypto().keccak256(&data)
+    }
+
+    // Storage
+    #[storage_mapper("last_round_id")]
+    fn last_round_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("rounds")]
+    fn rounds(&self, round_id: u32) -> SingleValueMapper<Round<Self::Api>>;
+
+    #[storage_mapper("claimed")]
+    fn claimed(&self, round_id: u32, recipient: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    // Events
+    #[event("round_created")]
+    fn round_created_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        funded: &BigUint,
+        expiry_block: u64,
+    );
+
+    #[event("claimed")]
+    fn claimed_event(&self, #[indexed] round_id: u32, #[indexed] recipient: &ManagedAddress, amount: &BigUint);
+
+    #[event("clawed_back")]
+    fn clawed_back_event(&self, #[indexed] round_id: u32, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Round<M: ManagedTypeApi> {
+    pub merkle_root: ManagedByteArray<M, 32>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub funded: BigUint<M>,
+    pub remaining: BigUint<M>,
+    pub expiry_block: u64,
+    pub clawed_back: bool,
+}
@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+
+/// Endpoints of the suite's gov-token contract used in mint/burn mode
+#[multiversx_sc::proxy]
+pub trait GovToken {
+    #[endpoint(mint)]
+    fn mint(&self, recipient: ManagedAddress, amount: BigUint);
+
+    #[payable("*")]
+    #[endpoint(burn)]
+    fn burn(&self);
+}
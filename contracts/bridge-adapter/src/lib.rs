@@ -0,0 +1,288 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod gov_token_proxy;
+
+/// Bridge Adapter Smart Contract
+/// Moves the suite's governance token between MultiversX and other chains through the
+/// MultiversX bridge. Outgoing transfers are deposited here and stay pending until a bridge
+/// relayer confirms or refunds them; incoming transfers are paid out by relayers, once per
+/// source transaction. In lock/release mode deposits stay locked and incoming transfers are
+/// paid from them; in mint/burn mode deposits are burned and incoming transfers minted through
+/// the gov-token contract, which must list this adapter as a minter.
+#[multiversx_sc::contract]
+pub trait BridgeAdapter {
+    #[init]
+    fn init(&self, token: TokenIdentifier, mode: BridgeMode, gov_token: ManagedAddress) {
+        require!(token.is_valid_esdt_identifier(), "Invalid token");
+        if mode == BridgeMode::MintBurn {
+            require!(self.blockchain().is_smart_contract(&gov_token), "Gov token must be a contract");
+        }
+        self.token().set(&token);
+        self.mode().set(mode);
+        self.gov_token().set(&gov_token);
+    }
+
+    #[only_owner]
+    #[endpoint(addRelayer)]
+    fn add_relayer(&self, relayer: ManagedAddress) {
+        require!(self.relayers().insert(relayer.clone()), "Already a relayer");
+
+        self.relayer_added_event(&relayer);
+    }
+
+    #[only_owner]
+    #[endpoint(removeRelayer)]
+    fn remove_relayer(&self, relayer: ManagedAddress) {
+        require!(self.relayers().swap_remove(&relayer), "Not a relayer");
+
+        self.relayer_removed_event(&relayer);
+    }
+
+    /// Send the tokens paid with the call to `recipient` on `to_chain`
+    #[payable("*")]
+    #[endpoint(deposit)]
+    fn deposit(&self, to_chain: ManagedBuffer, recipient: ManagedBuffer) -> u64 {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.token().get() && payment.token_nonce == 0,
+            "Wrong token"
+        );
+        require!(payment.amount > 0, "Must send tokens");
+        require!(!to_chain.is_empty() && !recipient.is_empty(), "Destination required");
+
+        let transfer_id = self.last_transfer_id().get() + 1;
+        let transfer = OutgoingTransfer {
+            id: transfer_id,
+            sender: self.blockchain().get_caller(),
+            to_chain,
+            recipient,
+            amount: payment.amount,
+            block: self.blockchain().get_block_nonce(),
+            status: TransferStatus::Pending,
+        };
+        self.outgoing_transfers(transfer_id).set(&transfer);
+        self.pending_transfers().insert(transfer_id);
+        self.last_transfer_id().set(transfer_id);
+        self.locked().update(|locked| *locked += &transfer.amount);
+
+        self.deposited_event(
+            transfer_id,
+            &transfer.sender,
+            &transfer.to_chain,
+            &transfer.recipient,
+            &transfer.amount,
+        );
+
+        transfer_id
+    }
+
+    /// Mark an outgoing transfer delivered on the destination chain. In mint/burn mode its
+    /// tokens are burned now that the wrapped supply exists elsewhere.
+    #[endpoint(confirmTransfer)]
+    fn confirm_transfer(&self, transfer_id: u64) {
+        self.require_relayer();
+        let mut transfer = self.pending_transfer(transfer_id);
+
+        transfer.status = TransferStatus::Completed;
+        self.outgoing_transfers(transfer_id).set(&transfer);
+        self.pending_transfers().swap_remove(&transfer_id);
+
+        if self.mode().get() == BridgeMode::MintBurn {
+            self.locked().update(|locked| *locked -= &transfer.amount);
+            self.gov_token_proxy(self.gov_token().get())
+                .burn()
+                .with_esdt_transfer((self.token().get(), 0, transfer.amount.clone()))
+                .execute_on_dest_context::<()>();
+        }
+
+        self.transfer_confirmed_event(transfer_id);
+    }
+
+    /// Return the tokens of an outgoing transfer the bridge could not deliver
+    #[endpoint(refundTransfer)]
+    fn refund_transfer(&self, transfer_id: u64) {
+        self.require_relayer();
+        let mut transfer = self.pending_transfer(transfer_id);
+
+        transfer.status = TransferStatus::Refunded;
+        self.outgoing_transfers(transfer_id).set(&transfer);
+        self.pending_transfers().swap_remove(&transfer_id);
+        self.locked().update(|locked| *locked -= &transfer.amount);
+
+        self.send()
+            .direct_esdt(&transfer.sender, &self.token().get(), 0, &transfer.amount);
+
+        self.transfer_refunded_event(transfer_id);
+    }
+
+    /// Pay out an incoming transfer identified by its transaction hash on the source chain
+    #[endpoint(completeIncoming)]
+    fn complete_incoming(&self, source_tx_hash: ManagedBuffer, recipient: ManagedAddress, amount: BigUint) {
+        self.require_relayer();
+        require!(amount > 0, "Amount must be positive");
+        require!(
+            self.processed_incoming().insert(source_tx_hash.clone()),
+            "Transfer already processed"
+        );
+
+        match self.mode().get() {
+            BridgeMode::LockRelease => {
+                let locked_mapper = self.locked();
+                require!(amount <= locked_mapper.get(), "Not enough locked tokens");
+                locked_mapper.update(|locked| *locked -= &amount);
+
+                self.send()
+                    .direct_esdt(&recipient, &self.token().get(), 0, &amount);
+            },
+            BridgeMode::MintBurn => {
+                self.gov_token_proxy(self.gov_token().get())
+                    .mint(recipient.clone(), amount.clone())
+                    .execute_on_dest_context::<()>();
+            },
+        }
+
+        self.incoming_completed_event(&source_tx_hash, &recipient, &amount);
+    }
+
+    // View functions
+    #[view(getTransfer)]
+    fn get_transfer(&self, transfer_id: u64) -> OptionalValue<OutgoingTransfer<Self::Api>> {
+        if self.outgoing_transfers(transfer_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.outgoing_transfers(transfer_id).get())
+    }
+
+    #[view(getPendingTransfers)]
+    fn get_pending_transfers(&self) -> MultiValueEncoded<u64> {
+        self.pending_transfers().iter().collect()
+    }
+
+    #[view(isIncomingProcessed)]
+    fn is_incoming_processed(&self, source_tx_hash: ManagedBuffer) -> bool {
+        self.processed_incoming().contains(&source_tx_hash)
+    }
+
+    /// Tokens held by the adapter: pending deposits, plus in lock/release mode every token
+    /// currently bridged out
+    #[view(getLocked)]
+    fn get_locked(&self) -> BigUint {
+        self.locked().get()
+    }
+
+    #[view(getRelayers)]
+    fn get_relayers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.relayers().iter().collect()
+    }
+
+    #[view(getMode)]
+    fn get_mode(&self) -> BridgeMode {
+        self.mode().get()
+    }
+
+    // Private functions
+    fn require_relayer(&self) {
+        require!(
+            self.relayers().contains(&self.blockchain().get_caller()),
+            "Only relayers"
+        );
+    }
+
+    fn pending_transfer(&self, transfer_id: u64) -> OutgoingTransfer<Self::Api> {
+        require!(self.pending_transfers().contains(&transfer_id), "Transfer not pending");
+        self.outgoing_transfers(transfer_id).get()
+    }
+
+    // Storage
+    #[storage_mapper("token")]
+    fn token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("mode")]
+    fn mode(&self) -> SingleValueMapper<BridgeMode>;
+
+    #[storage_mapper("gov_token")]
+    fn gov_token(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("relayers")]
+    fn relayers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("locked")]
+    fn locked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("last_transfer_id")]
+    fn last_transfer_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("outgoing_transfers")]
+    fn outgoing_transfers(&self, transfer_id: u64) -> SingleValueMapper<OutgoingTransfer<Self::Api>>;
+
+    #[storage_mapper("pending_transfers")]
+    fn pending_transfers(&self) -> UnorderedSetMapper<u64>;
+
+    /// Source chain transaction hashes of incoming transfers already paid out
+    #[storage_mapper("processed_incoming")]
+    fn processed_incoming(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[proxy]
+    fn gov_token_proxy(&self, sc_address: ManagedAddress) -> gov_token_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("relayer_added")]
+    fn relayer_added_event(&self, #[indexed] relayer: &ManagedAddress);
+
+    #[event("relayer_removed")]
+    fn relayer_removed_event(&self, #[indexed] relayer: &ManagedAddress);
+
+    #[event("deposited")]
+    fn deposited_event(
+        &self,
+        #[indexed] transfer_id: u64,
+        #[indexed] sender: &ManagedAddress,
+        #[indexed] to_chain: &ManagedBuffer,
+        #[indexed] recipient: &ManagedBuffer,
+        amount: &BigUint,
+    );
+
+    #[event("transfer_confirmed")]
+    fn transfer_confirmed_event(&self, #[indexed] transfer_id: u64);
+
+    #[event("transfer_refunded")]
+    fn transfer_refunded_event(&self, #[indexed] transfer_id: u64);
+
+    #[event("incoming_completed")]
+    fn incoming_completed_event(
+        &self,
+        #[indexed] source_tx_hash: &ManagedBuffer,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BridgeMode {
+    /// The token is native here: bridged-out tokens stay locked and back incoming transfers
+    LockRelease,
+    /// Bridged-out tokens are burned and incoming transfers minted through gov-token
+    MintBurn,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferStatus {
+    Pending,
+    Completed,
+    Refunded,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct OutgoingTransfer<M: ManagedTypeApi> {
+    pub id: u64,
+    pub sender: ManagedAddress<M>,
+    pub to_chain: ManagedBuffer<M>,
+    /// Address on the destination chain, in that chain's format
+    pub recipient: ManagedBuffer<M>,
+    pub amount: BigUint<M>,
+    pub block: u64,
+    pub status: TransferStatus,
+}
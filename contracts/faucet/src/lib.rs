@@ -0,0 +1,197 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on captcha hashes added per call
+pub const MAX_CAPTCHA_HASHES_PER_CALL: usize = 100;
+
+/// Faucet Smart Contract
+/// Hands out small amounts of test EGLD and ESDTs on devnet and testnet, so playtesters can
+/// join games without manual token distribution. Each address can claim each token once per
+/// cooldown. Optionally every claim must also present a captcha solution whose keccak256 hash
+/// the owner's captcha backend registered beforehand.
+#[multiversx_sc::contract]
+pub trait Faucet {
+    #[init]
+    fn init(&self, cooldown_blocks: u64) {
+        self.cooldown_blocks().set(cooldown_blocks);
+    }
+
+    #[only_owner]
+    #[endpoint(setCooldown)]
+    fn set_cooldown(&self, cooldown_blocks: u64) {
+        self.cooldown_blocks().set(cooldown_blocks);
+    }
+
+    /// Dispense `amount` of `token` per claim
+    #[only_owner]
+    #[endpoint(setDrip)]
+    fn set_drip(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        require!(token.is_valid(), "Invalid token");
+        require!(amount > 0, "Amount must be positive");
+        self.tokens().insert(token.clone());
+        self.drip_amount(&token).set(&amount);
+
+        self.drip_set_event(&token, &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(removeDrip)]
+    fn remove_drip(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(self.tokens().swap_remove(&token), "Token not dispensed");
+        self.drip_amount(&token).clear();
+    }
+
+    #[only_owner]
+    #[endpoint(setCaptchaRequired)]
+    fn set_captcha_required(&self, required: bool) {
+        self.captcha_required().set(required);
+    }
+
+    /// Register keccak256 hashes of captcha solutions; each one admits a single claim
+    #[only_owner]
+    #[endpoint(addCaptchaHashes)]
+    fn add_captcha_hashes(&self, hashes: MultiValueEncoded<ManagedByteArray<32>>) {
+        require!(
+            hashes.len() <= MAX_CAPTCHA_HASHES_PER_CALL,
+            "Too many hashes in one call"
+        );
+        for hash in hashes {
+            self.captcha_hashes().insert(hash);
+        }
+    }
+
+    /// Top up the faucet with EGLD or a fungible ESDT
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(refill)]
+    fn refill(&self) {
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0, "Only fungible tokens accepted");
+        require!(amount > 0, "Must send tokens");
+
+        self.refilled_event(&token, &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(withdraw)]
+    fn withdraw(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        require!(amount <= self.balance(&token), "Insufficient balance");
+
+        self.send()
+            .direct(&self.blockchain().get_caller(), &token, 0, &amount);
+    }
+
+    /// Receive one drip of `token`. `captcha` is the solution issued by the captcha backend,
+    /// required only while captcha gating is on.
+    #[endpoint(claim)]
+    fn claim(&self, token: EgldOrEsdtTokenIdentifier, captcha: OptionalValue<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Contracts cannot claim");
+        require!(self.tokens().contains(&token), "Token not dispensed");
+
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block >= self.get_next_claim_block(caller.clone(), token.clone()),
+            "Cooldown not elapsed"
+        );
+
+        if self.captcha_required().get() {
+            let captcha = match captcha {
+                OptionalValue::Some(captcha) => captcha,
+                OptionalValue::None => sc_panic!("Captcha required"),
+            };
+            let hash = self.crypto().keccak256(&captcha);
+            require!(self.captcha_hashes().swap_remove(&hash), "Invalid captcha");
+        }
+
+        let amount = self.drip_amount(&token).get();
+        require!(amount <= self.balance(&token), "Faucet empty, try again later");
+        self.last_claim_block(&caller, &token).set(current_block);
+
+        self.send().direct(&caller, &token, 0, &amount);
+
+        self.claimed_event(&caller, &token, &amount);
+    }
+
+    // View functions
+    #[view(getDrips)]
+    fn get_drips(&self) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.tokens().iter() {
+            let amount = self.drip_amount(&token).get();
+            result.push((token, amount).into());
+        }
+        result
+    }
+
+    /// First block `address` can claim `token` again; 0 if it never claimed it
+    #[view(getNextClaimBlock)]
+    fn get_next_claim_block(&self, address: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> u64 {
+        let last_claim_mapper = self.last_claim_block(&address, &token);
+        if last_claim_mapper.is_empty() {
+            return 0;
+        }
+
+        last_claim_mapper.get() + self.cooldown_blocks().get()
+    }
+
+    #[view(getCooldown)]
+    fn get_cooldown(&self) -> u64 {
+        self.cooldown_blocks().get()
+    }
+
+    #[view(isCaptchaRequired)]
+    fn is_captcha_required(&self) -> bool {
+        self.captcha_required().get()
+    }
+
+    #[view(getCaptchaHashCount)]
+    fn get_captcha_hash_count(&self) -> usize {
+        self.captcha_hashes().len()
+    }
+
+    // Private functions
+    fn balance(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.blockchain().get_sc_balance(token, 0)
+    }
+
+    // Storage
+    #[storage_mapper("cooldown_blocks")]
+    fn cooldown_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("tokens")]
+    fn tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("drip_amount")]
+    fn drip_amount(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("last_claim_block")]
+    fn last_claim_block(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("captcha_required")]
+    fn captcha_required(&self) -> SingleValueMapper<bool>;
+
+    /// Unused captcha solution hashes
+    #[storage_mapper("captcha_hashes")]
+    fn captcha_hashes(&self) -> UnorderedSetMapper<ManagedByteArray<32>>;
+
+    // Events
+    #[event("drip_set")]
+    fn drip_set_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier, amount: &BigUint);
+
+    #[event("refilled")]
+    fn refilled_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier, amount: &BigUint);
+
+    #[event("claimed")]
+    fn claimed_event(
+        &self,
+        #[indexed] claimer: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,5 @@
+use multiversx_sc_meta::*;
+
+fn main() {
+    multiversx_sc_meta::cli_main::<game_factory::AbiProvider>();
+}
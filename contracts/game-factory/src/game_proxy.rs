@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Mirrors `quantum_dao::types::FeeConfig`; field order must match for the encodings to agree
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct FeeConfig<M: ManagedTypeApi> {
+    pub proposal_fee: BigUint<M>,
+    pub prediction_fee_bps: u64,
+    pub betting_fee_bps: u64,
+    pub booster_prize_pool_share_bps: u64,
+    pub min_boost_amount: BigUint<M>,
+}
+
+/// Endpoints of the quantum-dao game used to deploy and configure new instances
+#[multiversx_sc::proxy]
+pub trait Game {
+    #[init]
+    fn init(&self, game_duration_blocks: u64, nft_reward_token_id: TokenIdentifier);
+
+    #[endpoint(setFeeConfig)]
+    fn set_fee_config(&self, config: FeeConfig<Self::Api>);
+
+    #[endpoint(setSuperpositionMode)]
+    fn set_superposition_mode(&self, enabled: bool, reveal_duration_blocks: u64);
+
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress);
+}
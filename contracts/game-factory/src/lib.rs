@@ -0,0 +1,219 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_proxy;
+pub mod registry_proxy;
+
+use game_proxy::FeeConfig;
+
+/// Upper bound on stored templates, keeping `getTemplateNames` cheap
+pub const MAX_TEMPLATES: usize = 20;
+pub const MAX_BPS: u64 = 10_000;
+/// Same cap the game applies in `setFeeConfig`
+pub const MAX_FEE_BPS: u64 = 2_000;
+/// Registry name the game source is resolved under
+pub const REGISTRY_GAME_SOURCE_NAME: &[u8] = b"quantum-dao";
+
+/// Game Factory Smart Contract
+/// Deploys quantum-dao game instances from a source contract, configured by named templates
+/// such as a "weekend blitz" or a "month-long marathon"
+#[multiversx_sc::contract]
+pub trait GameFactory {
+    #[init]
+    fn init(&self, game_source: ManagedAddress) {
+        self.set_game_source(game_source);
+    }
+
+    /// Deployed game whose code new instances are copied from
+    #[only_owner]
+    #[endpoint(setGameSource)]
+    fn set_game_source(&self, game_source: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game_source), "Source must be a contract");
+        self.game_source().set(&game_source);
+    }
+
+    #[only_owner]
+    #[endpoint(setRegistry)]
+    fn set_registry(&self, registry: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&registry), "Registry must be a contract");
+        self.registry_address().set(&registry);
+    }
+
+    /// Replace the game source with the one currently registered in the suite registry,
+    /// so newly deployed games pick up upgrades published there
+    #[endpoint(syncGameSource)]
+    fn sync_game_source(&self) {
+        require!(!self.registry_address().is_empty(), "Registry not configured");
+
+        let registry = self.registry_address().get();
+        self.registry_proxy(registry)
+            .get_address(ManagedBuffer::from(REGISTRY_GAME_SOURCE_NAME))
+            .async_call()
+            .with_callback(self.callbacks().game_source_sync_callback())
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn game_source_sync_callback(&self, #[call_result] result: ManagedAsyncCallResult<ManagedAddress>) {
+        if let ManagedAsyncCallResult::Ok(game_source) = result {
+            self.game_source().set(&game_source);
+            self.game_source_synced_event(&game_source);
+        }
+    }
+
+    /// Create or overwrite a named template
+    #[only_owner]
+    #[endpoint(setTemplate)]
+    fn set_template(&self, name: ManagedBuffer, template: GameTemplate<Self::Api>) {
+        require!(!name.is_empty(), "Template name required");
+        require!(template.duration_blocks > 0, "Duration must be positive");
+        require!(template.reward_token_id.is_valid_esdt_identifier(), "Invalid reward token");
+        require!(
+            template.voting_mode == VotingMode::Open || template.reveal_duration_blocks > 0,
+            "Reveal window must be positive"
+        );
+        require!(
+            template.fees.prediction_fee_bps <= MAX_FEE_BPS
+                && template.fees.betting_fee_bps <= MAX_FEE_BPS
+                && template.fees.booster_prize_pool_share_bps <= MAX_BPS,
+            "Fee too high"
+        );
+
+        if !self.template_names().contains(&name) {
+            require!(self.template_names().len() < MAX_TEMPLATES, "Too many templates");
+            self.template_names().insert(name.clone());
+        }
+        self.templates(&name).set(&template);
+
+        self.template_set_event(&name, &template);
+    }
+
+    #[only_owner]
+    #[endpoint(removeTemplate)]
+    fn remove_template(&self, name: ManagedBuffer) {
+        require!(self.template_names().swap_remove(&name), "Template does not exist");
+        self.templates(&name).clear();
+
+        self.template_removed_event(&name);
+    }
+
+    /// Deploy a game configured by `template_name`. The factory hands ownership to the caller,
+    /// who completes it by calling `acceptOwnership` on the new game.
+    #[endpoint(deployFromTemplate)]
+    fn deploy_from_template(&self, template_name: ManagedBuffer) -> ManagedAddress {
+        require!(self.template_names().contains(&template_name), "Template does not exist");
+        require!(!self.game_source().is_empty(), "Game source not set");
+
+        let template = self.templates(&template_name).get();
+        let (game, ()) = self
+            .game_proxy()
+            .init(template.duration_blocks, template.reward_token_id.clone())
+            .deploy_from_source(
+                &self.game_source().get(),
+                CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE_BY_SC,
+            );
+
+        self.game_proxy()
+            .contract(game.clone())
+            .set_fee_config(template.fees)
+            .execute_on_dest_context::<()>();
+
+        if template.voting_mode == VotingMode::Superposition {
+            self.game_proxy()
+                .contract(game.clone())
+                .set_superposition_mode(true, template.reveal_duration_blocks)
+                .execute_on_dest_context::<()>();
+        }
+
+        let caller = self.blockchain().get_caller();
+        self.game_proxy()
+            .contract(game.clone())
+            .transfer_ownership(caller.clone())
+            .execute_on_dest_context::<()>();
+
+        self.deployed_games().push(&game);
+
+        self.game_deployed_event(&game, &caller, &template_name);
+
+        game
+    }
+
+    // View functions
+    #[view(getTemplate)]
+    fn get_template(&self, name: ManagedBuffer) -> OptionalValue<GameTemplate<Self::Api>> {
+        if !self.template_names().contains(&name) {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.templates(&name).get())
+    }
+
+    #[view(getTemplateNames)]
+    fn get_template_names(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.template_names().iter().collect()
+    }
+
+    #[view(getDeployedGames)]
+    fn get_deployed_games(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.deployed_games().iter().collect()
+    }
+
+    // Storage
+    #[storage_mapper("game_source")]
+    fn game_source(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("registry_address")]
+    fn registry_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("template_names")]
+    fn template_names(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("templates")]
+    fn templates(&self, name: &ManagedBuffer) -> SingleValueMapper<GameTemplate<Self::Api>>;
+
+    #[storage_mapper("deployed_games")]
+    fn deployed_games(&self) -> VecMapper<ManagedAddress>;
+
+    #[proxy]
+    fn game_proxy(&self) -> game_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn registry_proxy(&self, sc_address: ManagedAddress) -> registry_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("game_source_synced")]
+    fn game_source_synced_event(&self, #[indexed] game_source: &ManagedAddress);
+
+    #[event("template_set")]
+    fn template_set_event(&self, #[indexed] name: &ManagedBuffer, template: &GameTemplate<Self::Api>);
+
+    #[event("template_removed")]
+    fn template_removed_event(&self, #[indexed] name: &ManagedBuffer);
+
+    #[event("game_deployed")]
+    fn game_deployed_event(
+        &self,
+        #[indexed] game: &ManagedAddress,
+        #[indexed] deployer: &ManagedAddress,
+        template_name: &ManagedBuffer,
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingMode {
+    /// Votes are public as soon as they are cast
+    Open,
+    /// Votes are committed and revealed after voting closes
+    Superposition,
+}
+
+/// Settings applied to every game deployed from a template
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GameTemplate<M: ManagedTypeApi> {
+    pub duration_blocks: u64,
+    pub reward_token_id: TokenIdentifier<M>,
+    pub fees: FeeConfig<M>,
+    pub voting_mode: VotingMode,
+    pub reveal_duration_blocks: u64,
+}
@@ -0,0 +1,175 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Gov Token Smart Contract
+/// Issues the suite's fungible governance token and holds its mint and burn roles. Minters
+/// (game instances, the treasury) mint rewards within their allowance, the total supply never
+/// exceeds the cap, and anyone can burn tokens they hold.
+#[multiversx_sc::contract]
+pub trait GovToken {
+    #[init]
+    fn init(&self, max_supply: BigUint) {
+        require!(max_supply > 0, "Max supply must be positive");
+        self.max_supply().set(&max_supply);
+    }
+
+    /// Issue the token with this contract holding the local mint and burn roles.
+    /// The payment covers the protocol issue cost.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueToken)]
+    fn issue_token(&self, display_name: ManagedBuffer, ticker: ManagedBuffer, num_decimals: usize) {
+        require!(self.token().is_empty(), "Token already issued");
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.token()
+            .issue_and_set_all_roles(issue_cost, display_name, ticker, num_decimals, None);
+    }
+
+    /// Let `minter` mint up to `allowance` more tokens, replacing any previous allowance
+    #[only_owner]
+    #[endpoint(setMinter)]
+    fn set_minter(&self, minter: ManagedAddress, allowance: BigUint) {
+        self.minters().insert(minter.clone());
+        self.mint_allowance(&minter).set(&allowance);
+
+        self.minter_set_event(&minter, &allowance);
+    }
+
+    #[only_owner]
+    #[endpoint(removeMinter)]
+    fn remove_minter(&self, minter: ManagedAddress) {
+        require!(self.minters().swap_remove(&minter), "Not a minter");
+        self.mint_allowance(&minter).clear();
+
+        self.minter_removed_event(&minter);
+    }
+
+    /// Lower or raise the cap; it can never drop below the tokens already in circulation
+    #[only_owner]
+    #[endpoint(setMaxSupply)]
+    fn set_max_supply(&self, max_supply: BigUint) {
+        require!(max_supply >= self.circulating_supply().get(), "Below circulating supply");
+        self.max_supply().set(&max_supply);
+
+        self.max_supply_set_event(&max_supply);
+    }
+
+    /// Mint `amount` to `recipient` out of the caller's allowance
+    #[endpoint(mint)]
+    fn mint(&self, recipient: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(self.minters().contains(&caller), "Not a minter");
+        require!(amount > 0, "Amount must be positive");
+        require!(!self.token().is_empty(), "Token not issued");
+
+        let allowance_mapper = self.mint_allowance(&caller);
+        let allowance = allowance_mapper.get();
+        require!(amount <= allowance, "Mint allowance exceeded");
+        let supply = self.circulating_supply().get() + &amount;
+        require!(supply <= self.max_supply().get(), "Max supply exceeded");
+
+        allowance_mapper.set(&(allowance - &amount));
+        self.circulating_supply().set(&supply);
+        self.total_minted().update(|total| *total += &amount);
+        self.token().mint_and_send(&recipient, amount.clone());
+
+        self.minted_event(&caller, &recipient, &amount);
+    }
+
+    /// Burn the governance tokens sent with the call
+    #[payable("*")]
+    #[endpoint(burn)]
+    fn burn(&self) {
+        let payment = self.token().require_same_token_single();
+        require!(payment.amount > 0, "Must send tokens");
+
+        self.token().burn(&payment.amount);
+        self.circulating_supply().update(|supply| *supply -= &payment.amount);
+        self.total_burned().update(|total| *total += &payment.amount);
+
+        self.burned_event(&self.blockchain().get_caller(), &payment.amount);
+    }
+
+    // View functions
+    #[view(getTokenId)]
+    fn get_token_id(&self) -> OptionalValue<TokenIdentifier> {
+        if self.token().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.token().get_token_id())
+    }
+
+    #[view(getSupply)]
+    fn get_supply(&self) -> TokenSupply<Self::Api> {
+        TokenSupply {
+            circulating: self.circulating_supply().get(),
+            max: self.max_supply().get(),
+            total_minted: self.total_minted().get(),
+            total_burned: self.total_burned().get(),
+        }
+    }
+
+    #[view(getMinters)]
+    fn get_minters(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for minter in self.minters().iter() {
+            let allowance = self.mint_allowance(&minter).get();
+            result.push((minter, allowance).into());
+        }
+        result
+    }
+
+    // Storage
+    #[storage_mapper("token")]
+    fn token(&self) -> FungibleTokenMapper<Self::Api>;
+
+    #[storage_mapper("max_supply")]
+    fn max_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("circulating_supply")]
+    fn circulating_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_minted")]
+    fn total_minted(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_burned")]
+    fn total_burned(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("minters")]
+    fn minters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("mint_allowance")]
+    fn mint_allowance(&self, minter: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Events
+    #[event("minter_set")]
+    fn minter_set_event(&self, #[indexed] minter: &ManagedAddress, allowance: &BigUint);
+
+    #[event("minter_removed")]
+    fn minter_removed_event(&self, #[indexed] minter: &ManagedAddress);
+
+    #[event("max_supply_set")]
+    fn max_supply_set_event(&self, max_supply: &BigUint);
+
+    #[event("minted")]
+    fn minted_event(
+        &self,
+        #[indexed] minter: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("burned")]
+    fn burned_event(&self, #[indexed] holder: &ManagedAddress, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct TokenSupply<M: ManagedTypeApi> {
+    pub circulating: BigUint<M>,
+    pub max: BigUint<M>,
+    pub total_minted: BigUint<M>,
+    pub total_burned: BigUint<M>,
+}
@@ -0,0 +1,24 @@
+multiversx_sc::imports!();
+
+/// Endpoints of a quantum-dao game that accept the guardian multisig once it is set as the
+/// game's admin multisig
+#[multiversx_sc::proxy]
+pub trait Game {
+    #[endpoint(pause)]
+    fn pause(&self);
+
+    #[endpoint(unpause)]
+    fn unpause(&self);
+
+    #[endpoint(vetoProposal)]
+    fn veto_proposal(&self, proposal_id: u32);
+
+    #[endpoint(initiateEmergencyWithdraw)]
+    fn initiate_emergency_withdraw(&self, recovery_address: ManagedAddress);
+
+    #[endpoint(cancelEmergencyWithdraw)]
+    fn cancel_emergency_withdraw(&self);
+
+    #[endpoint(executeEmergencyWithdraw)]
+    fn execute_emergency_withdraw(&self);
+}
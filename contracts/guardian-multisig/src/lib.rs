@@ -0,0 +1,277 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_proxy;
+
+/// Upper bound on actions in one batch
+pub const MAX_ACTIONS_PER_BATCH: usize = 10;
+/// Upper bound on signers, keeping approval counting cheap
+pub const MAX_SIGNERS: usize = 20;
+
+/// Guardian Multisig Smart Contract
+/// M-of-N signers approve batches of typed guardian actions on the suite's games: pausing,
+/// vetoing proposals, emergency withdrawals and upgrades, plus changes to the signer set itself.
+/// Games accept these calls once this contract is set as their admin multisig; upgrades need
+/// this contract to own the target.
+#[multiversx_sc::contract]
+pub trait GuardianMultisig {
+    #[init]
+    fn init(&self, quorum: usize, signers: MultiValueEncoded<ManagedAddress>) {
+        for signer in signers {
+            self.signers().insert(signer);
+        }
+        require!(self.signers().len() <= MAX_SIGNERS, "Too many signers");
+        self.set_quorum(quorum);
+    }
+
+    /// Propose a batch of actions executed together, in order. The proposer's approval is
+    /// counted. An upgrade can only be the last action, as it is given the remaining gas.
+    #[endpoint(proposeBatch)]
+    fn propose_batch(&self, actions: MultiValueEncoded<GuardianAction<Self::Api>>) -> u32 {
+        let caller = self.require_signer();
+        require!(!actions.is_empty(), "No actions");
+        require!(actions.len() <= MAX_ACTIONS_PER_BATCH, "Too many actions");
+
+        let batch_id = self.last_batch_id().get() + 1;
+        let action_count = actions.len();
+        for (index, action) in actions.into_iter().enumerate() {
+            if matches!(action, GuardianAction::Upgrade { .. }) {
+                require!(index == action_count - 1, "Upgrade must be the last action");
+            }
+            self.batch_actions(batch_id).push(&action);
+        }
+        self.batch_status(batch_id).set(BatchStatus::Pending);
+        self.approvals(batch_id).insert(caller.clone());
+        self.last_batch_id().set(batch_id);
+
+        self.batch_proposed_event(batch_id, &caller, action_count);
+
+        batch_id
+    }
+
+    #[endpoint(approve)]
+    fn approve(&self, batch_id: u32) {
+        let caller = self.require_signer();
+        self.require_pending(batch_id);
+        require!(self.approvals(batch_id).insert(caller.clone()), "Already approved");
+
+        self.batch_approved_event(batch_id, &caller);
+    }
+
+    #[endpoint(unapprove)]
+    fn unapprove(&self, batch_id: u32) {
+        let caller = self.require_signer();
+        self.require_pending(batch_id);
+        require!(self.approvals(batch_id).swap_remove(&caller), "Not approved");
+
+        self.batch_unapproved_event(batch_id, &caller);
+    }
+
+    /// Drop a pending batch nobody approves any more
+    #[endpoint(discardBatch)]
+    fn discard_batch(&self, batch_id: u32) {
+        self.require_signer();
+        self.require_pending(batch_id);
+        require!(self.valid_approval_count(batch_id) == 0, "Batch still approved");
+
+        self.batch_status(batch_id).set(BatchStatus::Discarded);
+
+        self.batch_discarded_event(batch_id);
+    }
+
+    /// Run every action of a batch approved by at least `quorum` current signers
+    #[endpoint(executeBatch)]
+    fn execute_batch(&self, batch_id: u32) {
+        self.require_signer();
+        self.require_pending(batch_id);
+        require!(
+            self.valid_approval_count(batch_id) >= self.quorum().get(),
+            "Quorum not reached"
+        );
+
+        self.batch_status(batch_id).set(BatchStatus::Executed);
+        for action in self.batch_actions(batch_id).iter() {
+            self.perform_action(action);
+        }
+
+        self.batch_executed_event(batch_id, &self.blockchain().get_caller());
+    }
+
+    // View functions
+    #[view(getSigners)]
+    fn get_signers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.signers().iter().collect()
+    }
+
+    #[view(getQuorum)]
+    fn get_quorum(&self) -> usize {
+        self.quorum().get()
+    }
+
+    #[view(getBatch)]
+    fn get_batch(&self, batch_id: u32) -> MultiValue2<BatchStatus, MultiValueEncoded<GuardianAction<Self::Api>>> {
+        require!(!self.batch_status(batch_id).is_empty(), "Batch does not exist");
+
+        let actions = self.batch_actions(batch_id).iter().collect();
+        (self.batch_status(batch_id).get(), actions).into()
+    }
+
+    /// Signers currently approving a batch; approvals of removed signers are not listed
+    #[view(getApprovals)]
+    fn get_approvals(&self, batch_id: u32) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for signer in self.approvals(batch_id).iter() {
+            if self.signers().contains(&signer) {
+                result.push(signer);
+            }
+        }
+        result
+    }
+
+    // Private functions
+    fn require_signer(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(self.signers().contains(&caller), "Only signers");
+        caller
+    }
+
+    fn require_pending(&self, batch_id: u32) {
+        require!(!self.batch_status(batch_id).is_empty(), "Batch does not exist");
+        require!(
+            self.batch_status(batch_id).get() == BatchStatus::Pending,
+            "Batch not pending"
+        );
+    }
+
+    /// Approvals by addresses that are still signers
+    fn valid_approval_count(&self, batch_id: u32) -> usize {
+        self.approvals(batch_id)
+            .iter()
+            .filter(|approver| self.signers().contains(approver))
+            .count()
+    }
+
+    fn set_quorum(&self, quorum: usize) {
+        require!(quorum > 0, "Quorum must be positive");
+        require!(quorum <= self.signers().len(), "Quorum exceeds signer count");
+        self.quorum().set(quorum);
+    }
+
+    fn perform_action(&self, action: GuardianAction<Self::Api>) {
+        match action {
+            GuardianAction::PauseGame { game } => {
+                self.game_proxy(game).pause().execute_on_dest_context::<()>();
+            },
+            GuardianAction::UnpauseGame { game } => {
+                self.game_proxy(game).unpause().execute_on_dest_context::<()>();
+            },
+            GuardianAction::VetoProposal { game, proposal_id } => {
+                self.game_proxy(game)
+                    .veto_proposal(proposal_id)
+                    .execute_on_dest_context::<()>();
+            },
+            GuardianAction::InitiateEmergencyWithdraw {
+                game,
+                recovery_address,
+            } => {
+                self.game_proxy(game)
+                    .initiate_emergency_withdraw(recovery_address)
+                    .execute_on_dest_context::<()>();
+            },
+            GuardianAction::CancelEmergencyWithdraw { game } => {
+                self.game_proxy(game)
+                    .cancel_emergency_withdraw()
+                    .execute_on_dest_context::<()>();
+            },
+            GuardianAction::ExecuteEmergencyWithdraw { game } => {
+                self.game_proxy(game)
+                    .execute_emergency_withdraw()
+                    .execute_on_dest_context::<()>();
+            },
+            GuardianAction::Upgrade { target, source } => {
+                self.send_raw().upgrade_from_source_contract(
+                    &target,
+                    self.blockchain().get_gas_left(),
+                    &BigUint::zero(),
+                    &source,
+                    CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE_BY_SC,
+                    &ManagedArgBuffer::new(),
+                );
+            },
+            GuardianAction::AddSigner { signer } => {
+                require!(self.signers().len() < MAX_SIGNERS, "Too many signers");
+                require!(self.signers().insert(signer), "Already a signer");
+            },
+            GuardianAction::RemoveSigner { signer } => {
+                require!(self.signers().swap_remove(&signer), "Not a signer");
+                require!(self.quorum().get() <= self.signers().len(), "Quorum exceeds signer count");
+            },
+            GuardianAction::SetQuorum { quorum } => {
+                self.set_quorum(quorum);
+            },
+        }
+    }
+
+    // Storage
+    #[storage_mapper("signers")]
+    fn signers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("last_batch_id")]
+    fn last_batch_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("batch_actions")]
+    fn batch_actions(&self, batch_id: u32) -> VecMapper<GuardianAction<Self::Api>>;
+
+    #[storage_mapper("batch_status")]
+    fn batch_status(&self, batch_id: u32) -> SingleValueMapper<BatchStatus>;
+
+    /// Signers that approved a batch, including any removed since
+    #[storage_mapper("approvals")]
+    fn approvals(&self, batch_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[proxy]
+    fn game_proxy(&self, sc_address: ManagedAddress) -> game_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("batch_proposed")]
+    fn batch_proposed_event(&self, #[indexed] batch_id: u32, #[indexed] proposer: &ManagedAddress, actions: usize);
+
+    #[event("batch_approved")]
+    fn batch_approved_event(&self, #[indexed] batch_id: u32, #[indexed] signer: &ManagedAddress);
+
+    #[event("batch_unapproved")]
+    fn batch_unapproved_event(&self, #[indexed] batch_id: u32, #[indexed] signer: &ManagedAddress);
+
+    #[event("batch_discarded")]
+    fn batch_discarded_event(&self, #[indexed] batch_id: u32);
+
+    #[event("batch_executed")]
+    fn batch_executed_event(&self, #[indexed] batch_id: u32, #[indexed] executed_by: &ManagedAddress);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchStatus {
+    Pending,
+    Executed,
+    Discarded,
+}
+
+/// The actions signers can approve; anything else needs the owner of the target contract
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub enum GuardianAction<M: ManagedTypeApi> {
+    PauseGame { game: ManagedAddress<M> },
+    UnpauseGame { game: ManagedAddress<M> },
+    VetoProposal { game: ManagedAddress<M>, proposal_id: u32 },
+    InitiateEmergencyWithdraw { game: ManagedAddress<M>, recovery_address: ManagedAddress<M> },
+    CancelEmergencyWithdraw { game: ManagedAddress<M> },
+    ExecuteEmergencyWithdraw { game: ManagedAddress<M> },
+    /// Replace `target`'s code with `source`'s; `target` must be owned by this contract
+    Upgrade { target: ManagedAddress<M>, source: ManagedAddress<M> },
+    AddSigner { signer: ManagedAddress<M> },
+    RemoveSigner { signer: ManagedAddress<M> },
+    SetQuorum { quorum: usize },
+}
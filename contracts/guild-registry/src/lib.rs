@@ -0,0 +1,326 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub const MAX_GUILD_NAME_LENGTH: usize = 32;
+/// Upper bound on members of one guild
+pub const MAX_GUILD_MEMBERS: usize = 100;
+/// Upper bound on guilds credited in one points report
+pub const MAX_POINTS_ENTRIES: usize = 50;
+
+/// Guild Registry Smart Contract
+/// Keeps guilds at suite level so every game instance sees the same guilds: their identity,
+/// members and an EGLD treasury run by the guild leader. Registered games credit guild points
+/// for what members achieve in them; points are kept per game and summed across games.
+#[multiversx_sc::contract]
+pub trait GuildRegistry {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(addGame)]
+    fn add_game(&self, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        require!(self.games().insert(game.clone()), "Game already added");
+
+        self.game_added_event(&game);
+    }
+
+    #[only_owner]
+    #[endpoint(removeGame)]
+    fn remove_game(&self, game: ManagedAddress) {
+        require!(self.games().swap_remove(&game), "Game not added");
+
+        self.game_removed_event(&game);
+    }
+
+    /// Found a guild led by the caller
+    #[endpoint(createGuild)]
+    fn create_guild(&self, name: ManagedBuffer) -> u32 {
+        let caller = self.blockchain().get_caller();
+        require!(!name.is_empty() && name.len() <= MAX_GUILD_NAME_LENGTH, "Invalid guild name");
+        require!(self.guild_by_name(&name).is_empty(), "Guild name taken");
+        require!(self.player_guild(&caller).is_empty(), "Already in a guild");
+
+        let guild_id = self.last_guild_id().get() + 1;
+        let guild = Guild {
+            id: guild_id,
+            name: name.clone(),
+            founder: caller.clone(),
+            leader: caller.clone(),
+            created_block: self.blockchain().get_block_nonce(),
+        };
+        self.guilds(guild_id).set(&guild);
+        self.guild_by_name(&name).set(guild_id);
+        self.last_guild_id().set(guild_id);
+        self.guild_members(guild_id).insert(caller.clone());
+        self.player_guild(&caller).set(guild_id);
+
+        self.guild_created_event(guild_id, &caller, &name);
+
+        guild_id
+    }
+
+    #[endpoint(joinGuild)]
+    fn join_guild(&self, guild_id: u32) {
+        let caller = self.blockchain().get_caller();
+        self.existing_guild(guild_id);
+        require!(self.player_guild(&caller).is_empty(), "Already in a guild");
+        require!(self.guild_members(guild_id).len() < MAX_GUILD_MEMBERS, "Guild is full");
+
+        self.guild_members(guild_id).insert(caller.clone());
+        self.player_guild(&caller).set(guild_id);
+
+        self.guild_joined_event(guild_id, &caller);
+    }
+
+    /// Leave the caller's guild. The leader has to hand over leadership first unless they are
+    /// the last member, in which case the guild is disbanded and its name freed; points stay
+    /// on record and a non-empty treasury has to be withdrawn beforehand.
+    #[endpoint(leaveGuild)]
+    fn leave_guild(&self) {
+        let caller = self.blockchain().get_caller();
+        let guild_id = self.caller_guild(&caller);
+        let guild = self.guilds(guild_id).get();
+
+        if guild.leader == caller {
+            require!(
+                self.guild_members(guild_id).len() == 1,
+                "Leader must transfer leadership first"
+            );
+            require!(self.guild_treasury(guild_id).get() == 0, "Treasury not empty");
+
+            self.guild_by_name(&guild.name).clear();
+            self.guilds(guild_id).clear();
+            self.guild_disbanded_event(guild_id);
+        }
+
+        self.guild_members(guild_id).swap_remove(&caller);
+        self.player_guild(&caller).clear();
+
+        self.guild_left_event(guild_id, &caller);
+    }
+
+    #[endpoint(transferLeadership)]
+    fn transfer_leadership(&self, new_leader: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let guild_id = self.caller_guild(&caller);
+        let mut guild = self.led_guild(guild_id, &caller);
+        require!(self.guild_members(guild_id).contains(&new_leader), "Not a guild member");
+
+        guild.leader = new_leader.clone();
+        self.guilds(guild_id).set(&guild);
+
+        self.leadership_transferred_event(guild_id, &caller, &new_leader);
+    }
+
+    #[endpoint(kickMember)]
+    fn kick_member(&self, member: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let guild_id = self.caller_guild(&caller);
+        self.led_guild(guild_id, &caller);
+        require!(member != caller, "Leader cannot kick themselves");
+        require!(self.guild_members(guild_id).swap_remove(&member), "Not a guild member");
+        self.player_guild(&member).clear();
+
+        self.guild_left_event(guild_id, &member);
+    }
+
+    /// Contribute EGLD to the caller's guild treasury
+    #[payable("EGLD")]
+    #[endpoint(contribute)]
+    fn contribute(&self) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0, "Must send EGLD");
+        let guild_id = self.caller_guild(&caller);
+
+        self.guild_treasury(guild_id).update(|treasury| *treasury += &payment);
+
+        self.guild_contribution_event(guild_id, &caller, &payment);
+    }
+
+    /// Pay `amount` from the caller's guild treasury to `recipient`; leader only
+    #[endpoint(spendTreasury)]
+    fn spend_treasury(&self, recipient: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let guild_id = self.caller_guild(&caller);
+        self.led_guild(guild_id, &caller);
+        require!(amount > 0, "Amount must be positive");
+
+        let treasury_mapper = self.guild_treasury(guild_id);
+        require!(amount <= treasury_mapper.get(), "Insufficient guild treasury");
+        treasury_mapper.update(|treasury| *treasury -= &amount);
+
+        self.send().direct_egld(&recipient, &amount);
+
+        self.guild_treasury_spent_event(guild_id, &recipient, &amount);
+    }
+
+    /// Credit points to guilds for the calling game, one `(guild_id, points)` entry per guild
+    #[endpoint(addGuildPoints)]
+    fn add_guild_points(&self, entries: MultiValueEncoded<MultiValue2<u32, u64>>) {
+        let game = self.blockchain().get_caller();
+        require!(self.games().contains(&game), "Game not added");
+        require!(entries.len() <= MAX_POINTS_ENTRIES, "Too many entries");
+
+        for entry in entries {
+            let (guild_id, points) = entry.into_tuple();
+            self.existing_guild(guild_id);
+            if points == 0 {
+                continue;
+            }
+
+            self.game_guild_points(&game, guild_id).update(|total| *total += points);
+            self.guild_points(guild_id).update(|total| *total += points);
+
+            self.guild_points_added_event(guild_id, &game, points);
+        }
+    }
+
+    // View functions
+    /// The guild, its member count and its treasury balance
+    #[view(getGuild)]
+    fn get_guild(&self, guild_id: u32) -> OptionalValue<MultiValue3<Guild<Self::Api>, usize, BigUint>> {
+        if self.guilds(guild_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(
+            (
+                self.guilds(guild_id).get(),
+                self.guild_members(guild_id).len(),
+                self.guild_treasury(guild_id).get(),
+            )
+                .into(),
+        )
+    }
+
+    #[view(getGuildByName)]
+    fn get_guild_by_name(&self, name: ManagedBuffer) -> u32 {
+        self.guild_by_name(&name).get()
+    }
+
+    #[view(getGuildMembers)]
+    fn get_guild_members(&self, guild_id: u32) -> MultiValueEncoded<ManagedAddress> {
+        self.guild_members(guild_id).iter().collect()
+    }
+
+    /// Guild of `player`, zero if none
+    #[view(getPlayerGuild)]
+    fn get_player_guild(&self, player: ManagedAddress) -> u32 {
+        self.player_guild(&player).get()
+    }
+
+    /// Points of a guild summed across games
+    #[view(getGuildPoints)]
+    fn get_guild_points(&self, guild_id: u32) -> u64 {
+        self.guild_points(guild_id).get()
+    }
+
+    #[view(getGameGuildPoints)]
+    fn get_game_guild_points(&self, game: ManagedAddress, guild_id: u32) -> u64 {
+        self.game_guild_points(&game, guild_id).get()
+    }
+
+    #[view(getGames)]
+    fn get_games(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.games().iter().collect()
+    }
+
+    // Private functions
+    fn existing_guild(&self, guild_id: u32) -> Guild<Self::Api> {
+        require!(!self.guilds(guild_id).is_empty(), "Guild does not exist");
+        self.guilds(guild_id).get()
+    }
+
+    fn caller_guild(&self, caller: &ManagedAddress) -> u32 {
+        let player_guild_mapper = self.player_guild(caller);
+        require!(!player_guild_mapper.is_empty(), "Not in a guild");
+        player_guild_mapper.get()
+    }
+
+    fn led_guild(&self, guild_id: u32, caller: &ManagedAddress) -> Guild<Self::Api> {
+        let guild = self.guilds(guild_id).get();
+        require!(guild.leader == *caller, "Only the guild leader");
+        guild
+    }
+
+    // Storage
+    #[storage_mapper("games")]
+    fn games(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("last_guild_id")]
+    fn last_guild_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guilds")]
+    fn guilds(&self, guild_id: u32) -> SingleValueMapper<Guild<Self::Api>>;
+
+    #[storage_mapper("guild_by_name")]
+    fn guild_by_name(&self, name: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guild_members")]
+    fn guild_members(&self, guild_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("player_guild")]
+    fn player_guild(&self, player: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guild_treasury")]
+    fn guild_treasury(&self, guild_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("guild_points")]
+    fn guild_points(&self, guild_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("game_guild_points")]
+    fn game_guild_points(&self, game: &ManagedAddress, guild_id: u32) -> SingleValueMapper<u64>;
+
+    // Events
+    #[event("game_added")]
+    fn game_added_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("game_removed")]
+    fn game_removed_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("guild_created")]
+    fn guild_created_event(&self, #[indexed] guild_id: u32, #[indexed] founder: &ManagedAddress, name: &ManagedBuffer);
+
+    #[event("guild_disbanded")]
+    fn guild_disbanded_event(&self, #[indexed] guild_id: u32);
+
+    #[event("guild_joined")]
+    fn guild_joined_event(&self, #[indexed] guild_id: u32, #[indexed] member: &ManagedAddress);
+
+    #[event("guild_left")]
+    fn guild_left_event(&self, #[indexed] guild_id: u32, #[indexed] member: &ManagedAddress);
+
+    #[event("leadership_transferred")]
+    fn leadership_transferred_event(
+        &self,
+        #[indexed] guild_id: u32,
+        #[indexed] previous_leader: &ManagedAddress,
+        #[indexed] new_leader: &ManagedAddress,
+    );
+
+    #[event("guild_contribution")]
+    fn guild_contribution_event(&self, #[indexed] guild_id: u32, #[indexed] member: &ManagedAddress, amount: &BigUint);
+
+    #[event("guild_treasury_spent")]
+    fn guild_treasury_spent_event(
+        &self,
+        #[indexed] guild_id: u32,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("guild_points_added")]
+    fn guild_points_added_event(&self, #[indexed] guild_id: u32, #[indexed] game: &ManagedAddress, points: u64);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Guild<M: ManagedTypeApi> {
+    pub id: u32,
+    pub name: ManagedBuffer<M>,
+    pub founder: ManagedAddress<M>,
+    pub leader: ManagedAddress<M>,
+    pub created_block: u64,
+}
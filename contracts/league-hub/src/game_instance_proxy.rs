@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+
+/// Endpoints of the quantum-dao game instances called by the hub
+#[multiversx_sc::proxy]
+pub trait GameInstance {
+    #[view(getTotalScore)]
+    fn get_total_score(&self, season_id: OptionalValue<u32>) -> u64;
+
+    #[payable("EGLD")]
+    #[endpoint(receiveWarBonus)]
+    fn receive_war_bonus(&self, war_id: u32);
+}
@@ -0,0 +1,507 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_instance_proxy;
+
+/// Upper bound on players per league, keeping `closeLeagueSeason` within block gas limits
+pub const MAX_LEAGUE_SIZE: usize = 100;
+pub const MAX_LEAGUES: u32 = 10;
+
+/// League Hub Smart Contract
+/// Game instances report season scores here; players are placed in leagues by their
+/// aggregated historical score and promoted or relegated at the end of each league season
+#[multiversx_sc::contract]
+pub trait LeagueHub {
+    #[init]
+    fn init(&self, league_count: u32, promotion_slots: u32, relegation_slots: u32) {
+        require!(league_count > 0 && league_count <= MAX_LEAGUES, "Invalid league count");
+        self.league_count().set(league_count);
+        self.promotion_slots().set(promotion_slots);
+        self.relegation_slots().set(relegation_slots);
+        self.current_league_season().set(1u32);
+    }
+
+    /// Allow a game instance to report scores
+    #[only_owner]
+    #[endpoint(registerInstance)]
+    fn register_instance(&self, instance: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&instance), "Instance must be a contract");
+        require!(self.instances().insert(instance.clone()), "Instance already registered");
+
+        self.instance_registered_event(&instance);
+    }
+
+    #[only_owner]
+    #[endpoint(unregisterInstance)]
+    fn unregister_instance(&self, instance: ManagedAddress) {
+        require!(self.instances().swap_remove(&instance), "Instance not registered");
+
+        self.instance_unregistered_event(&instance);
+    }
+
+    /// Minimum historical rating for each league, from the top league down
+    #[only_owner]
+    #[endpoint(setRatingThresholds)]
+    fn set_rating_thresholds(&self, thresholds: MultiValueEncoded<u64>) {
+        let league_count = self.league_count().get();
+        require!(thresholds.len() == league_count as usize, "One threshold per league required");
+
+        self.rating_thresholds().clear();
+        let mut previous = u64::MAX;
+        for threshold in thresholds {
+            require!(threshold <= previous, "Thresholds must be descending");
+            self.rating_thresholds().push(&threshold);
+            previous = threshold;
+        }
+    }
+
+    /// Register the caller for league play, placing them by historical rating
+    #[endpoint(registerPlayer)]
+    fn register_player(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.player_league(&caller).is_empty(), "Already registered");
+
+        let rating = self.player_rating(&caller).get();
+        let league_count = self.league_count().get();
+        let mut league = self.league_for_rating(rating);
+        while league <= league_count && self.league_members(league).len() >= MAX_LEAGUE_SIZE {
+            league += 1;
+        }
+        require!(league <= league_count, "All leagues are full");
+
+        self.league_members(league).insert(caller.clone());
+        self.player_league(&caller).set(league);
+
+        self.player_registered_event(&caller, league);
+    }
+
+    /// Called by registered game instances at the end of their season
+    #[endpoint(reportScores)]
+    fn report_scores(&self, scores: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) {
+        let instance = self.blockchain().get_caller();
+        require!(self.instances().contains(&instance), "Instance not registered");
+
+        let league_season = self.current_league_season().get();
+        for entry in scores {
+            let (player, score) = entry.into_tuple();
+            self.player_rating(&player).update(|rating| *rating += score);
+            if !self.player_league(&player).is_empty() {
+                self.season_scores(league_season, &player).update(|total| *total += score);
+            }
+
+            self.score_reported_event(&instance, &player, score);
+        }
+    }
+
+    /// Rank every league by season score, promote the top and relegate the bottom players,
+    /// then open the next league season
+    #[only_owner]
+    #[endpoint(closeLeagueSeason)]
+    fn close_league_season(&self) {
+        let league_season = self.current_league_season().get();
+        let league_count = self.league_count().get();
+        let promotion_slots = self.promotion_slots().get() as usize;
+        let relegation_slots = self.relegation_slots().get() as usize;
+
+        let mut moved_players: ManagedVec<ManagedAddress> = ManagedVec::new();
+        let mut moved_to: ManagedVec<u32> = ManagedVec::new();
+
+        for league in 1..=league_count {
+            let standings = self.sorted_standings(league_season, league);
+            let size = standings.len();
+
+            if league > 1 {
+                for index in 0..core::cmp::min(promotion_slots, size) {
+                    moved_players.push(standings.get(index).player);
+                    moved_to.push(league - 1);
+                }
+            }
+
+            if league < league_count {
+                // Players already promoted out of this league are never relegated
+                let promoted = if league > 1 {
+                    core::cmp::min(promotion_slots, size)
+                } else {
+                    0
+                };
+                let first_relegated = size - core::cmp::min(relegation_slots, size);
+                let first_relegated = core::cmp::max(first_relegated, promoted);
+                for index in first_relegated..size {
+                    moved_players.push(standings.get(index).player);
+                    moved_to.push(league + 1);
+                }
+            }
+        }
+
+        for (player, new_league) in moved_players.iter().zip(moved_to.iter()) {
+            let old_league = self.player_league(&player).get();
+            self.league_members(old_league).swap_remove(&player);
+            self.league_members(new_league).insert(player.clone_value());
+            self.player_league(&player).set(new_league);
+
+            self.league_changed_event(&player, old_league, new_league);
+        }
+
+        self.current_league_season().set(league_season + 1);
+
+        self.league_season_closed_event(league_season);
+    }
+
+    /// Anyone can top up the shared pot that pays DAO war bonuses
+    #[payable("EGLD")]
+    #[endpoint(fundWarPot)]
+    fn fund_war_pot(&self) {
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0, "Must send EGLD");
+
+        self.war_pot().update(|pot| *pot += &payment);
+    }
+
+    /// Bonus reserved from the pot for each accepted war
+    #[only_owner]
+    #[endpoint(setWarBonus)]
+    fn set_war_bonus(&self, bonus: BigUint) {
+        self.war_bonus().set(&bonus);
+    }
+
+    /// Challenge another registered instance to a head-to-head match.
+    /// Aggregate scores of both instances' seasons are compared after `deadline_block`.
+    #[endpoint(registerWar)]
+    fn register_war(&self, opponent: ManagedAddress, deadline_block: u64, season_id: u32) -> u32 {
+        let challenger = self.blockchain().get_caller();
+        require!(self.instances().contains(&challenger), "Instance not registered");
+        require!(self.instances().contains(&opponent), "Opponent not registered");
+        require!(challenger != opponent, "Cannot challenge yourself");
+        require!(deadline_block > self.blockchain().get_block_nonce(), "Deadline must be in the future");
+
+        let war_id = self.last_war_id().get() + 1;
+        let war = War {
+            id: war_id,
+            challenger: challenger.clone(),
+            opponent: opponent.clone(),
+            challenger_season: season_id,
+            opponent_season: 0,
+            deadline_block,
+            bonus: BigUint::zero(),
+            status: WarStatus::Pending,
+        };
+        self.wars(war_id).set(&war);
+        self.last_war_id().set(war_id);
+
+        self.war_registered_event(war_id, &challenger, &opponent, deadline_block);
+
+        war_id
+    }
+
+    /// Accept a pending challenge, reserving the war bonus from the pot
+    #[endpoint(acceptWar)]
+    fn accept_war(&self, war_id: u32, season_id: u32) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.wars(war_id).is_empty(), "War does not exist");
+
+        let mut war = self.wars(war_id).get();
+        require!(war.status == WarStatus::Pending, "War not pending");
+        require!(caller == war.opponent, "Only the challenged instance can accept");
+        require!(self.blockchain().get_block_nonce() < war.deadline_block, "War deadline passed");
+
+        let bonus = self.war_bonus().get();
+        let pot = self.war_pot().get();
+        require!(pot >= bonus, "War pot too small");
+        self.war_pot().set(&(pot - &bonus));
+
+        war.opponent_season = season_id;
+        war.bonus = bonus;
+        war.status = WarStatus::Active;
+        self.wars(war_id).set(&war);
+
+        self.war_accepted_event(war_id);
+    }
+
+    /// Fetch one side's aggregate season score once the deadline has passed
+    #[endpoint(collectWarScore)]
+    fn collect_war_score(&self, war_id: u32, instance: ManagedAddress) {
+        require!(!self.wars(war_id).is_empty(), "War does not exist");
+
+        let war = self.wars(war_id).get();
+        require!(war.status == WarStatus::Active, "War not active");
+        require!(self.blockchain().get_block_nonce() > war.deadline_block, "War still running");
+        require!(!self.war_score_collected(war_id).contains(&instance), "Score already collected");
+
+        let season_id = if instance == war.challenger {
+            war.challenger_season
+        } else {
+            require!(instance == war.opponent, "Instance not in this war");
+            war.opponent_season
+        };
+
+        self.game_instance_proxy(instance.clone())
+            .get_total_score(OptionalValue::Some(season_id))
+            .async_call()
+            .with_callback(self.callbacks().war_score_callback(war_id, instance))
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn war_score_callback(
+        &self,
+        war_id: u32,
+        instance: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<u64>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(score) = result {
+            if self.war_score_collected(war_id).insert(instance.clone()) {
+                self.war_scores(war_id, &instance).set(score);
+                self.war_score_collected_event(war_id, &instance, score);
+            }
+        }
+    }
+
+    /// Pay the bonus into the winner's treasury. On a tie the bonus returns to the pot.
+    #[endpoint(settleWar)]
+    fn settle_war(&self, war_id: u32) {
+        require!(!self.wars(war_id).is_empty(), "War does not exist");
+
+        let mut war = self.wars(war_id).get();
+        require!(war.status == WarStatus::Active, "War not active");
+        require!(self.war_score_collected(war_id).len() == 2, "Scores not collected");
+
+        let challenger_score = self.war_scores(war_id, &war.challenger).get();
+        let opponent_score = self.war_scores(war_id, &war.opponent).get();
+
+        war.status = WarStatus::Settled;
+        self.wars(war_id).set(&war);
+
+        let winner = if challenger_score > opponent_score {
+            war.challenger
+        } else if opponent_score > challenger_score {
+            war.opponent
+        } else {
+            self.war_pot().update(|pot| *pot += &war.bonus);
+            self.war_settled_event(war_id, &ManagedAddress::zero(), &BigUint::zero());
+            return;
+        };
+
+        if war.bonus > 0 {
+            self.game_instance_proxy(winner.clone())
+                .receive_war_bonus(war_id)
+                .with_egld_transfer(war.bonus.clone())
+                .transfer_execute();
+        }
+
+        self.war_settled_event(war_id, &winner, &war.bonus);
+    }
+
+    // View functions
+    #[view(getPlayerLeague)]
+    fn get_player_league(&self, player: &ManagedAddress) -> u32 {
+        self.player_league(player).get()
+    }
+
+    #[view(getPlayerRating)]
+    fn get_player_rating(&self, player: &ManagedAddress) -> u64 {
+        self.player_rating(player).get()
+    }
+
+    #[view(getLeagueStandings)]
+    fn get_league_standings(&self, league: u32) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let league_season = self.current_league_season().get();
+        let mut result = MultiValueEncoded::new();
+        for standing in self.sorted_standings(league_season, league).iter() {
+            result.push((standing.player, standing.score).into());
+        }
+        result
+    }
+
+    #[view(getCurrentLeagueSeason)]
+    fn get_current_league_season(&self) -> u32 {
+        self.current_league_season().get()
+    }
+
+    #[view(getWar)]
+    fn get_war(&self, war_id: u32) -> OptionalValue<War<Self::Api>> {
+        if self.wars(war_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.wars(war_id).get())
+    }
+
+    #[view(getWarPot)]
+    fn get_war_pot(&self) -> BigUint {
+        self.war_pot().get()
+    }
+
+    #[view(getInstances)]
+    fn get_instances(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.instances().iter().collect()
+    }
+
+    // Private functions
+    fn league_for_rating(&self, rating: u64) -> u32 {
+        let league_count = self.league_count().get();
+        for (index, threshold) in self.rating_thresholds().iter().enumerate() {
+            if rating >= threshold {
+                return index as u32 + 1;
+            }
+        }
+        league_count
+    }
+
+    /// League members ordered by season score, highest first
+    fn sorted_standings(&self, league_season: u32, league: u32) -> ManagedVec<Standing<Self::Api>> {
+        let mut standings: ManagedVec<Standing<Self::Api>> = ManagedVec::new();
+        for player in self.league_members(league).iter() {
+            let score = self.season_scores(league_season, &player).get();
+            let mut position = standings.len();
+            while position > 0 && standings.get(position - 1).score < score {
+                position -= 1;
+            }
+
+            let standing = Standing { player, score };
+            if position == standings.len() {
+                standings.push(standing);
+            } else {
+                let mut reordered: ManagedVec<Standing<Self::Api>> = ManagedVec::new();
+                for (index, existing) in standings.iter().enumerate() {
+                    if index == position {
+                        reordered.push(standing.clone());
+                    }
+                    reordered.push(existing);
+                }
+                standings = reordered;
+            }
+        }
+        standings
+    }
+
+    // Storage
+    #[storage_mapper("league_count")]
+    fn league_count(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("promotion_slots")]
+    fn promotion_slots(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("relegation_slots")]
+    fn relegation_slots(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("rating_thresholds")]
+    fn rating_thresholds(&self) -> VecMapper<u64>;
+
+    #[storage_mapper("current_league_season")]
+    fn current_league_season(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("instances")]
+    fn instances(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("player_rating")]
+    fn player_rating(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("player_league")]
+    fn player_league(&self, player: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("league_members")]
+    fn league_members(&self, league: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("season_scores")]
+    fn season_scores(&self, league_season: u32, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("war_pot")]
+    fn war_pot(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("war_bonus")]
+    fn war_bonus(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("last_war_id")]
+    fn last_war_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("wars")]
+    fn wars(&self, war_id: u32) -> SingleValueMapper<War<Self::Api>>;
+
+    #[storage_mapper("war_score_collected")]
+    fn war_score_collected(&self, war_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("war_scores")]
+    fn war_scores(&self, war_id: u32, instance: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[proxy]
+    fn game_instance_proxy(&self, sc_address: ManagedAddress) -> game_instance_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("instance_registered")]
+    fn instance_registered_event(&self, #[indexed] instance: &ManagedAddress);
+
+    #[event("instance_unregistered")]
+    fn instance_unregistered_event(&self, #[indexed] instance: &ManagedAddress);
+
+    #[event("player_registered")]
+    fn player_registered_event(&self, #[indexed] player: &ManagedAddress, league: u32);
+
+    #[event("score_reported")]
+    fn score_reported_event(
+        &self,
+        #[indexed] instance: &ManagedAddress,
+        #[indexed] player: &ManagedAddress,
+        score: u64,
+    );
+
+    #[event("league_changed")]
+    fn league_changed_event(&self, #[indexed] player: &ManagedAddress, old_league: u32, new_league: u32);
+
+    #[event("league_season_closed")]
+    fn league_season_closed_event(&self, #[indexed] league_season: u32);
+
+    #[event("war_registered")]
+    fn war_registered_event(
+        &self,
+        #[indexed] war_id: u32,
+        #[indexed] challenger: &ManagedAddress,
+        #[indexed] opponent: &ManagedAddress,
+        deadline_block: u64,
+    );
+
+    #[event("war_accepted")]
+    fn war_accepted_event(&self, #[indexed] war_id: u32);
+
+    #[event("war_score_collected")]
+    fn war_score_collected_event(
+        &self,
+        #[indexed] war_id: u32,
+        #[indexed] instance: &ManagedAddress,
+        score: u64,
+    );
+
+    #[event("war_settled")]
+    fn war_settled_event(
+        &self,
+        #[indexed] war_id: u32,
+        #[indexed] winner: &ManagedAddress,
+        bonus: &BigUint,
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Standing<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub score: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WarStatus {
+    Pending,
+    Active,
+    Settled,
+}
+
+/// Head-to-head match between two game instances
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct War<M: ManagedTypeApi> {
+    pub id: u32,
+    pub challenger: ManagedAddress<M>,
+    pub opponent: ManagedAddress<M>,
+    pub challenger_season: u32,
+    pub opponent_season: u32,
+    pub deadline_block: u64,
+    pub bonus: BigUint<M>,
+    pub status: WarStatus,
+}
@@ -0,0 +1,14 @@
+multiversx_sc::imports!();
+
+/// Hook a game contract exposes to learn that one of its boosted NFTs changed hands
+#[multiversx_sc::proxy]
+pub trait GameHook {
+    #[endpoint(onBoostedNftTransfer)]
+    fn on_boosted_nft_transfer(
+        &self,
+        token_id: TokenIdentifier,
+        nonce: u64,
+        from: ManagedAddress,
+        to: ManagedAddress,
+    );
+}
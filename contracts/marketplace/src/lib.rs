@@ -0,0 +1,429 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_hook_proxy;
+pub mod treasury_proxy;
+
+pub const MAX_BPS: u64 = 10_000;
+/// Upper bound on the marketplace fee taken from every sale
+pub const MAX_FEE_BPS: u64 = 1_000;
+
+/// Marketplace Smart Contract
+/// Fixed-price sales and English auctions of the suite's reward NFTs. Every sale pays the
+/// NFT's royalties and the marketplace fee, which goes to the treasury. Collections whose NFTs
+/// grant in-game boosts can name a game contract that is notified when one changes hands.
+#[multiversx_sc::contract]
+pub trait Marketplace {
+    #[init]
+    fn init(&self, treasury: ManagedAddress, fee_bps: u64) {
+        require!(fee_bps <= MAX_FEE_BPS, "Fee too high");
+        self.treasury().set(&treasury);
+        self.fee_bps().set(fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(setTreasury)]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        self.treasury().set(&treasury);
+    }
+
+    #[only_owner]
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee_bps: u64) {
+        require!(fee_bps <= MAX_FEE_BPS, "Fee too high");
+        self.fee_bps().set(fee_bps);
+
+        self.fee_set_event(fee_bps);
+    }
+
+    /// Allow NFTs of `collection` to be listed
+    #[only_owner]
+    #[endpoint(addCollection)]
+    fn add_collection(&self, collection: TokenIdentifier) {
+        require!(collection.is_valid_esdt_identifier(), "Invalid collection");
+        require!(self.collections().insert(collection.clone()), "Collection already added");
+
+        self.collection_added_event(&collection);
+    }
+
+    /// Stop new listings of `collection`; existing listings can still be completed
+    #[only_owner]
+    #[endpoint(removeCollection)]
+    fn remove_collection(&self, collection: TokenIdentifier) {
+        require!(self.collections().swap_remove(&collection), "Collection not added");
+
+        self.collection_removed_event(&collection);
+    }
+
+    /// Pay royalties of `collection` to `receiver` instead of the NFT's creator, e.g. when a
+    /// minter contract rather than the artist created the NFTs
+    #[only_owner]
+    #[endpoint(setRoyaltyReceiver)]
+    fn set_royalty_receiver(&self, collection: TokenIdentifier, receiver: ManagedAddress) {
+        self.royalty_receiver(&collection).set(&receiver);
+    }
+
+    /// Notify `game` whenever an NFT of `collection` is sold, so it can move the boost the NFT
+    /// grants to the new holder
+    #[only_owner]
+    #[endpoint(setTransferHook)]
+    fn set_transfer_hook(&self, collection: TokenIdentifier, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Hook must be a contract");
+        self.transfer_hook(&collection).set(&game);
+
+        self.transfer_hook_set_event(&collection, &game);
+    }
+
+    #[only_owner]
+    #[endpoint(removeTransferHook)]
+    fn remove_transfer_hook(&self, collection: TokenIdentifier) {
+        require!(!self.transfer_hook(&collection).is_empty(), "No hook for collection");
+        self.transfer_hook(&collection).clear();
+    }
+
+    /// List the NFT sent with the call for `price` of `payment_token`
+    #[payable("*")]
+    #[endpoint(listFixedPrice)]
+    fn list_fixed_price(&self, payment_token: EgldOrEsdtTokenIdentifier, price: BigUint) -> u64 {
+        require!(price > 0, "Price must be positive");
+
+        self.create_listing(payment_token, price, ListingKind::FixedPrice, 0)
+    }
+
+    /// Auction the NFT sent with the call for `duration_blocks`, starting at `min_bid`
+    #[payable("*")]
+    #[endpoint(listAuction)]
+    fn list_auction(
+        &self,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        min_bid: BigUint,
+        duration_blocks: u64,
+    ) -> u64 {
+        require!(min_bid > 0, "Minimum bid must be positive");
+        require!(duration_blocks > 0, "Duration must be positive");
+
+        let end_block = self.blockchain().get_block_nonce() + duration_blocks;
+        self.create_listing(payment_token, min_bid, ListingKind::Auction, end_block)
+    }
+
+    /// Return the NFT to its seller. Auctions can only be cancelled before the first bid.
+    #[endpoint(cancelListing)]
+    fn cancel_listing(&self, listing_id: u64) {
+        let listing = self.active_listing(listing_id);
+        require!(
+            self.blockchain().get_caller() == listing.seller,
+            "Only the seller can cancel"
+        );
+        require!(listing.highest_bidder.is_none(), "Auction already has bids");
+
+        self.close_listing(listing_id);
+        self.send()
+            .direct_esdt(&listing.seller, &listing.token_id, listing.nonce, &BigUint::from(1u32));
+
+        self.listing_cancelled_event(listing_id);
+    }
+
+    /// Buy a fixed-price listing, paying exactly its price
+    #[payable("*")]
+    #[endpoint(buy)]
+    fn buy(&self, listing_id: u64) {
+        let listing = self.active_listing(listing_id);
+        require!(listing.kind == ListingKind::FixedPrice, "Listing is an auction");
+
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(token == listing.payment_token && nonce == 0, "Wrong payment token");
+        require!(amount == listing.price, "Payment must equal the price");
+
+        let buyer = self.blockchain().get_caller();
+        require!(buyer != listing.seller, "Cannot buy own listing");
+
+        self.close_listing(listing_id);
+        self.settle_sale(&listing, &buyer, &amount);
+    }
+
+    /// Outbid the current highest bid; the previous bidder is refunded immediately
+    #[payable("*")]
+    #[endpoint(bid)]
+    fn bid(&self, listing_id: u64) {
+        let mut listing = self.active_listing(listing_id);
+        require!(listing.kind == ListingKind::Auction, "Listing is not an auction");
+        require!(
+            self.blockchain().get_block_nonce() < listing.end_block,
+            "Auction ended"
+        );
+
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(token == listing.payment_token && nonce == 0, "Wrong payment token");
+        let bidder = self.blockchain().get_caller();
+        require!(bidder != listing.seller, "Cannot bid on own auction");
+
+        if let Some(previous_bidder) = listing.highest_bidder.take() {
+            require!(amount > listing.highest_bid, "Bid too low");
+            self.send()
+                .direct(&previous_bidder, &listing.payment_token, 0, &listing.highest_bid);
+        } else {
+            require!(amount >= listing.price, "Bid below minimum");
+        }
+
+        listing.highest_bid = amount;
+        listing.highest_bidder = Some(bidder.clone());
+        self.listings(listing_id).set(&listing);
+
+        self.bid_placed_event(listing_id, &bidder, &listing.highest_bid);
+    }
+
+    /// Settle an auction after its end block: the highest bidder receives the NFT, or the
+    /// seller gets it back if nobody bid. Anyone can call this.
+    #[endpoint(endAuction)]
+    fn end_auction(&self, listing_id: u64) {
+        let listing = self.active_listing(listing_id);
+        require!(listing.kind == ListingKind::Auction, "Listing is not an auction");
+        require!(
+            self.blockchain().get_block_nonce() >= listing.end_block,
+            "Auction still running"
+        );
+
+        self.close_listing(listing_id);
+        match &listing.highest_bidder {
+            Some(winner) => self.settle_sale(&listing, winner, &listing.highest_bid),
+            None => {
+                self.send().direct_esdt(
+                    &listing.seller,
+                    &listing.token_id,
+                    listing.nonce,
+                    &BigUint::from(1u32),
+                );
+                self.listing_cancelled_event(listing_id);
+            },
+        }
+    }
+
+    // View functions
+    #[view(getListing)]
+    fn get_listing(&self, listing_id: u64) -> OptionalValue<Listing<Self::Api>> {
+        if self.listings(listing_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.listings(listing_id).get())
+    }
+
+    #[view(getActiveListings)]
+    fn get_active_listings(&self) -> MultiValueEncoded<u64> {
+        self.active_listings().iter().collect()
+    }
+
+    #[view(getFee)]
+    fn get_fee(&self) -> u64 {
+        self.fee_bps().get()
+    }
+
+    #[view(getCollections)]
+    fn get_collections(&self) -> MultiValueEncoded<TokenIdentifier> {
+        self.collections().iter().collect()
+    }
+
+    #[view(getTransferHook)]
+    fn get_transfer_hook(&self, collection: TokenIdentifier) -> OptionalValue<ManagedAddress> {
+        if self.transfer_hook(&collection).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.transfer_hook(&collection).get())
+    }
+
+    // Private functions
+    fn create_listing(
+        &self,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        price: BigUint,
+        kind: ListingKind,
+        end_block: u64,
+    ) -> u64 {
+        let payment = self.call_value().single_esdt();
+        require!(
+            self.collections().contains(&payment.token_identifier),
+            "Collection not tradable"
+        );
+        require!(payment.token_nonce > 0 && payment.amount == 1, "Must send one NFT");
+        require!(payment_token.is_valid(), "Invalid payment token");
+
+        let listing_id = self.last_listing_id().get() + 1;
+        let listing = Listing {
+            id: listing_id,
+            seller: self.blockchain().get_caller(),
+            token_id: payment.token_identifier,
+            nonce: payment.token_nonce,
+            payment_token,
+            price,
+            kind,
+            end_block,
+            highest_bid: BigUint::zero(),
+            highest_bidder: None,
+        };
+        self.listings(listing_id).set(&listing);
+        self.active_listings().insert(listing_id);
+        self.last_listing_id().set(listing_id);
+
+        self.listed_event(listing_id, &listing.seller, &listing.token_id, listing.nonce, &listing.price);
+
+        listing_id
+    }
+
+    fn active_listing(&self, listing_id: u64) -> Listing<Self::Api> {
+        require!(self.active_listings().contains(&listing_id), "Listing not active");
+        self.listings(listing_id).get()
+    }
+
+    fn close_listing(&self, listing_id: u64) {
+        self.active_listings().swap_remove(&listing_id);
+        self.listings(listing_id).clear();
+    }
+
+    /// Splits `amount` into the treasury fee, the royalties and the seller's proceeds, hands
+    /// the NFT to `buyer` and notifies the collection's game hook, if any
+    fn settle_sale(&self, listing: &Listing<Self::Api>, buyer: &ManagedAddress, amount: &BigUint) {
+        let fee = amount * &BigUint::from(self.fee_bps().get()) / MAX_BPS;
+        let token_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            &listing.token_id,
+            listing.nonce,
+        );
+        let royalty_receiver_mapper = self.royalty_receiver(&listing.token_id);
+        let royalty_receiver = if royalty_receiver_mapper.is_empty() {
+            token_data.creator
+        } else {
+            royalty_receiver_mapper.get()
+        };
+        let royalty = if royalty_receiver == listing.seller {
+            BigUint::zero()
+        } else {
+            core::cmp::min(amount * &token_data.royalties / MAX_BPS, amount - &fee)
+        };
+        let proceeds = amount - &fee - &royalty;
+
+        if fee > 0 {
+            self.treasury_proxy(self.treasury().get())
+                .deposit_fees()
+                .with_egld_or_single_esdt_transfer((listing.payment_token.clone(), 0, fee.clone()))
+                .transfer_execute();
+        }
+        if royalty > 0 {
+            self.send()
+                .direct(&royalty_receiver, &listing.payment_token, 0, &royalty);
+        }
+        if proceeds > 0 {
+            self.send()
+                .direct(&listing.seller, &listing.payment_token, 0, &proceeds);
+        }
+        self.send()
+            .direct_esdt(buyer, &listing.token_id, listing.nonce, &BigUint::from(1u32));
+
+        let hook_mapper = self.transfer_hook(&listing.token_id);
+        if !hook_mapper.is_empty() {
+            self.game_hook_proxy(hook_mapper.get())
+                .on_boosted_nft_transfer(
+                    listing.token_id.clone(),
+                    listing.nonce,
+                    listing.seller.clone(),
+                    buyer.clone(),
+                )
+                .transfer_execute();
+        }
+
+        self.sold_event(listing.id, buyer, amount, &fee, &royalty);
+    }
+
+    // Storage
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("fee_bps")]
+    fn fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("collections")]
+    fn collections(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[storage_mapper("royalty_receiver")]
+    fn royalty_receiver(&self, collection: &TokenIdentifier) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("transfer_hook")]
+    fn transfer_hook(&self, collection: &TokenIdentifier) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("last_listing_id")]
+    fn last_listing_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("listings")]
+    fn listings(&self, listing_id: u64) -> SingleValueMapper<Listing<Self::Api>>;
+
+    #[storage_mapper("active_listings")]
+    fn active_listings(&self) -> UnorderedSetMapper<u64>;
+
+    #[proxy]
+    fn treasury_proxy(&self, sc_address: ManagedAddress) -> treasury_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn game_hook_proxy(&self, sc_address: ManagedAddress) -> game_hook_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("fee_set")]
+    fn fee_set_event(&self, fee_bps: u64);
+
+    #[event("collection_added")]
+    fn collection_added_event(&self, #[indexed] collection: &TokenIdentifier);
+
+    #[event("collection_removed")]
+    fn collection_removed_event(&self, #[indexed] collection: &TokenIdentifier);
+
+    #[event("transfer_hook_set")]
+    fn transfer_hook_set_event(&self, #[indexed] collection: &TokenIdentifier, game: &ManagedAddress);
+
+    #[event("listed")]
+    fn listed_event(
+        &self,
+        #[indexed] listing_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] token_id: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        price: &BigUint,
+    );
+
+    #[event("listing_cancelled")]
+    fn listing_cancelled_event(&self, #[indexed] listing_id: u64);
+
+    #[event("bid_placed")]
+    fn bid_placed_event(&self, #[indexed] listing_id: u64, #[indexed] bidder: &ManagedAddress, amount: &BigUint);
+
+    #[event("sold")]
+    fn sold_event(
+        &self,
+        #[indexed] listing_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        price: &BigUint,
+        fee: &BigUint,
+        royalty: &BigUint,
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ListingKind {
+    FixedPrice,
+    Auction,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Listing<M: ManagedTypeApi> {
+    pub id: u64,
+    pub seller: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
+    /// Fixed price, or the minimum first bid of an auction
+    pub price: BigUint<M>,
+    pub kind: ListingKind,
+    /// Block the auction ends at; 0 for fixed-price listings
+    pub end_block: u64,
+    pub highest_bid: BigUint<M>,
+    pub highest_bidder: Option<ManagedAddress<M>>,
+}
@@ -0,0 +1,191 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on NFT royalties, in basis points of the sale price
+pub const MAX_ROYALTIES_BPS: u64 = 10_000;
+
+/// NFT Minter Smart Contract
+/// Owns the suite's reward NFT collection and its create role. Registered game instances mint
+/// rewards through `mintFor`, so no game deployment ever needs the ESDTNFTCreate role itself.
+#[multiversx_sc::contract]
+pub trait NftMinter {
+    #[init]
+    fn init(&self) {}
+
+    /// Issue the reward collection with this contract holding every NFT role.
+    /// The payment covers the protocol issue cost.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueCollection)]
+    fn issue_collection(&self, display_name: ManagedBuffer, ticker: ManagedBuffer) {
+        require!(self.collection().is_empty(), "Collection already issued");
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.collection().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            display_name,
+            ticker,
+            0,
+            None,
+        );
+    }
+
+    /// Allow a game instance to mint rewards
+    #[only_owner]
+    #[endpoint(registerGame)]
+    fn register_game(&self, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        require!(self.games().insert(game.clone()), "Game already registered");
+
+        self.game_registered_event(&game);
+    }
+
+    #[only_owner]
+    #[endpoint(unregisterGame)]
+    fn unregister_game(&self, game: ManagedAddress) {
+        require!(self.games().swap_remove(&game), "Game not registered");
+
+        self.game_unregistered_event(&game);
+    }
+
+    /// Name, media and royalties of the NFTs minted for `tier`
+    #[only_owner]
+    #[endpoint(setTier)]
+    fn set_tier(&self, tier: u8, name: ManagedBuffer, uri: ManagedBuffer, royalties_bps: u64) {
+        require!(!name.is_empty(), "Name required");
+        require!(royalties_bps <= MAX_ROYALTIES_BPS, "Royalties too high");
+
+        self.tiers(tier).set(TierConfig {
+            name,
+            uri,
+            royalties_bps,
+        });
+
+        self.tier_set_event(tier, royalties_bps);
+    }
+
+    /// Mint one reward NFT of `tier` to `recipient`, carrying the game-defined `attributes`
+    #[endpoint(mintFor)]
+    fn mint_for(&self, recipient: ManagedAddress, tier: u8, attributes: ManagedBuffer) -> u64 {
+        let game = self.blockchain().get_caller();
+        require!(self.games().contains(&game), "Game not registered");
+        require!(!self.collection().is_empty(), "Collection not issued");
+        require!(!self.tiers(tier).is_empty(), "Tier not configured");
+
+        let config = self.tiers(tier).get();
+        let mut uris = ManagedVec::new();
+        if !config.uri.is_empty() {
+            uris.push(config.uri);
+        }
+        let token_id = self.collection().get_token_id();
+        let nft_attributes = RewardAttributes {
+            tier,
+            game: game.clone(),
+            data: attributes,
+        };
+        let nonce = self.send().esdt_nft_create(
+            &token_id,
+            &BigUint::from(1u32),
+            &config.name,
+            &BigUint::from(config.royalties_bps),
+            &ManagedBuffer::new(),
+            &nft_attributes,
+            &uris,
+        );
+        self.minted_count(tier).update(|count| *count += 1);
+        self.game_minted_count(&game).update(|count| *count += 1);
+
+        self.send()
+            .direct_esdt(&recipient, &token_id, nonce, &BigUint::from(1u32));
+
+        self.minted_event(&game, &recipient, tier, nonce);
+
+        nonce
+    }
+
+    // View functions
+    #[view(getCollection)]
+    fn get_collection(&self) -> OptionalValue<TokenIdentifier> {
+        if self.collection().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.collection().get_token_id())
+    }
+
+    #[view(getGames)]
+    fn get_games(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.games().iter().collect()
+    }
+
+    #[view(getTier)]
+    fn get_tier(&self, tier: u8) -> OptionalValue<TierConfig<Self::Api>> {
+        if self.tiers(tier).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.tiers(tier).get())
+    }
+
+    #[view(getMintedCount)]
+    fn get_minted_count(&self, tier: u8) -> u64 {
+        self.minted_count(tier).get()
+    }
+
+    #[view(getGameMintedCount)]
+    fn get_game_minted_count(&self, game: ManagedAddress) -> u64 {
+        self.game_minted_count(&game).get()
+    }
+
+    // Storage
+    #[storage_mapper("collection")]
+    fn collection(&self) -> NonFungibleTokenMapper<Self::Api>;
+
+    #[storage_mapper("games")]
+    fn games(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("tiers")]
+    fn tiers(&self, tier: u8) -> SingleValueMapper<TierConfig<Self::Api>>;
+
+    #[storage_mapper("minted_count")]
+    fn minted_count(&self, tier: u8) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("game_minted_count")]
+    fn game_minted_count(&self, game: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Events
+    #[event("game_registered")]
+    fn game_registered_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("game_unregistered")]
+    fn game_unregistered_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("tier_set")]
+    fn tier_set_event(&self, #[indexed] tier: u8, royalties_bps: u64);
+
+    #[event("minted")]
+    fn minted_event(
+        &self,
+        #[indexed] game: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] tier: u8,
+        nonce: u64,
+    );
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct TierConfig<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub uri: ManagedBuffer<M>,
+    pub royalties_bps: u64,
+}
+
+/// Attributes of every NFT in the collection: the tier, the minting game and its own payload
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct RewardAttributes<M: ManagedTypeApi> {
+    pub tier: u8,
+    pub game: ManagedAddress<M>,
+    pub data: ManagedBuffer<M>,
+}
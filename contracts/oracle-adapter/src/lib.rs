@@ -0,0 +1,240 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on whitelisted reporters, keeping median aggregation cheap
+pub const MAX_REPORTERS: usize = 21;
+
+/// Oracle Adapter Smart Contract
+/// Stores price and score feeds reported by whitelisted reporters. Reports are signed off-chain
+/// with the reporter's key, so anyone can relay them. Consumers read the median of each feed's
+/// fresh reports, e.g. the EGLD price behind EGLD-denominated thresholds or the score a
+/// prediction market settles on.
+#[multiversx_sc::contract]
+pub trait OracleAdapter {
+    #[init]
+    fn init(&self, min_reports: u32, max_staleness_seconds: u64) {
+        self.set_config(min_reports, max_staleness_seconds);
+    }
+
+    /// `min_reports` fresh reports are needed for a median; reports older than
+    /// `max_staleness_seconds` are ignored
+    #[only_owner]
+    #[endpoint(setConfig)]
+    fn set_config(&self, min_reports: u32, max_staleness_seconds: u64) {
+        require!(min_reports > 0, "Minimum reports must be positive");
+        require!(min_reports as usize <= MAX_REPORTERS, "Minimum reports exceeds reporter limit");
+        require!(max_staleness_seconds > 0, "Staleness must be positive");
+
+        self.min_reports().set(min_reports);
+        self.max_staleness_seconds().set(max_staleness_seconds);
+    }
+
+    /// Whitelist `reporter`, whose address is the ed25519 key its reports are signed with
+    #[only_owner]
+    #[endpoint(addReporter)]
+    fn add_reporter(&self, reporter: ManagedAddress) {
+        require!(self.reporters().len() < MAX_REPORTERS, "Too many reporters");
+        require!(self.reporters().insert(reporter.clone()), "Already a reporter");
+
+        self.reporter_added_event(&reporter);
+    }
+
+    /// Remove `reporter`; its stored reports stop counting immediately
+    #[only_owner]
+    #[endpoint(removeReporter)]
+    fn remove_reporter(&self, reporter: ManagedAddress) {
+        require!(self.reporters().swap_remove(&reporter), "Not a reporter");
+
+        self.reporter_removed_event(&reporter);
+    }
+
+    /// Store `value` of `feed` as observed by `reporter` at `timestamp`. `signature` is the
+    /// reporter's ed25519 signature of the top-encoded `ReportPayload`.
+    #[endpoint(submitReport)]
+    fn submit_report(
+        &self,
+        reporter: ManagedAddress,
+        feed: ManagedBuffer,
+        value: BigUint,
+        timestamp: u64,
+        signature: ManagedBuffer,
+    ) {
+        require!(self.reporters().contains(&reporter), "Not a reporter");
+        let now = self.blockchain().get_block_timestamp();
+        require!(timestamp <= now, "Report from the future");
+        require!(
+            now - timestamp <= self.max_staleness_seconds().get(),
+            "Report too old"
+        );
+
+        let report_mapper = self.reports(&feed, &reporter);
+        if !report_mapper.is_empty() {
+            require!(
+                timestamp > report_mapper.get().timestamp,
+                "Report older than the stored one"
+            );
+        }
+
+        let payload = ReportPayload {
+            oracle: self.blockchain().get_sc_address(),
+            feed: feed.clone(),
+            value: value.clone(),
+            timestamp,
+        };
+        let mut message = ManagedBuffer::new();
+        require!(payload.top_encode(&mut message).is_ok(), "Report encoding failed");
+        self.crypto()
+            .verify_ed25519(reporter.as_managed_buffer(), &message, &signature);
+
+        self.feeds().insert(feed.clone());
+        report_mapper.set(Report { value, timestamp });
+
+        self.report_submitted_event(&feed, &reporter, &payload.value, timestamp);
+    }
+
+    // View functions
+    /// Median of the fresh reports of `feed`; the mean of the two middle values when their
+    /// number is even. Fails without enough fresh reports.
+    #[view(getMedian)]
+    fn get_median(&self, feed: ManagedBuffer) -> BigUint {
+        let values = self.fresh_values(&feed);
+        require!(
+            values.len() >= self.min_reports().get() as usize,
+            "Not enough fresh reports"
+        );
+
+        let middle = values.len() / 2;
+        if values.len() % 2 == 1 {
+            return self.nth_smallest(&values, middle);
+        }
+
+        (self.nth_smallest(&values, middle - 1) + self.nth_smallest(&values, middle)) / 2u32
+    }
+
+    /// Whether `getMedian` currently has enough fresh reports for `feed`
+    #[view(isFeedLive)]
+    fn is_feed_live(&self, feed: ManagedBuffer) -> bool {
+        self.fresh_values(&feed).len() >= self.min_reports().get() as usize
+    }
+
+    /// Every whitelisted reporter's latest report of `feed`, fresh or not
+    #[view(getReports)]
+    fn get_reports(&self, feed: ManagedBuffer) -> MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for reporter in self.reporters().iter() {
+            let report_mapper = self.reports(&feed, &reporter);
+            if report_mapper.is_empty() {
+                continue;
+            }
+
+            let report = report_mapper.get();
+            result.push((reporter, report.value, report.timestamp).into());
+        }
+        result
+    }
+
+    #[view(getFeeds)]
+    fn get_feeds(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.feeds().iter().collect()
+    }
+
+    #[view(getReporters)]
+    fn get_reporters(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.reporters().iter().collect()
+    }
+
+    #[view(getConfig)]
+    fn get_config(&self) -> MultiValue2<u32, u64> {
+        (self.min_reports().get(), self.max_staleness_seconds().get()).into()
+    }
+
+    // Private functions
+    /// Values of the reports of `feed` by current reporters that are not stale
+    fn fresh_values(&self, feed: &ManagedBuffer) -> ManagedVec<BigUint> {
+        let now = self.blockchain().get_block_timestamp();
+        let max_staleness = self.max_staleness_seconds().get();
+        let mut values = ManagedVec::new();
+        for reporter in self.reporters().iter() {
+            let report_mapper = self.reports(feed, &reporter);
+            if report_mapper.is_empty() {
+                continue;
+            }
+
+            let report = report_mapper.get();
+            if now - report.timestamp <= max_staleness {
+                values.push(report.value);
+            }
+        }
+        values
+    }
+
+    /// The value at index `n` of `values` once sorted. Quadratic, which is cheaper than
+    /// sorting for at most `MAX_REPORTERS` values.
+    fn nth_smallest(&self, values: &ManagedVec<BigUint>, n: usize) -> BigUint {
+        for candidate in values.iter() {
+            let mut smaller = 0;
+            let mut equal = 0;
+            for value in values.iter() {
+                if *value < *candidate {
+                    smaller += 1;
+                } else if *value == *candidate {
+                    equal += 1;
+                }
+            }
+            if smaller <= n && n < smaller + equal {
+                return candidate.clone_value();
+            }
+        }
+
+        sc_panic!("Index out of range")
+    }
+
+    // Storage
+    #[storage_mapper("min_reports")]
+    fn min_reports(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("max_staleness_seconds")]
+    fn max_staleness_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("reporters")]
+    fn reporters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("feeds")]
+    fn feeds(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("reports")]
+    fn reports(&self, feed: &ManagedBuffer, reporter: &ManagedAddress) -> SingleValueMapper<Report<Self::Api>>;
+
+    // Events
+    #[event("reporter_added")]
+    fn reporter_added_event(&self, #[indexed] reporter: &ManagedAddress);
+
+    #[event("reporter_removed")]
+    fn reporter_removed_event(&self, #[indexed] reporter: &ManagedAddress);
+
+    #[event("report_submitted")]
+    fn report_submitted_event(
+        &self,
+        #[indexed] feed: &ManagedBuffer,
+        #[indexed] reporter: &ManagedAddress,
+        value: &BigUint,
+        timestamp: u64,
+    );
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Report<M: ManagedTypeApi> {
+    pub value: BigUint<M>,
+    pub timestamp: u64,
+}
+
+/// What a reporter signs. Binding the oracle's address stops a report from being replayed on
+/// another deployment.
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ReportPayload<M: ManagedTypeApi> {
+    pub oracle: ManagedAddress<M>,
+    pub feed: ManagedBuffer<M>,
+    pub value: BigUint<M>,
+    pub timestamp: u64,
+}
@@ -0,0 +1,11 @@
+multiversx_sc::imports!();
+
+/// Views of a quantum-dao game that markets settle on
+#[multiversx_sc::proxy]
+pub trait Game {
+    #[view(getProposalOutcome)]
+    fn get_proposal_outcome(&self, season_id: u32, proposal_id: u32) -> OptionalValue<bool>;
+
+    #[view(getSeasonWinner)]
+    fn get_season_winner(&self, season_id: u32) -> OptionalValue<ManagedAddress>;
+}
@@ -0,0 +1,356 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_proxy;
+pub mod treasury_proxy;
+
+pub const MAX_BPS: u64 = 10_000;
+/// Upper bound on the fee taken from a settled market's pool
+pub const MAX_FEE_BPS: u64 = 1_000;
+
+/// Prediction Market Smart Contract
+/// Parimutuel yes/no markets on game outcomes: whether a proposal passes, or whether a player
+/// wins a season. Bets close at a set block; settlement asks the game for the outcome through
+/// an async call. Winners share the whole pool pro rata to their stake, minus a fee sent to the
+/// treasury. If nobody bet on the outcome that happened, every bet is refunded.
+#[multiversx_sc::contract]
+pub trait PredictionMarket {
+    #[init]
+    fn init(&self, treasury: ManagedAddress, fee_bps: u64) {
+        require!(fee_bps <= MAX_FEE_BPS, "Fee too high");
+        self.treasury().set(&treasury);
+        self.fee_bps().set(fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(setTreasury)]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        self.treasury().set(&treasury);
+    }
+
+    /// Applies to markets settled from now on
+    #[only_owner]
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee_bps: u64) {
+        require!(fee_bps <= MAX_FEE_BPS, "Fee too high");
+        self.fee_bps().set(fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(createMarket)]
+    fn create_market(&self, game: ManagedAddress, question: MarketQuestion<Self::Api>, betting_end_block: u64) -> u64 {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        require!(
+            betting_end_block > self.blockchain().get_block_nonce(),
+            "Betting must end in the future"
+        );
+
+        let market_id = self.last_market_id().get() + 1;
+        let market = Market {
+            id: market_id,
+            game,
+            question,
+            betting_end_block,
+            yes_pool: BigUint::zero(),
+            no_pool: BigUint::zero(),
+            status: MarketStatus::Open,
+            outcome: false,
+            fee: BigUint::zero(),
+        };
+        self.markets(market_id).set(&market);
+        self.open_markets().insert(market_id);
+        self.last_market_id().set(market_id);
+
+        self.market_created_event(market_id, &market.game, betting_end_block);
+
+        market_id
+    }
+
+    /// Refund every bet of a market that cannot be settled, e.g. on a proposal never executed
+    #[only_owner]
+    #[endpoint(cancelMarket)]
+    fn cancel_market(&self, market_id: u64) {
+        let mut market = self.existing_market(market_id);
+        require!(market.status == MarketStatus::Open, "Market not open");
+
+        market.status = MarketStatus::Cancelled;
+        self.markets(market_id).set(&market);
+        self.open_markets().swap_remove(&market_id);
+
+        self.market_cancelled_event(market_id);
+    }
+
+    /// Bet the EGLD sent on `outcome` (true for yes)
+    #[payable("EGLD")]
+    #[endpoint(bet)]
+    fn bet(&self, market_id: u64, outcome: bool) {
+        let mut market = self.existing_market(market_id);
+        require!(market.status == MarketStatus::Open, "Market not open");
+        require!(
+            self.blockchain().get_block_nonce() < market.betting_end_block,
+            "Betting closed"
+        );
+        let amount = self.call_value().egld_value().clone_value();
+        require!(amount > 0, "Must send EGLD");
+
+        if outcome {
+            market.yes_pool += &amount;
+        } else {
+            market.no_pool += &amount;
+        }
+        self.markets(market_id).set(&market);
+
+        let caller = self.blockchain().get_caller();
+        self.stakes(market_id, &caller, outcome).update(|stake| *stake += &amount);
+
+        self.bet_placed_event(market_id, &caller, outcome, &amount);
+    }
+
+    /// Ask the game for the outcome once betting has closed. Anyone can call this; if the game
+    /// has no outcome yet, the market stays open for a later attempt.
+    #[endpoint(settle)]
+    fn settle(&self, market_id: u64) {
+        let mut market = self.existing_market(market_id);
+        require!(market.status == MarketStatus::Open, "Market not open");
+        require!(
+            self.blockchain().get_block_nonce() >= market.betting_end_block,
+            "Betting still open"
+        );
+
+        market.status = MarketStatus::Settling;
+        self.markets(market_id).set(&market);
+
+        match market.question {
+            MarketQuestion::ProposalPasses {
+                season_id,
+                proposal_id,
+            } => {
+                self.game_proxy(market.game)
+                    .get_proposal_outcome(season_id, proposal_id)
+                    .async_call()
+                    .with_callback(self.callbacks().proposal_outcome_callback(market_id))
+                    .call_and_exit();
+            },
+            MarketQuestion::SeasonWinner { season_id, player } => {
+                self.game_proxy(market.game)
+                    .get_season_winner(season_id)
+                    .async_call()
+                    .with_callback(self.callbacks().season_winner_callback(market_id, player))
+                    .call_and_exit();
+            },
+        }
+    }
+
+    #[callback]
+    fn proposal_outcome_callback(
+        &self,
+        market_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<OptionalValue<bool>>,
+    ) {
+        let outcome = match result {
+            ManagedAsyncCallResult::Ok(OptionalValue::Some(passed)) => Some(passed),
+            _ => None,
+        };
+        self.resolve_market(market_id, outcome);
+    }
+
+    #[callback]
+    fn season_winner_callback(
+        &self,
+        market_id: u64,
+        player: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<OptionalValue<ManagedAddress>>,
+    ) {
+        let outcome = match result {
+            ManagedAsyncCallResult::Ok(OptionalValue::Some(winner)) => Some(winner == player),
+            _ => None,
+        };
+        self.resolve_market(market_id, outcome);
+    }
+
+    /// Collect winnings of a resolved market, or the refund of a cancelled one or one nobody
+    /// predicted correctly
+    #[endpoint(claim)]
+    fn claim(&self, market_id: u64) -> BigUint {
+        let market = self.existing_market(market_id);
+        let caller = self.blockchain().get_caller();
+
+        let payout = match market.status {
+            MarketStatus::Resolved => {
+                let winning_pool = if market.outcome {
+                    &market.yes_pool
+                } else {
+                    &market.no_pool
+                };
+                if *winning_pool == 0 {
+                    self.stakes(market_id, &caller, true).take() + self.stakes(market_id, &caller, false).take()
+                } else {
+                    let stake = self.stakes(market_id, &caller, market.outcome).take();
+                    self.stakes(market_id, &caller, !market.outcome).clear();
+                    let pool = &market.yes_pool + &market.no_pool - &market.fee;
+                    stake * &pool / winning_pool
+                }
+            },
+            MarketStatus::Cancelled => {
+                self.stakes(market_id, &caller, true).take() + self.stakes(market_id, &caller, false).take()
+            },
+            _ => sc_panic!("Market not settled"),
+        };
+        require!(payout > 0, "Nothing to claim");
+
+        self.send().direct_egld(&caller, &payout);
+
+        self.claimed_event(market_id, &caller, &payout);
+
+        payout
+    }
+
+    // View functions
+    #[view(getMarket)]
+    fn get_market(&self, market_id: u64) -> OptionalValue<Market<Self::Api>> {
+        if self.markets(market_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.markets(market_id).get())
+    }
+
+    /// Markets still taking bets or awaiting settlement
+    #[view(getOpenMarkets)]
+    fn get_open_markets(&self) -> MultiValueEncoded<u64> {
+        self.open_markets().iter().collect()
+    }
+
+    /// Stakes of `bettor` on yes and on no
+    #[view(getStakes)]
+    fn get_stakes(&self, market_id: u64, bettor: ManagedAddress) -> MultiValue2<BigUint, BigUint> {
+        (
+            self.stakes(market_id, &bettor, true).get(),
+            self.stakes(market_id, &bettor, false).get(),
+        )
+            .into()
+    }
+
+    #[view(getFee)]
+    fn get_fee(&self) -> u64 {
+        self.fee_bps().get()
+    }
+
+    // Private functions
+    fn existing_market(&self, market_id: u64) -> Market<Self::Api> {
+        require!(!self.markets(market_id).is_empty(), "Market does not exist");
+        self.markets(market_id).get()
+    }
+
+    /// Record `outcome`, or reopen the market if the game had none to give. The fee is only
+    /// taken when someone predicted the outcome correctly.
+    fn resolve_market(&self, market_id: u64, outcome: Option<bool>) {
+        let mut market = self.markets(market_id).get();
+        let outcome = match outcome {
+            Some(outcome) => outcome,
+            None => {
+                market.status = MarketStatus::Open;
+                self.markets(market_id).set(&market);
+                return;
+            },
+        };
+
+        let winning_pool = if outcome { &market.yes_pool } else { &market.no_pool };
+        if *winning_pool > 0 {
+            let total_pool = &market.yes_pool + &market.no_pool;
+            market.fee = total_pool * &BigUint::from(self.fee_bps().get()) / MAX_BPS;
+        }
+        market.status = MarketStatus::Resolved;
+        market.outcome = outcome;
+        self.markets(market_id).set(&market);
+        self.open_markets().swap_remove(&market_id);
+
+        if market.fee > 0 {
+            self.treasury_proxy(self.treasury().get())
+                .deposit_fees()
+                .with_egld_transfer(market.fee.clone())
+                .transfer_execute();
+        }
+
+        self.market_resolved_event(market_id, outcome, &market.fee);
+    }
+
+    // Storage
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("fee_bps")]
+    fn fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_market_id")]
+    fn last_market_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("markets")]
+    fn markets(&self, market_id: u64) -> SingleValueMapper<Market<Self::Api>>;
+
+    #[storage_mapper("open_markets")]
+    fn open_markets(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("stakes")]
+    fn stakes(&self, market_id: u64, bettor: &ManagedAddress, outcome: bool) -> SingleValueMapper<BigUint>;
+
+    #[proxy]
+    fn game_proxy(&self, sc_address: ManagedAddress) -> game_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn treasury_proxy(&self, sc_address: ManagedAddress) -> treasury_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("market_created")]
+    fn market_created_event(&self, #[indexed] market_id: u64, #[indexed] game: &ManagedAddress, betting_end_block: u64);
+
+    #[event("market_cancelled")]
+    fn market_cancelled_event(&self, #[indexed] market_id: u64);
+
+    #[event("bet_placed")]
+    fn bet_placed_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] bettor: &ManagedAddress,
+        #[indexed] outcome: bool,
+        amount: &BigUint,
+    );
+
+    #[event("market_resolved")]
+    fn market_resolved_event(&self, #[indexed] market_id: u64, #[indexed] outcome: bool, fee: &BigUint);
+
+    #[event("claimed")]
+    fn claimed_event(&self, #[indexed] market_id: u64, #[indexed] bettor: &ManagedAddress, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub enum MarketQuestion<M: ManagedTypeApi> {
+    /// Yes if the proposal passes when executed
+    ProposalPasses { season_id: u32, proposal_id: u32 },
+    /// Yes if `player` tops the season's leaderboard when it ends
+    SeasonWinner { season_id: u32, player: ManagedAddress<M> },
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketStatus {
+    Open,
+    /// Waiting for the game's answer
+    Settling,
+    Resolved,
+    Cancelled,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Market<M: ManagedTypeApi> {
+    pub id: u64,
+    pub game: ManagedAddress<M>,
+    pub question: MarketQuestion<M>,
+    pub betting_end_block: u64,
+    pub yes_pool: BigUint<M>,
+    pub no_pool: BigUint<M>,
+    pub status: MarketStatus,
+    /// Meaningful once resolved; true for yes
+    pub outcome: bool,
+    pub fee: BigUint<M>,
+}
@@ -0,0 +1,9 @@
+multiversx_sc::imports!();
+
+/// Endpoint of the suite's treasury that receives the market fee
+#[multiversx_sc::proxy]
+pub trait Treasury {
+    #[payable("*")]
+    #[endpoint(depositFees)]
+    fn deposit_fees(&self);
+}
@@ -0,0 +1,406 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod reputation_proxy;
+
+/// Upper bound on projects in one round, which bounds the settlement loop
+pub const MAX_PROJECTS_PER_ROUND: u32 = 50;
+pub const MAX_PROJECT_NAME_LENGTH: usize = 64;
+
+/// QF Rounds Smart Contract
+/// Runs quadratic funding rounds for ecosystem projects. During a round the community
+/// contributes EGLD to projects; at settlement a matching pool is split between projects in
+/// proportion to `(sum of sqrt(contribution))^2 - sum of contributions`, so broad support counts
+/// for more than a few large contributions. Each contributor's total per project is tracked, so
+/// repeated contributions from one address are not counted as several supporters.
+///
+/// To keep fresh addresses from inflating the match, contributors need at least a minimum
+/// score in the reputation SBT contract. The score is read with a synchronous call, so that
+/// contract has to live in this contract's shard.
+#[multiversx_sc::contract]
+pub trait QfRounds {
+    #[init]
+    fn init(&self, reputation_sbt: ManagedAddress, min_reputation: u64) {
+        self.set_reputation_gate(reputation_sbt, min_reputation);
+    }
+
+    /// Contract queried for contributor reputation, and the score needed to contribute
+    #[only_owner]
+    #[endpoint(setReputationGate)]
+    fn set_reputation_gate(&self, reputation_sbt: ManagedAddress, min_reputation: u64) {
+        require!(
+            self.blockchain().is_smart_contract(&reputation_sbt),
+            "Reputation SBT must be a contract"
+        );
+        require!(
+            self.blockchain().get_shard_of_address(&reputation_sbt)
+                == self.blockchain().get_shard_of_address(&self.blockchain().get_sc_address()),
+            "Reputation SBT must be in this shard"
+        );
+        self.reputation_sbt().set(&reputation_sbt);
+        self.min_reputation().set(min_reputation);
+
+        self.reputation_gate_set_event(&reputation_sbt, min_reputation);
+    }
+
+    /// Open a round taking contributions from `start_block` until `end_block`; the EGLD sent
+    /// seeds its matching pool
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(createRound)]
+    fn create_round(&self, start_block: u64, end_block: u64) -> u32 {
+        require!(
+            start_block >= self.blockchain().get_block_nonce(),
+            "Start must not be in the past"
+        );
+        require!(end_block > start_block, "End must be after start");
+
+        let round_id = self.last_round_id().get() + 1;
+        let round = Round {
+            id: round_id,
+            start_block,
+            end_block,
+            matching_pool: self.call_value().egld_value().clone_value(),
+            project_count: 0,
+            settled: false,
+        };
+        self.rounds(round_id).set(&round);
+        self.last_round_id().set(round_id);
+
+        self.round_created_event(round_id, start_block, end_block, &round.matching_pool);
+
+        round_id
+    }
+
+    /// Add the EGLD sent to a round's matching pool; open to anyone until settlement
+    #[payable("EGLD")]
+    #[endpoint(fundMatchingPool)]
+    fn fund_matching_pool(&self, round_id: u32) {
+        let mut round = self.existing_round(round_id);
+        require!(!round.settled, "Round already settled");
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0, "Must send EGLD");
+
+        round.matching_pool += &payment;
+        self.rounds(round_id).set(&round);
+
+        self.matching_pool_funded_event(round_id, &self.blockchain().get_caller(), &payment);
+    }
+
+    /// Admit a project to a round before it starts; `recipient` receives its funding
+    #[only_owner]
+    #[endpoint(addProject)]
+    fn add_project(&self, round_id: u32, recipient: ManagedAddress, name: ManagedBuffer) -> u32 {
+        let mut round = self.existing_round(round_id);
+        require!(
+            self.blockchain().get_block_nonce() < round.start_block,
+            "Round already started"
+        );
+        require!(round.project_count < MAX_PROJECTS_PER_ROUND, "Too many projects");
+        require!(
+            !name.is_empty() && name.len() <= MAX_PROJECT_NAME_LENGTH,
+            "Invalid project name"
+        );
+
+        round.project_count += 1;
+        let project_id = round.project_count;
+        let project = Project {
+            id: project_id,
+            recipient,
+            name,
+            contributed: BigUint::zero(),
+            sqrt_sum: BigUint::zero(),
+            matching: BigUint::zero(),
+            claimed: false,
+        };
+        self.projects(round_id, project_id).set(&project);
+        self.rounds(round_id).set(&round);
+
+        self.project_added_event(round_id, project_id, &project.recipient, &project.name);
+
+        project_id
+    }
+
+    /// Contribute the EGLD sent to a project of a running round
+    #[payable("EGLD")]
+    #[endpoint(contribute)]
+    fn contribute(&self, round_id: u32, project_id: u32) {
+        let round = self.existing_round(round_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block >= round.start_block && current_block < round.end_block,
+            "Round not running"
+        );
+        let mut project = self.existing_project(round_id, project_id);
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0, "Must send EGLD");
+
+        let caller = self.blockchain().get_caller();
+        require!(caller != project.recipient, "Cannot fund own project");
+        let reputation = self
+            .reputation_proxy(self.reputation_sbt().get())
+            .get_reputation(caller.clone())
+            .execute_on_dest_context::<u64>();
+        require!(reputation >= self.min_reputation().get(), "Reputation too low");
+
+        let contribution_mapper = self.contributions(round_id, project_id, &caller);
+        let previous = contribution_mapper.get();
+        let total = &previous + &payment;
+        project.sqrt_sum -= previous.sqrt();
+        project.sqrt_sum += total.sqrt();
+        project.contributed += &payment;
+        contribution_mapper.set(&total);
+        self.projects(round_id, project_id).set(&project);
+        self.project_contributors(round_id, project_id).insert(caller.clone());
+
+        self.contributed_event(round_id, project_id, &caller, &payment);
+    }
+
+    /// Split the matching pool of an ended round between its projects. Anyone can call this;
+    /// whatever the split leaves over, including the whole pool if no project has a match,
+    /// returns to the owner.
+    #[endpoint(settle)]
+    fn settle(&self, round_id: u32) {
+        let mut round = self.existing_round(round_id);
+        require!(
+            self.blockchain().get_block_nonce() >= round.end_block,
+            "Round still running"
+        );
+        require!(!round.settled, "Round already settled");
+
+        let mut weights = ManagedVec::<Self::Api, BigUint>::new();
+        let mut total_weight = BigUint::zero();
+        for project_id in 1..=round.project_count {
+            let weight = self.match_weight(&self.projects(round_id, project_id).get());
+            total_weight += &weight;
+            weights.push(weight);
+        }
+
+        let mut distributed = BigUint::zero();
+        if total_weight > 0 {
+            for project_id in 1..=round.project_count {
+                let weight = weights.get((project_id - 1) as usize).clone_value();
+                if weight == 0 {
+                    continue;
+                }
+
+                let mut project = self.projects(round_id, project_id).get();
+                project.matching = &round.matching_pool * &weight / &total_weight;
+                distributed += &project.matching;
+                self.projects(round_id, project_id).set(&project);
+            }
+        }
+
+        round.settled = true;
+        self.rounds(round_id).set(&round);
+
+        let leftover = &round.matching_pool - &distributed;
+        if leftover > 0 {
+            self.send()
+                .direct_egld(&self.blockchain().get_owner_address(), &leftover);
+        }
+
+        self.round_settled_event(round_id, &distributed, &leftover);
+    }
+
+    /// Pay a project of a settled round its contributions plus its match
+    #[endpoint(claim)]
+    fn claim(&self, round_id: u32, project_id: u32) -> BigUint {
+        let round = self.existing_round(round_id);
+        require!(round.settled, "Round not settled");
+        let mut project = self.existing_project(round_id, project_id);
+        require!(
+            self.blockchain().get_caller() == project.recipient,
+            "Only the project recipient"
+        );
+        require!(!project.claimed, "Already claimed");
+
+        project.claimed = true;
+        self.projects(round_id, project_id).set(&project);
+
+        let payout = &project.contributed + &project.matching;
+        if payout > 0 {
+            self.send().direct_egld(&project.recipient, &payout);
+        }
+
+        self.claimed_event(round_id, project_id, &payout);
+
+        payout
+    }
+
+    // View functions
+    #[view(getRound)]
+    fn get_round(&self, round_id: u32) -> OptionalValue<Round<Self::Api>> {
+        if self.rounds(round_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.rounds(round_id).get())
+    }
+
+    #[view(getLastRoundId)]
+    fn get_last_round_id(&self) -> u32 {
+        self.last_round_id().get()
+    }
+
+    #[view(getProject)]
+    fn get_project(&self, round_id: u32, project_id: u32) -> OptionalValue<Project<Self::Api>> {
+        if self.projects(round_id, project_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.projects(round_id, project_id).get())
+    }
+
+    #[view(getContribution)]
+    fn get_contribution(&self, round_id: u32, project_id: u32, contributor: ManagedAddress) -> BigUint {
+        self.contributions(round_id, project_id, &contributor).get()
+    }
+
+    #[view(getProjectContributors)]
+    fn get_project_contributors(&self, round_id: u32, project_id: u32) -> MultiValueEncoded<ManagedAddress> {
+        self.project_contributors(round_id, project_id).iter().collect()
+    }
+
+    /// Match a project would get if its round were settled now
+    #[view(getEstimatedMatch)]
+    fn get_estimated_match(&self, round_id: u32, project_id: u32) -> BigUint {
+        let round = self.existing_round(round_id);
+        let project = self.existing_project(round_id, project_id);
+        if round.settled {
+            return project.matching;
+        }
+
+        let mut total_weight = BigUint::zero();
+        for other_id in 1..=round.project_count {
+            total_weight += self.match_weight(&self.projects(round_id, other_id).get());
+        }
+        if total_weight == 0 {
+            return BigUint::zero();
+        }
+
+        round.matching_pool * self.match_weight(&project) / total_weight
+    }
+
+    #[view(getReputationGate)]
+    fn get_reputation_gate(&self) -> MultiValue2<ManagedAddress, u64> {
+        (self.reputation_sbt().get(), self.min_reputation().get()).into()
+    }
+
+    // Private functions
+    fn existing_round(&self, round_id: u32) -> Round<Self::Api> {
+        require!(!self.rounds(round_id).is_empty(), "Round does not exist");
+        self.rounds(round_id).get()
+    }
+
+    fn existing_project(&self, round_id: u32, project_id: u32) -> Project<Self::Api> {
+        require!(!self.projects(round_id, project_id).is_empty(), "Project does not exist");
+        self.projects(round_id, project_id).get()
+    }
+
+    /// `(sum of sqrt(contribution))^2 - sum of contributions`, floored at zero since the
+    /// integer square roots round down
+    fn match_weight(&self, project: &Project<Self::Api>) -> BigUint {
+        let squared = &project.sqrt_sum * &project.sqrt_sum;
+        if squared > project.contributed {
+            squared - &project.contributed
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    // Storage
+    #[storage_mapper("reputation_sbt")]
+    fn reputation_sbt(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("min_reputation")]
+    fn min_reputation(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_round_id")]
+    fn last_round_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("rounds")]
+    fn rounds(&self, round_id: u32) -> SingleValueMapper<Round<Self::Api>>;
+
+    #[storage_mapper("projects")]
+    fn projects(&self, round_id: u32, project_id: u32) -> SingleValueMapper<Project<Self::Api>>;
+
+    #[storage_mapper("contributions")]
+    fn contributions(
+        &self,
+        round_id: u32,
+        project_id: u32,
+        contributor: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("project_contributors")]
+    fn project_contributors(&self, round_id: u32, project_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[proxy]
+    fn reputation_proxy(&self, sc_address: ManagedAddress) -> reputation_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("reputation_gate_set")]
+    fn reputation_gate_set_event(&self, #[indexed] reputation_sbt: &ManagedAddress, min_reputation: u64);
+
+    #[event("round_created")]
+    fn round_created_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] start_block: u64,
+        #[indexed] end_block: u64,
+        matching_pool: &BigUint,
+    );
+
+    #[event("matching_pool_funded")]
+    fn matching_pool_funded_event(&self, #[indexed] round_id: u32, #[indexed] funder: &ManagedAddress, amount: &BigUint);
+
+    #[event("project_added")]
+    fn project_added_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] project_id: u32,
+        #[indexed] recipient: &ManagedAddress,
+        name: &ManagedBuffer,
+    );
+
+    #[event("contributed")]
+    fn contributed_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] project_id: u32,
+        #[indexed] contributor: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("round_settled")]
+    fn round_settled_event(&self, #[indexed] round_id: u32, distributed: &BigUint, returned: &BigUint);
+
+    #[event("claimed")]
+    fn claimed_event(&self, #[indexed] round_id: u32, #[indexed] project_id: u32, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Round<M: ManagedTypeApi> {
+    pub id: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub matching_pool: BigUint<M>,
+    /// Projects are numbered from 1 to this count
+    pub project_count: u32,
+    pub settled: bool,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Project<M: ManagedTypeApi> {
+    pub id: u32,
+    pub recipient: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+    pub contributed: BigUint<M>,
+    /// Sum over contributors of the square root of their total contribution
+    pub sqrt_sum: BigUint<M>,
+    /// Set at settlement
+    pub matching: BigUint<M>,
+    pub claimed: bool,
+}
@@ -0,0 +1,8 @@
+multiversx_sc::imports!();
+
+/// View of the reputation SBT contract used to gate contributors
+#[multiversx_sc::proxy]
+pub trait Reputation {
+    #[view(getReputation)]
+    fn get_reputation(&self, holder: ManagedAddress) -> u64;
+}
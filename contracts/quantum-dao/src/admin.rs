@@ -0,0 +1,1202 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, treasury_proxy, types::*};
+
+/// Access control, pausing and operator configuration, plus the guards the other modules rely on
+#[multiversx_sc::module]
+pub trait AdminModule: crate::storage::StorageModule + crate::events::EventsModule {
+    /// Create or overwrite a named season theme
+    #[endpoint(setSeasonTheme)]
+    fn set_season_theme(&self, theme_id: u32, theme: SeasonTheme<Self::Api>) {
+        self.require_role(Role::Admin);
+
+        require!(theme_id > 0, "Theme id must be positive");
+        require!(!theme.name.is_empty(), "Theme name required");
+        require!(theme.duration_blocks > 0, "Season duration must be positive");
+        require!(
+            !theme.superposition_voting || theme.reveal_duration_blocks > 0,
+            "Reveal window must be positive"
+        );
+        require!(
+            theme.sudden_death.duration_blocks == 0 || theme.sudden_death.max_voting_blocks > 0,
+            "Voting window must be positive"
+        );
+
+        let old_theme = if self.theme_ids().contains(&theme_id) {
+            self.audit_encode(&self.season_themes(theme_id).get())
+        } else {
+            ManagedBuffer::new()
+        };
+        self.season_themes(theme_id).set(&theme);
+        self.theme_ids().insert(theme_id);
+
+        self.audit(
+            AdminAction::SetSeasonTheme,
+            &theme_id,
+            &old_theme,
+            &self.audit_encode(&theme),
+        );
+        self.season_theme_set_event(theme_id, &theme.name);
+    }
+
+    #[endpoint(removeSeasonTheme)]
+    fn remove_season_theme(&self, theme_id: u32) {
+        self.require_role(Role::Admin);
+
+        require!(self.theme_ids().swap_remove(&theme_id), "Theme does not exist");
+        let old_theme = self.season_themes(theme_id).take();
+
+        self.audit(
+            AdminAction::RemoveSeasonTheme,
+            &theme_id,
+            &self.audit_encode(&old_theme),
+            &ManagedBuffer::new(),
+        );
+    }
+
+    #[endpoint(setPowerUpTokenId)]
+    fn set_power_up_token_id(&self, token_id: TokenIdentifier) {
+        self.require_role(Role::Admin);
+
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        let old_token_id = self.power_up_token_id().get();
+        self.power_up_token_id().set(&token_id);
+
+        self.audit(AdminAction::SetPowerUpToken, &(), &old_token_id, &token_id);
+    }
+
+    #[endpoint(setCheckInConfig)]
+    fn set_check_in_config(&self, config: CheckInConfig) {
+        self.require_role(Role::Admin);
+
+        require!(config.epoch_blocks > 0, "Check-in epoch must be positive");
+        require!(config.max_streak > 0, "Max streak must be positive");
+        let old_config = self.game_config().get().check_in;
+        self.game_config().update(|game_config| game_config.check_in = config.clone());
+
+        self.audit(AdminAction::SetCheckInConfig, &(), &old_config, &config);
+    }
+
+    #[endpoint(setQuantumEventConfig)]
+    fn set_quantum_event_config(&self, config: QuantumEventConfig) {
+        self.require_role(Role::Admin);
+
+        require!(config.interval_blocks > 0, "Event interval must be positive");
+        let old_config = self.game_config().get().quantum_events;
+        self.game_config().update(|game_config| game_config.quantum_events = config.clone());
+
+        self.audit(AdminAction::SetQuantumEventConfig, &(), &old_config, &config);
+    }
+
+    /// Set the entry fee for the current season; zero disables entry-fee mode.
+    /// Can only be changed before anyone has joined or scored.
+    #[endpoint(setEntryFee)]
+    fn set_entry_fee(&self, entry_fee: BigUint) {
+        self.require_role(Role::Admin);
+
+        let season_id = self.current_season_id().get();
+        require!(
+            self.joined_players(season_id).is_empty() && self.players(season_id).is_empty(),
+            "Season already has participants"
+        );
+        let old_entry_fee = self.entry_fee(season_id).get();
+        self.entry_fee(season_id).set(&entry_fee);
+
+        self.audit(AdminAction::SetEntryFee, &season_id, &old_entry_fee, &entry_fee);
+    }
+
+    /// Pause every game endpoint; callable by guardians and above, or only the admin multisig once set
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.require_critical_access(Role::Guardian);
+        require!(!self.paused().get(), "Game already paused");
+
+        self.paused().set(true);
+        self.paused_at_block().set(self.blockchain().get_block_nonce());
+
+        self.audit(AdminAction::SetPaused, &(), &false, &true);
+        self.game_paused_event(&self.blockchain().get_caller());
+    }
+
+    /// Resume the game, extending the season end by the number of blocks spent paused
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.require_critical_access(Role::Guardian);
+        require!(self.paused().get(), "Game not paused");
+        require!(self.migration_cursor().is_empty(), "Migration in progress");
+
+        let paused_blocks = self.blockchain().get_block_nonce() - self.paused_at_block().get();
+        self.paused().set(false);
+        self.paused_at_block().clear();
+
+        self.extend_game_timing(paused_blocks);
+
+        self.audit(AdminAction::SetPaused, &(), &true, &false);
+        self.game_unpaused_event(&self.blockchain().get_caller(), paused_blocks);
+    }
+
+    /// Disable or re-enable a single flow without pausing the whole game
+    #[endpoint(setFlowPaused)]
+    fn set_flow_paused(&self, flow: GameFlow, paused: bool) {
+        self.require_role(Role::Admin);
+
+        if paused {
+            require!(self.paused_flows().insert(flow), "Flow already paused");
+        } else {
+            require!(self.paused_flows().swap_remove(&flow), "Flow not paused");
+        }
+
+        self.audit(AdminAction::SetFlowPaused, &flow, &!paused, &paused);
+        self.flow_paused_event(flow, paused);
+    }
+
+    /// Block an address from creating proposals, voting and claiming rewards
+    #[endpoint(addToBlacklist)]
+    fn add_to_blacklist(&self, address: ManagedAddress) {
+        self.require_role(Role::Moderator);
+        require!(self.blacklist().insert(address.clone()), "Address already blacklisted");
+
+        self.audit(AdminAction::Blacklist, &address, &false, &true);
+        self.blacklist_added_event(&address);
+    }
+
+    #[endpoint(removeFromBlacklist)]
+    fn remove_from_blacklist(&self, address: ManagedAddress) {
+        self.require_role(Role::Moderator);
+        require!(self.blacklist().swap_remove(&address), "Address not blacklisted");
+
+        self.audit(AdminAction::Blacklist, &address, &true, &false);
+        self.blacklist_removed_event(&address);
+    }
+
+    /// Restrict participation to whitelisted addresses, e.g. for community-only tournaments
+    #[endpoint(setWhitelistMode)]
+    fn set_whitelist_mode(&self, enabled: bool) {
+        self.require_role(Role::Admin);
+        let was_enabled = self.whitelist_mode().get();
+        self.whitelist_mode().set(enabled);
+
+        self.audit(AdminAction::SetWhitelistMode, &(), &was_enabled, &enabled);
+        self.whitelist_mode_changed_event(enabled);
+    }
+
+    #[endpoint(addToWhitelist)]
+    fn add_to_whitelist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::Admin);
+        self.require_bounded_batch(addresses.len());
+
+        for address in addresses {
+            self.access_requests().swap_remove(&address);
+            if self.whitelist().insert(address.clone()) {
+                self.audit(AdminAction::Whitelist, &address, &false, &true);
+                self.whitelist_added_event(&address);
+            }
+        }
+    }
+
+    #[endpoint(removeFromWhitelist)]
+    fn remove_from_whitelist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::Admin);
+        self.require_bounded_batch(addresses.len());
+
+        for address in addresses {
+            if self.whitelist().swap_remove(&address) {
+                self.audit(AdminAction::Whitelist, &address, &true, &false);
+                self.whitelist_removed_event(&address);
+            }
+        }
+    }
+
+    /// Ask to be whitelisted; admins approve requests with `addToWhitelist` or drop them
+    /// with `rejectAccess`
+    #[endpoint(requestAccess)]
+    fn request_access(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.whitelist().contains(&caller), "Already whitelisted");
+        require!(!self.blacklist().contains(&caller), "Address is blacklisted");
+        require!(self.access_requests().insert(caller.clone()), "Access already requested");
+
+        self.access_requested_event(&caller);
+    }
+
+    #[endpoint(rejectAccess)]
+    fn reject_access(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::Admin);
+        self.require_bounded_batch(addresses.len());
+
+        for address in addresses {
+            if self.access_requests().swap_remove(&address) {
+                self.audit(AdminAction::RejectAccess, &address, &true, &false);
+            }
+        }
+    }
+
+    /// Hand critical actions (treasury withdrawals, pausing, migrations) to a multisig contract.
+    /// Once set, only the multisig itself can change it.
+    #[endpoint(setAdminMultisig)]
+    fn set_admin_multisig(&self, multisig: ManagedAddress) {
+        self.require_critical_access(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&multisig), "Multisig must be a contract");
+
+        let old_multisig = if self.admin_multisig().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.admin_multisig().get()
+        };
+        self.admin_multisig().set(&multisig);
+
+        self.audit(AdminAction::SetAdminMultisig, &(), &old_multisig, &multisig);
+        self.admin_multisig_set_event(&multisig);
+    }
+
+    /// Let `router` act for its callers through `voteFor` and `claimPrizeFor`
+    #[endpoint(setTrustedRouter)]
+    fn set_trusted_router(&self, router: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&router), "Router must be a contract");
+        let old_router = if self.trusted_router().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.trusted_router().get()
+        };
+        self.trusted_router().set(&router);
+
+        self.audit(AdminAction::SetTrustedRouter, &(), &old_router, &router);
+    }
+
+    /// Let `aggregator` forward batched votes through `submitAggregatedVotes`
+    #[endpoint(addVoteAggregator)]
+    fn add_vote_aggregator(&self, aggregator: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&aggregator), "Aggregator must be a contract");
+        require!(self.vote_aggregators().insert(aggregator.clone()), "Already a vote aggregator");
+
+        self.audit(AdminAction::SetVoteAggregator, &aggregator, &false, &true);
+    }
+
+    #[endpoint(removeVoteAggregator)]
+    fn remove_vote_aggregator(&self, aggregator: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.vote_aggregators().swap_remove(&aggregator), "Not a vote aggregator");
+
+        self.audit(AdminAction::SetVoteAggregator, &aggregator, &true, &false);
+    }
+
+    /// Give an address a role. The owner role moves only through `transferOwnership`.
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(role != Role::Owner, "Owner role moves with transferOwnership");
+        require!(self.role_members(role).insert(address.clone()), "Address already has this role");
+
+        self.audit(AdminAction::SetRole, &(role, &address), &false, &true);
+        self.role_granted_event(role, &address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.role_members(role).swap_remove(&address), "Address does not have this role");
+
+        self.audit(AdminAction::SetRole, &(role, &address), &true, &false);
+        self.role_revoked_event(role, &address);
+    }
+
+    /// First step of handing over game ownership; takes effect once `new_owner` calls
+    /// `acceptOwnership`, so a mistyped address can simply be replaced or cancelled
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
+        self.require_critical_access(Role::Owner);
+        require!(!new_owner.is_zero(), "Invalid owner address");
+        require!(new_owner != self.game_owner().get(), "Already the owner");
+
+        self.pending_owner().set(&new_owner);
+
+        self.ownership_transfer_proposed_event(&self.game_owner().get(), &new_owner);
+    }
+
+    #[endpoint(cancelOwnershipTransfer)]
+    fn cancel_ownership_transfer(&self) {
+        self.require_critical_access(Role::Owner);
+        require!(!self.pending_owner().is_empty(), "No ownership transfer pending");
+
+        self.pending_owner().clear();
+    }
+
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.pending_owner().is_empty() && caller == self.pending_owner().get(),
+            "Caller is not the pending owner"
+        );
+
+        let previous_owner = self.game_owner().get();
+        self.pending_owner().clear();
+        self.game_owner().set(&caller);
+
+        self.audit(AdminAction::TransferOwnership, &(), &previous_owner, &caller);
+        self.ownership_transferred_event(&previous_owner, &caller);
+    }
+
+    /// Offer a role the caller holds to `to`; the caller keeps it until `to` accepts
+    #[endpoint(transferRole)]
+    fn transfer_role(&self, role: Role, to: ManagedAddress) {
+        require!(role != Role::Owner, "Use transferOwnership for the owner role");
+
+        let caller = self.blockchain().get_caller();
+        require!(self.role_members(role).contains(&caller), "Caller does not hold this role");
+        require!(!self.role_members(role).contains(&to), "Address already has this role");
+
+        self.pending_role_transfer(role, &caller).set(&to);
+
+        self.role_transfer_proposed_event(role, &caller, &to);
+    }
+
+    #[endpoint(cancelRoleTransfer)]
+    fn cancel_role_transfer(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.pending_role_transfer(role, &caller).is_empty(),
+            "No role transfer pending"
+        );
+
+        self.pending_role_transfer(role, &caller).clear();
+    }
+
+    #[endpoint(acceptRole)]
+    fn accept_role(&self, role: Role, from: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let pending_mapper = self.pending_role_transfer(role, &from);
+        require!(
+            !pending_mapper.is_empty() && caller == pending_mapper.get(),
+            "Caller is not the pending role holder"
+        );
+        pending_mapper.clear();
+
+        let mut members = self.role_members(role);
+        require!(members.swap_remove(&from), "Previous holder lost the role");
+        members.insert(caller.clone());
+
+        self.audit(AdminAction::TransferRole, &role, &from, &caller);
+        self.role_transferred_event(role, &from, &caller);
+    }
+
+    /// Set the maximum number of blocks a season can be extended by governance
+    #[endpoint(setMaxGameExtension)]
+    fn set_max_game_extension(&self, max_extension_blocks: u64) {
+        self.require_role(Role::Admin);
+
+        let old_max_extension_blocks = self.game_config().get().max_game_extension_blocks;
+        self.game_config()
+            .update(|game_config| game_config.max_game_extension_blocks = max_extension_blocks);
+
+        self.audit(
+            AdminAction::SetMaxGameExtension,
+            &(),
+            &old_max_extension_blocks,
+            &max_extension_blocks,
+        );
+    }
+
+    #[endpoint(setEnergyConfig)]
+    fn set_energy_config(&self, config: EnergyConfig) {
+        self.require_role(Role::Admin);
+
+        require!(config.blocks_per_energy_point > 0, "Regen interval must be positive");
+        require!(
+            config.proposal_cost <= config.max_energy && config.vote_cost <= config.max_energy,
+            "Action cost exceeds max energy"
+        );
+        let old_config = self.game_config().get().energy;
+        self.game_config().update(|game_config| game_config.energy = config.clone());
+
+        self.audit(AdminAction::SetEnergyConfig, &(), &old_config, &config);
+    }
+
+    /// Replace every fee setting at once; a zero proposal fee disables it
+    #[endpoint(setFeeConfig)]
+    fn set_fee_config(&self, config: FeeConfig<Self::Api>) {
+        self.require_role(Role::Admin);
+
+        require!(config.prediction_fee_bps <= MAX_FEE_BPS, "Prediction fee too high");
+        require!(config.betting_fee_bps <= MAX_FEE_BPS, "Betting fee too high");
+        require!(config.booster_prize_pool_share_bps <= MAX_BPS, "Share too high");
+        let old_config = self.game_config().get().fees;
+        self.game_config().update(|game_config| game_config.fees = config.clone());
+
+        self.audit(AdminAction::SetFeeConfig, &(), &old_config, &config);
+        self.fee_config_updated_event(&config);
+    }
+
+    #[endpoint(withdrawTreasury)]
+    fn withdraw_treasury(&self, to: ManagedAddress, amount: BigUint) {
+        self.require_critical_access(Role::Owner);
+
+        let treasury = self.treasury().get();
+        require!(amount <= treasury, "Insufficient treasury");
+
+        let remaining = &treasury - &amount;
+        self.treasury().set(&remaining);
+        self.send().direct_egld(&to, &amount);
+
+        self.audit(AdminAction::WithdrawTreasury, &to, &treasury, &remaining);
+        self.treasury_withdrawn_event(&to, &amount);
+    }
+
+    /// Send the collected fees to the suite treasury vault through its `depositFees`
+    #[endpoint(setFeeVault)]
+    fn set_fee_vault(&self, vault: ManagedAddress) {
+        self.require_critical_access(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&vault), "Fee vault must be a contract");
+        let old_vault = if self.fee_vault_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.fee_vault_address().get()
+        };
+        self.fee_vault_address().set(&vault);
+
+        self.audit(AdminAction::SetFeeVault, &(), &old_vault, &vault);
+    }
+
+    /// Forward every fee collected so far to the fee vault. The transfer is not awaited, so
+    /// the vault may live on another shard.
+    #[endpoint(forwardFees)]
+    fn forward_fees(&self) {
+        self.require_role(Role::Keeper);
+        require!(!self.fee_vault_address().is_empty(), "Fee vault not configured");
+
+        let amount = self.treasury().take();
+        require!(amount > 0, "No fees to forward");
+
+        let vault = self.fee_vault_address().get();
+        self.audit(AdminAction::ForwardFees, &vault, &amount, &BigUint::zero());
+        self.fees_forwarded_event(&vault, &amount);
+
+        self.treasury_proxy(vault)
+            .deposit_fees()
+            .with_egld_transfer(amount)
+            .transfer_execute();
+    }
+
+    /// Toggle superposition voting for proposals created from now on
+    #[endpoint(setSuperpositionMode)]
+    fn set_superposition_mode(&self, enabled: bool, reveal_duration_blocks: u64) {
+        self.require_role(Role::Admin);
+
+        require!(!enabled || reveal_duration_blocks > 0, "Reveal window must be positive");
+        let old_config = self.game_config().get();
+        self.game_config().update(|game_config| {
+            game_config.superposition_mode = enabled;
+            game_config.reveal_duration_blocks = reveal_duration_blocks;
+        });
+
+        self.audit(
+            AdminAction::SetSuperpositionMode,
+            &(),
+            &(old_config.superposition_mode, old_config.reveal_duration_blocks),
+            &(enabled, reveal_duration_blocks),
+        );
+    }
+
+    #[endpoint(setEntanglementConfig)]
+    fn set_entanglement_config(&self, config: EntanglementConfig) {
+        self.require_role(Role::Admin);
+
+        require!(config.share_bps <= MAX_BPS, "Share too high");
+        let old_config = self.game_config().get().entanglement;
+        self.game_config().update(|game_config| game_config.entanglement = config.clone());
+
+        self.audit(AdminAction::SetEntanglementConfig, &(), &old_config, &config);
+    }
+
+    /// Put a booster on sale; a zero duration takes it off sale
+    #[endpoint(setBoosterOffer)]
+    fn set_booster_offer(&self, booster_type: BoosterType, price: BigUint, duration_blocks: u64) {
+        self.require_role(Role::Admin);
+
+        let old_offer = if self.booster_offers(booster_type).is_empty() {
+            ManagedBuffer::new()
+        } else {
+            self.audit_encode(&self.booster_offers(booster_type).get())
+        };
+
+        if duration_blocks == 0 {
+            self.booster_offers(booster_type).clear();
+            self.audit(
+                AdminAction::SetBoosterOffer,
+                &booster_type,
+                &old_offer,
+                &ManagedBuffer::new(),
+            );
+            return;
+        }
+
+        require!(price > 0, "Booster price must be positive");
+        let offer = BoosterOffer {
+            price,
+            duration_blocks,
+        };
+        self.booster_offers(booster_type).set(&offer);
+
+        self.audit(
+            AdminAction::SetBoosterOffer,
+            &booster_type,
+            &old_offer,
+            &self.audit_encode(&offer),
+        );
+    }
+
+    /// Configure the sudden-death final phase; a zero duration disables it
+    #[endpoint(setSuddenDeathConfig)]
+    fn set_sudden_death_config(&self, config: SuddenDeathConfig) {
+        self.require_role(Role::Admin);
+
+        require!(
+            config.duration_blocks == 0 || config.max_voting_blocks > 0,
+            "Voting window must be positive"
+        );
+        let old_config = self.game_config().get().sudden_death;
+        self.game_config().update(|game_config| game_config.sudden_death = config.clone());
+
+        self.audit(AdminAction::SetSuddenDeathConfig, &(), &old_config, &config);
+    }
+
+    /// Configure how quorum, pass threshold and proposal fee scale with participation
+    #[endpoint(setDifficultyConfig)]
+    fn set_difficulty_config(&self, config: DifficultyConfig<Self::Api>) {
+        self.require_role(Role::Admin);
+
+        require!(config.min_quorum <= config.max_quorum, "Invalid quorum bounds");
+        require!(
+            config.base_pass_threshold_bps >= HALF_BPS
+                && config.base_pass_threshold_bps <= config.max_pass_threshold_bps
+                && config.max_pass_threshold_bps <= MAX_BPS,
+            "Invalid pass threshold bounds"
+        );
+        require!(config.quorum_stake_bps <= MAX_BPS, "Invalid quorum stake share");
+        require!(config.fee_player_step > 0, "Fee player step must be positive");
+
+        let old_config = if self.difficulty_config().is_empty() {
+            ManagedBuffer::new()
+        } else {
+            self.audit_encode(&self.difficulty_config().get())
+        };
+        self.difficulty_config().set(&config);
+
+        self.audit(
+            AdminAction::SetDifficultyConfig,
+            &(),
+            &old_config,
+            &self.audit_encode(&config),
+        );
+    }
+
+    #[endpoint(setBoostDuration)]
+    fn set_boost_duration(&self, duration_blocks: u64) {
+        self.require_role(Role::Admin);
+
+        require!(duration_blocks > 0, "Duration must be positive");
+        let old_duration_blocks = self.game_config().get().boost_duration_blocks;
+        self.game_config()
+            .update(|game_config| game_config.boost_duration_blocks = duration_blocks);
+
+        self.audit(AdminAction::SetBoostDuration, &(), &old_duration_blocks, &duration_blocks);
+    }
+
+    /// Change the current season length. Mid-season changes require the game to be paused.
+    #[endpoint(setGameDuration)]
+    fn set_game_duration(&self, game_duration_blocks: u64) {
+        self.require_role(Role::Admin);
+
+        require!(game_duration_blocks > 0, "Duration must be positive");
+        let game_active = self.is_game_active();
+        self.require_settings_changeable(game_active);
+
+        let season_id = self.current_season_id().get();
+        let start_block = self.game_start_block().get();
+        if game_active {
+            require!(
+                start_block + game_duration_blocks >= self.blockchain().get_block_nonce(),
+                "Season cannot end in the past"
+            );
+        }
+
+        let old_duration_blocks = self.game_duration_blocks().get();
+        self.game_duration_blocks().set(game_duration_blocks);
+        self.seasons(season_id)
+            .update(|season| season.end_block = start_block + game_duration_blocks);
+
+        self.audit(
+            AdminAction::SetGameDuration,
+            &season_id,
+            &old_duration_blocks,
+            &game_duration_blocks,
+        );
+        self.game_duration_updated_event(season_id, game_duration_blocks);
+    }
+
+    /// Change the grand prize NFT collection. Mid-season changes require the game to be paused.
+    #[endpoint(setNftRewardTokenId)]
+    fn set_nft_reward_token_id(&self, token_id: TokenIdentifier) {
+        self.require_role(Role::Admin);
+
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
+        self.require_settings_changeable(self.is_game_active());
+
+        let old_token_id = self.nft_reward_token_id().get();
+        self.nft_reward_token_id().set(&token_id);
+
+        self.audit(AdminAction::SetNftRewardToken, &(), &old_token_id, &token_id);
+        self.nft_reward_token_updated_event(&token_id);
+    }
+
+    /// Schedule moving every contract fund to `recovery_address` and pause the game.
+    /// Execution is possible only after `EMERGENCY_WITHDRAW_DELAY_BLOCKS`; until then any guardian can cancel.
+    #[endpoint(initiateEmergencyWithdraw)]
+    fn initiate_emergency_withdraw(&self, recovery_address: ManagedAddress) {
+        self.require_critical_access(Role::Guardian);
+        require!(self.emergency_withdraw().is_empty(), "Emergency withdraw already pending");
+        require!(!recovery_address.is_zero(), "Invalid recovery address");
+
+        let caller = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
+        let executable_block = current_block + EMERGENCY_WITHDRAW_DELAY_BLOCKS;
+        let pending = EmergencyWithdraw {
+            recovery_address: recovery_address.clone(),
+            initiated_by: caller.clone(),
+            initiated_block: current_block,
+            executable_block,
+        };
+        self.emergency_withdraw().set(&pending);
+
+        if !self.paused().get() {
+            self.paused().set(true);
+            self.paused_at_block().set(current_block);
+            self.audit(AdminAction::SetPaused, &(), &false, &true);
+            self.game_paused_event(&caller);
+        }
+
+        self.audit(
+            AdminAction::EmergencyWithdraw,
+            &(),
+            &ManagedBuffer::new(),
+            &self.audit_encode(&pending),
+        );
+        self.emergency_withdraw_initiated_event(&caller, &recovery_address, executable_block);
+    }
+
+    #[endpoint(cancelEmergencyWithdraw)]
+    fn cancel_emergency_withdraw(&self) {
+        self.require_critical_access(Role::Guardian);
+        require!(!self.emergency_withdraw().is_empty(), "No emergency withdraw pending");
+
+        let pending = self.emergency_withdraw().get();
+        require!(
+            self.blockchain().get_block_nonce() < pending.executable_block,
+            "Cancellation window closed"
+        );
+
+        self.emergency_withdraw().clear();
+
+        self.audit(
+            AdminAction::EmergencyWithdraw,
+            &(),
+            &self.audit_encode(&pending),
+            &ManagedBuffer::new(),
+        );
+        self.emergency_withdraw_cancelled_event(&self.blockchain().get_caller());
+    }
+
+    /// Send the whole EGLD balance, and any held power-up tokens, to the recovery address.
+    /// The treasury and current prize pool are zeroed since nothing backs them afterwards.
+    #[endpoint(executeEmergencyWithdraw)]
+    fn execute_emergency_withdraw(&self) {
+        self.require_critical_access(Role::Guardian);
+        require!(!self.emergency_withdraw().is_empty(), "No emergency withdraw pending");
+
+        let pending = self.emergency_withdraw().take();
+        require!(
+            self.blockchain().get_block_nonce() >= pending.executable_block,
+            "Emergency withdraw still timelocked"
+        );
+
+        self.treasury().clear();
+        self.prize_pool(self.current_season_id().get()).clear();
+        self.total_staked().clear();
+
+        let egld_balance = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        if egld_balance > 0 {
+            self.send().direct_egld(&pending.recovery_address, &egld_balance);
+        }
+
+        if !self.power_up_token_id().is_empty() {
+            let token_id = self.power_up_token_id().get();
+            let token_balance = self
+                .blockchain()
+                .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0);
+            if token_balance > 0 {
+                self.send()
+                    .direct_esdt(&pending.recovery_address, &token_id, 0, &token_balance);
+            }
+        }
+
+        self.audit(
+            AdminAction::EmergencyWithdraw,
+            &(),
+            &self.audit_encode(&pending),
+            &ManagedBuffer::new(),
+        );
+        self.emergency_withdraw_executed_event(&pending.recovery_address, &egld_balance);
+    }
+
+    /// Close player endpoints from `start_block` until `end_block`.
+    /// The game and current season are extended by the window length up front.
+    #[endpoint(scheduleMaintenance)]
+    fn schedule_maintenance(&self, start_block: u64, end_block: u64) {
+        self.require_role(Role::Admin);
+        require!(start_block < end_block, "Invalid maintenance window");
+        require!(
+            start_block > self.blockchain().get_block_nonce(),
+            "Maintenance must start in the future"
+        );
+
+        self.prune_maintenance_windows();
+        require!(
+            self.maintenance_windows().len() < MAX_MAINTENANCE_WINDOWS,
+            "Too many maintenance windows"
+        );
+        for window in self.maintenance_windows().iter() {
+            require!(
+                end_block <= window.start_block || start_block >= window.end_block,
+                "Overlaps a scheduled window"
+            );
+        }
+
+        let window = MaintenanceWindow {
+            start_block,
+            end_block,
+        };
+        self.maintenance_windows().push(&window);
+        self.extend_game_timing(end_block - start_block);
+
+        self.audit(AdminAction::Maintenance, &window, &false, &true);
+        self.maintenance_scheduled_event(start_block, end_block);
+    }
+
+    /// Drop a window that has not started yet, reverting its timing extension
+    #[endpoint(cancelMaintenance)]
+    fn cancel_maintenance(&self, start_block: u64) {
+        self.require_role(Role::Admin);
+        require!(
+            start_block > self.blockchain().get_block_nonce(),
+            "Maintenance already started"
+        );
+
+        let mut windows = self.maintenance_windows();
+        let index = windows
+            .iter()
+            .position(|window| window.start_block == start_block);
+        require!(index.is_some(), "Unknown maintenance window");
+
+        let index = index.unwrap() + 1;
+        let window = windows.get(index);
+        windows.swap_remove(index);
+
+        let blocks = window.end_block - window.start_block;
+        self.game_duration_blocks().update(|duration| *duration -= blocks);
+        let season_id = self.current_season_id().get();
+        self.seasons(season_id).update(|season| season.end_block -= blocks);
+
+        self.audit(AdminAction::Maintenance, &window, &true, &false);
+        self.maintenance_cancelled_event(window.start_block, window.end_block);
+    }
+
+    /// Configure council mode; a smaller seat count applies from the next election
+    #[endpoint(setCouncilConfig)]
+    fn set_council_config(&self, config: CouncilConfig) {
+        self.require_role(Role::Admin);
+
+        require!(
+            config.seats > 0 && config.seats <= MAX_COUNCIL_SEATS,
+            "Invalid council seat count"
+        );
+        require!(config.term_blocks > 0, "Term must be positive");
+        require!(
+            config.election_duration_blocks > 0 && config.election_duration_blocks < config.term_blocks,
+            "Election must be shorter than a term"
+        );
+
+        let old_config = self.game_config().get().council;
+        self.game_config().update(|game_config| game_config.council = config.clone());
+
+        self.audit(AdminAction::SetCouncilConfig, &(), &old_config, &config);
+    }
+
+    // View functions
+    #[view(isGameActive)]
+    fn is_game_active(&self) -> bool {
+        let current_block = self.blockchain().get_block_nonce();
+        let game_end = self.game_start_block().get() + self.game_duration_blocks().get();
+        current_block <= game_end
+    }
+
+    // Private functions
+    fn require_game_active(&self) {
+        require!(self.is_game_active(), "Game has ended");
+    }
+
+    fn require_game_ended(&self) {
+        require!(!self.is_game_active(), "Game is still active");
+    }
+
+    fn require_season_ended(&self, season_id: u32) {
+        let current_season_id = self.current_season_id().get();
+        require!(season_id > 0 && season_id <= current_season_id, "Season does not exist");
+        if season_id == current_season_id {
+            self.require_game_ended();
+        }
+    }
+
+    /// Emit the `admin_action` audit event for a mutation made by the caller
+    fn audit<T: TopEncode, V: TopEncode>(
+        &self,
+        action: AdminAction,
+        target: &T,
+        old_value: &V,
+        new_value: &V,
+    ) {
+        let record = AuditRecord {
+            target: self.audit_encode(target),
+            old_value: self.audit_encode(old_value),
+            new_value: self.audit_encode(new_value),
+        };
+        self.admin_action_event(&self.blockchain().get_caller(), action, &record);
+    }
+
+    fn audit_encode<T: TopEncode>(&self, value: &T) -> ManagedBuffer {
+        let mut encoded = ManagedBuffer::new();
+        require!(value.top_encode(&mut encoded).is_ok(), "Audit value encoding failed");
+        encoded
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Game is paused");
+        require!(!self.is_under_maintenance(), "Game is under scheduled maintenance");
+    }
+
+    fn is_under_maintenance(&self) -> bool {
+        let current_block = self.blockchain().get_block_nonce();
+        self.maintenance_windows()
+            .iter()
+            .any(|window| window.start_block <= current_block && current_block < window.end_block)
+    }
+
+    fn extend_game_timing(&self, blocks: u64) {
+        self.game_duration_blocks().update(|duration| *duration += blocks);
+        let season_id = self.current_season_id().get();
+        self.seasons(season_id).update(|season| season.end_block += blocks);
+    }
+
+    /// Remove windows that have already ended
+    fn prune_maintenance_windows(&self) {
+        let current_block = self.blockchain().get_block_nonce();
+        let mut windows = self.maintenance_windows();
+        let mut index = 1;
+        while index <= windows.len() {
+            if windows.get(index).end_block <= current_block {
+                windows.swap_remove(index);
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    fn require_flow_enabled(&self, flow: GameFlow) {
+        require!(!self.paused_flows().contains(&flow), "This action is paused");
+    }
+
+    fn require_not_blacklisted(&self) {
+        let caller = self.blockchain().get_caller();
+        self.require_player_not_blacklisted(&caller);
+    }
+
+    fn require_player_not_blacklisted(&self, player: &ManagedAddress) {
+        require!(!self.blacklist().contains(player), "Address is blacklisted");
+    }
+
+    /// For endpoints acting for a player named by the trusted router rather than the caller
+    fn require_trusted_router(&self) {
+        require!(
+            !self.trusted_router().is_empty() && self.blockchain().get_caller() == self.trusted_router().get(),
+            "Only the trusted router"
+        );
+    }
+
+    /// Critical endpoints are reserved to the admin multisig once one is configured
+    fn require_critical_access(&self, fallback_role: Role) {
+        let multisig_mapper = self.admin_multisig();
+        if multisig_mapper.is_empty() {
+            self.require_role(fallback_role);
+            return;
+        }
+
+        require!(
+            self.blockchain().get_caller() == multisig_mapper.get(),
+            "Only the admin multisig"
+        );
+    }
+
+    fn require_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(self.address_has_role(role, &caller), "Caller lacks the required role");
+    }
+
+    /// The owner holds every role and admins hold every role except owner
+    fn address_has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        if address == &self.game_owner().get() {
+            return true;
+        }
+
+        match role {
+            Role::Owner => false,
+            Role::Admin => self.role_members(Role::Admin).contains(address),
+            _ => {
+                self.role_members(role).contains(address)
+                    || self.role_members(Role::Admin).contains(address)
+            },
+        }
+    }
+
+    fn require_joined(&self, season_id: u32, player: &ManagedAddress) {
+        self.require_whitelisted(player);
+        if self.entry_fee(season_id).get() > 0 {
+            require!(self.joined_players(season_id).contains(player), "Must join the game first");
+        }
+    }
+
+    fn require_whitelisted(&self, player: &ManagedAddress) {
+        if self.whitelist_mode().get() {
+            require!(self.whitelist().contains(player), "Address not whitelisted");
+        }
+    }
+
+    /// Items the next batch may process: a caller-chosen `limit` capped at
+    /// `MAX_ITERATIONS_PER_CALL`
+    fn iteration_limit(&self, limit: usize) -> usize {
+        require!(limit > 0, "Limit must be positive");
+        core::cmp::min(limit, MAX_ITERATIONS_PER_CALL)
+    }
+
+    /// Rejects caller-supplied lists longer than one call may process
+    fn require_bounded_batch(&self, len: usize) {
+        require!(len <= MAX_ITERATIONS_PER_CALL, "Batch too large");
+    }
+
+    /// Gas left when an instrumented endpoint starts; 0 without the `gas-instrumentation` feature
+    fn gas_probe_start(&self) -> u64 {
+        if cfg!(feature = "gas-instrumentation") {
+            self.blockchain().get_gas_left()
+        } else {
+            0
+        }
+    }
+
+    /// Adds the gas spent since `gas_probe_start` to the counters of `endpoint`.
+    /// Compiled out without the `gas-instrumentation` feature.
+    fn gas_probe_end(&self, endpoint: &[u8], gas_at_start: u64) {
+        if !cfg!(feature = "gas-instrumentation") {
+            return;
+        }
+
+        let gas_used = gas_at_start.saturating_sub(self.blockchain().get_gas_left());
+        let endpoint = ManagedBuffer::from(endpoint);
+        self.instrumented_endpoints().insert(endpoint.clone());
+        self.gas_counters(&endpoint).update(|counters| {
+            counters.calls += 1;
+            counters.total_gas += gas_used;
+            counters.max_gas = core::cmp::max(counters.max_gas, gas_used);
+        });
+    }
+
+    fn resolve_season(&self, season_id: OptionalValue<u32>) -> u32 {
+        let current_season_id = self.current_season_id().get();
+        let season_id = season_id.into_option().unwrap_or(current_season_id);
+        require!(season_id > 0 && season_id <= current_season_id, "Season does not exist");
+        season_id
+    }
+
+    /// Id keying `player`'s storage; `NULL_ID` (empty storage) for addresses never registered
+    fn player_id(&self, player: &ManagedAddress) -> AddressId {
+        self.player_ids().get_id(player)
+    }
+
+    /// Like `player_id`, assigning a fresh id on first use; needed wherever player storage is written
+    fn register_player_id(&self, player: &ManagedAddress) -> AddressId {
+        self.player_ids().get_id_or_insert(player)
+    }
+
+    fn proposal_exists(&self, season_id: u32, proposal_id: u32) -> bool {
+        proposal_id > 0 && proposal_id as usize <= self.proposal_tallies(season_id).len()
+    }
+
+    /// Reassembles a proposal from its metadata and its tallies
+    fn load_proposal(&self, season_id: u32, proposal_id: u32) -> Proposal<Self::Api> {
+        let info = self.proposal_info(season_id).get(proposal_id as usize);
+        let tally = self.proposal_tallies(season_id).get(proposal_id as usize);
+        Proposal {
+            id: info.id,
+            creator: info.creator,
+            title: info.title,
+            description: info.description,
+            votes_for: tally.votes_for,
+            votes_against: tally.votes_against,
+            start_block: info.start_block,
+            end_block: info.end_block,
+            status: tally.status,
+        }
+    }
+
+    /// Appends a new proposal; its id must be the next index of the season
+    fn push_proposal(&self, season_id: u32, proposal: &Proposal<Self::Api>) {
+        self.proposal_info(season_id).push(&ProposalInfo {
+            id: proposal.id,
+            creator: proposal.creator.clone(),
+            title: proposal.title.clone(),
+            description: proposal.description.clone(),
+            start_block: proposal.start_block,
+            end_block: proposal.end_block,
+        });
+        self.proposal_tallies(season_id).push(&ProposalTally {
+            votes_for: proposal.votes_for.clone(),
+            votes_against: proposal.votes_against.clone(),
+            status: proposal.status,
+        });
+    }
+
+    /// Persists the tallies and status of `proposal`; its metadata never changes after creation
+    fn save_proposal_tally(&self, season_id: u32, proposal_id: u32, proposal: &Proposal<Self::Api>) {
+        self.proposal_tallies(season_id).set(
+            proposal_id as usize,
+            &ProposalTally {
+                votes_for: proposal.votes_for.clone(),
+                votes_against: proposal.votes_against.clone(),
+                status: proposal.status,
+            },
+        );
+    }
+
+    /// Spectators who bet on the season winner may not influence it
+    fn require_not_spectator(&self, caller: &ManagedAddress) {
+        let season_id = self.current_season_id().get();
+        require!(self.season_bets(season_id, caller).is_empty(), "Spectators cannot play");
+    }
+
+    /// Project proposals are settled by their funding round and council elections by
+    /// `closeCouncilElection`, never by regular votes
+    fn require_standard_proposal(&self, season_id: u32, proposal_id: u32) {
+        require!(
+            self.proposal_category(season_id, proposal_id).get() == ProposalCategory::Standard,
+            "Only standard proposals are voted on"
+        );
+    }
+
+    /// In council mode only sitting council members may create proposals.
+    /// A council stays in office past its term until the next election closes.
+    fn require_can_propose(&self, caller: &ManagedAddress) {
+        if !self.game_config().get().council.enabled {
+            return;
+        }
+
+        require!(self.council_members().contains(caller), "Only council members can propose");
+    }
+
+    /// Moves `proposal` to `status`, keeping the per-status counters in step; the caller stores it.
+    /// Proposals created before the counters existed were never counted, hence the saturation.
+    fn set_proposal_status(
+        &self,
+        season_id: u32,
+        proposal: &mut Proposal<Self::Api>,
+        status: ProposalStatus,
+    ) {
+        self.proposal_status_count(season_id, proposal.status)
+            .update(|count| *count = count.saturating_sub(1));
+        self.proposal_status_count(season_id, status).update(|count| *count += 1);
+
+        self.proposal_status_changed_event(
+            season_id,
+            proposal.id,
+            &ProposalStatusTransition {
+                from: proposal.status,
+                to: status,
+            },
+        );
+        proposal.status = status;
+    }
+
+    fn require_settings_changeable(&self, game_active: bool) {
+        require!(
+            !game_active || self.paused().get(),
+            "Pause the game to change this mid-season"
+        );
+    }
+
+    /// Folds the per-setting mappers used before `GameConfig` into it;
+    /// anything missing falls back to its default.
+    fn migrate_legacy_settings(&self) {
+        let mut game_config = GameConfig::default();
+
+        if !self.legacy_scoring_config().is_empty() {
+            game_config.scoring = self.legacy_scoring_config().take();
+        }
+        if !self.legacy_quantum_event_config().is_empty() {
+            game_config.quantum_events = self.legacy_quantum_event_config().take();
+        }
+        if !self.legacy_check_in_config().is_empty() {
+            game_config.check_in = self.legacy_check_in_config().take();
+        }
+        if !self.legacy_energy_config().is_empty() {
+            game_config.energy = self.legacy_energy_config().take();
+        }
+        if !self.legacy_entanglement_config().is_empty() {
+            game_config.entanglement = self.legacy_entanglement_config().take();
+        }
+        if !self.legacy_sudden_death_config().is_empty() {
+            game_config.sudden_death = self.legacy_sudden_death_config().take();
+        }
+        if !self.legacy_fee_config().is_empty() {
+            game_config.fees = self.legacy_fee_config().take();
+        }
+        if !self.legacy_boost_duration_blocks().is_empty() {
+            game_config.boost_duration_blocks = self.legacy_boost_duration_blocks().take();
+        }
+        game_config.superposition_mode = self.legacy_superposition_mode().take();
+        game_config.reveal_duration_blocks = self.legacy_reveal_duration_blocks().take();
+        game_config.max_game_extension_blocks = self.legacy_max_game_extension_blocks().take();
+
+        self.game_config().set(&game_config);
+    }
+
+    /// Brings a stored `GameConfig` up to `GAME_CONFIG_VERSION`.
+    /// Fails the upgrade on a layout this build cannot decode, so the config is never lost.
+    fn migrate_game_config(&self) {
+        let raw = self.game_config_raw().get();
+        let mut version_byte = [0u8; 1];
+        require!(raw.load_slice(0, &mut version_byte).is_ok(), "Unknown game config version");
+
+        let version = version_byte[0];
+        require!(
+            version == GAME_CONFIG_VERSION || version == 1,
+            "Unknown game config version"
+        );
+        if version == 1 {
+            let game_config: GameConfig<Self::Api> = self.game_config_v1().get().into();
+            self.game_config().set(game_config);
+        }
+    }
+
+    #[proxy]
+    fn treasury_proxy(&self, sc_address: ManagedAddress) -> treasury_proxy::Proxy<Self::Api>;
+}
@@ -0,0 +1,100 @@
+/// Storage layout version written by `init` and reached by `migrateStorageBatch` after an upgrade
+pub const CONTRACT_VERSION: u32 = 7;
+/// Layout version leading every stored `GameConfig`
+pub const GAME_CONFIG_VERSION: u8 = 2;
+
+/// Upper bound on quests per season, keeping quest progress tracking cheap on every action
+pub const MAX_QUESTS_PER_SEASON: usize = 20;
+
+pub const MAX_GUILD_NAME_LENGTH: usize = 32;
+
+pub const MIN_NICKNAME_LENGTH: usize = 3;
+pub const MAX_NICKNAME_LENGTH: usize = 20;
+/// Profile bios are stored off-chain; only their sha256 hash lives in the contract
+pub const BIO_HASH_LENGTH: usize = 32;
+
+pub const MAX_BPS: u64 = 10_000;
+/// Simple majority, the pass threshold when dynamic difficulty is not configured
+pub const HALF_BPS: u64 = 5_000;
+/// Point multiplier applied while a PointMultiplier booster is active
+pub const BOOSTED_POINTS_PERCENT: u64 = 150;
+pub const DEFAULT_PREDICTION_FEE_BPS: u64 = 200;
+pub const DEFAULT_BETTING_FEE_BPS: u64 = 500;
+/// Upper bound on fees taken from prediction and betting pools
+pub const MAX_FEE_BPS: u64 = 2_000;
+
+/// Upper bound on tournament entrants, keeping `advancePhase` within block gas limits
+pub const MAX_TOURNAMENT_PLAYERS: usize = 256;
+/// Bounds on values governance can set through parameter proposals
+pub const MAX_GOVERNED_PASS_THRESHOLD_BPS: u64 = 9_000;
+pub const MIN_GOVERNED_DURATION_BLOCKS: u64 = 10;
+/// About a week at 6 second blocks
+pub const MAX_GOVERNED_DURATION_BLOCKS: u64 = 100_800;
+pub const MAX_GOVERNED_POINTS: u64 = 1_000;
+
+/// Per-player notification queue length; the oldest entry is dropped when full
+pub const MAX_NOTIFICATIONS: usize = 20;
+/// Voters notified when a proposal closes, keeping `close_proposal` within block gas limits
+pub const MAX_NOTIFIED_VOTERS: usize = 100;
+/// Size of the featured proposals list shown by `getFeaturedProposals`
+pub const MAX_FEATURED_PROPOSALS: usize = 10;
+/// Roughly one hour at 6 second blocks
+pub const DEFAULT_BOOST_DURATION_BLOCKS: u64 = 600;
+/// Upper bound on projects per funding round, keeping `settleFundingRound` within block gas limits
+pub const MAX_ROUND_PROJECTS: usize = 50;
+/// Modules compiled into this build, reported by `getContractInfo`
+pub const CONTRACT_MODULES: &[&[u8]] = &[
+    b"admin",
+    b"scoring",
+    b"rewards",
+    b"staking",
+    b"governance",
+    b"league",
+    b"migration",
+    b"views",
+];
+/// Blocks between `initiateEmergencyWithdraw` and the earliest execution (~1 day)
+pub const EMERGENCY_WITHDRAW_DELAY_BLOCKS: u64 = 14_400;
+/// Upper bound on scheduled maintenance windows, keeping the per-call check cheap
+pub const MAX_MAINTENANCE_WINDOWS: usize = 5;
+/// Registry name the league hub address is resolved under
+pub const REGISTRY_LEAGUE_HUB_NAME: &[u8] = b"league-hub";
+/// Raffle tickets a player earns for each vote cast in a season
+pub const RAFFLE_TICKETS_PER_VOTE: u64 = 1;
+pub const DEFAULT_COUNCIL_SEATS: u32 = 5;
+/// Default council term (~1 week)
+pub const DEFAULT_COUNCIL_TERM_BLOCKS: u64 = 100_800;
+/// Default length of a council election (~1 day)
+pub const DEFAULT_COUNCIL_ELECTION_BLOCKS: u64 = 14_400;
+pub const MAX_COUNCIL_SEATS: u32 = 15;
+/// Upper bound on candidates per election, keeping `closeCouncilElection` within block gas limits
+pub const MAX_COUNCIL_CANDIDATES: usize = 50;
+/// Upper bound on items returned by one paginated view call
+pub const MAX_PAGE_SIZE: u32 = 50;
+/// Round duration of the MultiversX mainnet, used to estimate wall-clock countdowns
+pub const ESTIMATED_SECONDS_PER_BLOCK: u64 = 6;
+/// Number of players kept ranked on-chain for `getTopPlayers`
+pub const TOP_PLAYERS_SIZE: usize = 50;
+/// Number of final top players listed as winners by `getSeasonInfo`
+pub const SEASON_WINNERS_SHOWN: usize = 10;
+/// Number of final top players who may claim the NFT reward
+pub const NFT_REWARD_WINNERS: usize = 10;
+/// `nft-minter` tier of the NFT a tournament winner receives
+pub const GRAND_NFT_TIER: u8 = 0;
+/// `nft-minter` tier of the NFT the season's top players claim
+pub const TOP_PLAYER_NFT_TIER: u8 = 1;
+/// Blocks a closed proposal's votes are kept before `pruneProposal` may clear them (~1 week)
+pub const PROPOSAL_RETENTION_BLOCKS: u64 = 100_800;
+/// Blocks after a season ends during which its prize pool may be claimed, before
+/// `sweepPrizePool` moves what is left to the treasury (~30 days)
+pub const PRIZE_CLAIM_WINDOW_BLOCKS: u64 = 432_000;
+/// Upper bound on players, voters or proposals one call may loop over, keeping batched
+/// endpoints within block gas limits; longer work resumes from a cursor in later calls
+pub const MAX_ITERATIONS_PER_CALL: usize = 100;
+/// Gas kept in reserve by `migrateStorageBatch`, which stops early rather than run out mid-item
+pub const MIGRATION_GAS_RESERVE: u64 = 5_000_000;
+/// Season the original single-season deployment moves into
+pub const BASELINE_SEASON_ID: u32 = 1;
+/// Bits of `VoteRecord::flags`: the vote backs the proposal / is committed but not revealed yet
+pub const VOTE_FLAG_FOR: u8 = 0b01;
+pub const VOTE_FLAG_HIDDEN: u8 = 0b10;
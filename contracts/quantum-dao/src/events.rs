@@ -0,0 +1,517 @@
+use multiversx_sc::imports::*;
+
+use crate::types::*;
+
+/// Events emitted by the game
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("season_started")]
+    fn season_started_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] start_block: u64,
+        end_block: u64,
+    );
+
+    #[event("season_archived")]
+    fn season_archived_event(&self, #[indexed] season_id: u32, total_proposals: u32);
+
+    #[event("game_restarted")]
+    fn game_restarted_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] nft_reward_token_id: &TokenIdentifier,
+        duration_blocks: u64,
+    );
+
+    #[event("proposal_created")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] proposal_id: u32,
+        #[indexed] creator: &ManagedAddress,
+        title: &ManagedBuffer,
+    );
+
+    #[event("vote_cast")]
+    fn vote_cast_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] voter: &ManagedAddress,
+        vote_for: bool,
+        stake_amount: &BigUint,
+    );
+
+    #[event("proposal_executed")]
+    fn proposal_executed_event(&self, #[indexed] proposal_id: u32, passed: bool);
+
+    #[event("proposal_vetoed")]
+    fn proposal_vetoed_event(&self, #[indexed] proposal_id: u32, #[indexed] vetoed_by: &ManagedAddress);
+
+    #[event("nft_claimed")]
+    fn nft_claimed_event(&self, #[indexed] player: &ManagedAddress, score: u64);
+
+    #[event("player_joined")]
+    fn player_joined_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] player: &ManagedAddress,
+        entry_fee: &BigUint,
+    );
+
+    #[event("prize_claimed")]
+    fn prize_claimed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] player: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("prize_pool_swept")]
+    fn prize_pool_swept_event(&self, #[indexed] season_id: u32, amount: &BigUint);
+
+    #[event("quest_created")]
+    fn quest_created_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] quest_id: usize,
+        quest: &Quest<Self::Api>,
+    );
+
+    #[event("quest_reward_claimed")]
+    fn quest_reward_claimed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] quest_id: usize,
+        #[indexed] player: &ManagedAddress,
+    );
+
+    #[event("checked_in")]
+    fn checked_in_event(&self, #[indexed] player: &ManagedAddress, streak: u64, points: u64);
+
+    #[event("power_up_used")]
+    fn power_up_used_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] player: &ManagedAddress,
+        kind: PowerUpKind,
+    );
+
+    #[event("quantum_event_triggered")]
+    fn quantum_event_triggered_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] kind: QuantumEventKind,
+        event: &QuantumEvent<Self::Api>,
+    );
+
+    #[event("tournament_registered")]
+    fn tournament_registered_event(&self, #[indexed] season_id: u32, #[indexed] player: &ManagedAddress);
+
+    #[event("tournament_phase_advanced")]
+    fn tournament_phase_advanced_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] phase: u32,
+        participants: usize,
+    );
+
+    #[event("tournament_won")]
+    fn tournament_won_event(&self, #[indexed] season_id: u32, #[indexed] winner: &ManagedAddress);
+
+    #[event("guild_created")]
+    fn guild_created_event(
+        &self,
+        #[indexed] guild_id: u32,
+        #[indexed] founder: &ManagedAddress,
+        name: &ManagedBuffer,
+    );
+
+    #[event("guild_joined")]
+    fn guild_joined_event(&self, #[indexed] guild_id: u32, #[indexed] player: &ManagedAddress);
+
+    #[event("guild_left")]
+    fn guild_left_event(&self, #[indexed] guild_id: u32, #[indexed] player: &ManagedAddress);
+
+    #[event("guild_contribution")]
+    fn guild_contribution_event(
+        &self,
+        #[indexed] guild_id: u32,
+        #[indexed] player: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("guild_treasury_spent")]
+    fn guild_treasury_spent_event(
+        &self,
+        #[indexed] guild_id: u32,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("profile_updated")]
+    fn profile_updated_event(&self, #[indexed] player: &ManagedAddress, #[indexed] nickname: &ManagedBuffer);
+
+    #[event("game_paused")]
+    fn game_paused_event(&self, #[indexed] caller: &ManagedAddress);
+
+    #[event("game_unpaused")]
+    fn game_unpaused_event(&self, #[indexed] caller: &ManagedAddress, paused_blocks: u64);
+
+    #[event("game_extended")]
+    fn game_extended_event(
+        &self,
+        #[indexed] season_id: u32,
+        extension_blocks: u64,
+        total_extension_blocks: u64,
+    );
+
+    #[event("game_extension_skipped")]
+    fn game_extension_skipped_event(&self, #[indexed] season_id: u32, extension_blocks: u64);
+
+    #[event("counter_proposal_created")]
+    fn counter_proposal_created_event(
+        &self,
+        #[indexed] original_id: u32,
+        #[indexed] counter_id: u32,
+        creator: &ManagedAddress,
+    );
+
+    #[event("counter_pair_resolved")]
+    fn counter_pair_resolved_event(
+        &self,
+        #[indexed] original_id: u32,
+        #[indexed] counter_id: u32,
+        winner_id: u32,
+    );
+
+    #[event("prediction_placed")]
+    fn prediction_placed_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] player: &ManagedAddress,
+        predicts_pass: bool,
+        amount: &BigUint,
+    );
+
+    #[event("prediction_pool_settled")]
+    fn prediction_pool_settled_event(&self, #[indexed] proposal_id: u32, passed: bool, fee: &BigUint);
+
+    #[event("prediction_claimed")]
+    fn prediction_claimed_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] player: &ManagedAddress,
+        payout: &BigUint,
+    );
+
+    #[event("treasury_withdrawn")]
+    fn treasury_withdrawn_event(&self, #[indexed] to: &ManagedAddress, amount: &BigUint);
+
+    #[event("fees_forwarded")]
+    fn fees_forwarded_event(&self, #[indexed] vault: &ManagedAddress, amount: &BigUint);
+
+    #[event("vote_committed")]
+    fn vote_committed_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] voter: &ManagedAddress,
+        stake_amount: &BigUint,
+    );
+
+    #[event("entanglement_requested")]
+    fn entanglement_requested_event(
+        &self,
+        #[indexed] requester: &ManagedAddress,
+        #[indexed] partner: &ManagedAddress,
+    );
+
+    #[event("entanglement_formed")]
+    fn entanglement_formed_event(
+        &self,
+        #[indexed] requester: &ManagedAddress,
+        #[indexed] partner: &ManagedAddress,
+    );
+
+    #[event("entanglement_broken")]
+    fn entanglement_broken_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] partner: &ManagedAddress,
+    );
+
+    #[event("booster_bought")]
+    fn booster_bought_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] booster_type: BoosterType,
+        expiry_block: u64,
+        price: &BigUint,
+    );
+
+    #[event("league_scores_reported")]
+    fn league_scores_reported_event(&self, #[indexed] season_id: u32, players: usize);
+
+    #[event("raffle_tickets_reported")]
+    fn raffle_tickets_reported_event(&self, #[indexed] season_id: u32, players: usize);
+
+    #[event("war_bonus_received")]
+    fn war_bonus_received_event(&self, #[indexed] war_id: u32, amount: &BigUint);
+
+    #[event("bet_placed")]
+    fn bet_placed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] bettor: &ManagedAddress,
+        #[indexed] candidate: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("betting_result_frozen")]
+    fn betting_result_frozen_event(&self, #[indexed] season_id: u32, winner: &ManagedAddress);
+
+    #[event("bet_claimed")]
+    fn bet_claimed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] bettor: &ManagedAddress,
+        payout: &BigUint,
+    );
+
+    #[event("season_theme_set")]
+    fn season_theme_set_event(&self, #[indexed] theme_id: u32, name: &ManagedBuffer);
+
+    #[event("themed_season_started")]
+    fn themed_season_started_event(&self, #[indexed] season_id: u32, #[indexed] theme_id: u32);
+
+    #[event("funding_round_started")]
+    fn funding_round_started_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] end_block: u64,
+        matching_pool: &BigUint,
+    );
+
+    #[event("project_submitted")]
+    fn project_submitted_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] proposal_id: u32,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("project_donation")]
+    fn project_donation_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] proposal_id: u32,
+        #[indexed] donor: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("project_funded")]
+    fn project_funded_event(
+        &self,
+        #[indexed] round_id: u32,
+        #[indexed] proposal_id: u32,
+        #[indexed] matched: &BigUint,
+        payout: &BigUint,
+    );
+
+    #[event("funding_round_settled")]
+    fn funding_round_settled_event(&self, #[indexed] round_id: u32);
+
+    #[event("proposal_boosted")]
+    fn proposal_boosted_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] proposal_id: u32,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("migration_completed")]
+    fn migration_completed_event(&self, #[indexed] version: u32);
+
+    #[event("game_duration_updated")]
+    fn game_duration_updated_event(&self, #[indexed] season_id: u32, game_duration_blocks: u64);
+
+    #[event("nft_reward_token_updated")]
+    fn nft_reward_token_updated_event(&self, token_id: &TokenIdentifier);
+
+    #[event("flow_paused")]
+    fn flow_paused_event(&self, #[indexed] flow: GameFlow, paused: bool);
+
+    #[event("whitelist_mode_changed")]
+    fn whitelist_mode_changed_event(&self, enabled: bool);
+
+    #[event("whitelist_added")]
+    fn whitelist_added_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("whitelist_removed")]
+    fn whitelist_removed_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("access_requested")]
+    fn access_requested_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("blacklist_added")]
+    fn blacklist_added_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("blacklist_removed")]
+    fn blacklist_removed_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("admin_multisig_set")]
+    fn admin_multisig_set_event(&self, #[indexed] multisig: &ManagedAddress);
+
+    #[event("role_granted")]
+    fn role_granted_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("ownership_transfer_proposed")]
+    fn ownership_transfer_proposed_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] pending_owner: &ManagedAddress,
+    );
+
+    #[event("ownership_transferred")]
+    fn ownership_transferred_event(
+        &self,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    #[event("role_transfer_proposed")]
+    fn role_transfer_proposed_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+    );
+
+    #[event("role_transferred")]
+    fn role_transferred_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+    );
+
+    #[event("fee_config_updated")]
+    fn fee_config_updated_event(&self, config: &FeeConfig<Self::Api>);
+
+    #[event("parameter_changed")]
+    fn parameter_changed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] proposal_id: u32,
+        change: &ParameterChange<Self::Api>,
+    );
+
+    #[event("parameter_change_skipped")]
+    fn parameter_change_skipped_event(&self, #[indexed] season_id: u32, #[indexed] proposal_id: u32);
+
+    #[event("emergency_withdraw_initiated")]
+    fn emergency_withdraw_initiated_event(
+        &self,
+        #[indexed] initiated_by: &ManagedAddress,
+        #[indexed] recovery_address: &ManagedAddress,
+        executable_block: u64,
+    );
+
+    #[event("emergency_withdraw_cancelled")]
+    fn emergency_withdraw_cancelled_event(&self, #[indexed] cancelled_by: &ManagedAddress);
+
+    #[event("emergency_withdraw_executed")]
+    fn emergency_withdraw_executed_event(
+        &self,
+        #[indexed] recovery_address: &ManagedAddress,
+        egld_amount: &BigUint,
+    );
+
+    #[event("maintenance_scheduled")]
+    fn maintenance_scheduled_event(&self, #[indexed] start_block: u64, #[indexed] end_block: u64);
+
+    #[event("maintenance_cancelled")]
+    fn maintenance_cancelled_event(&self, #[indexed] start_block: u64, #[indexed] end_block: u64);
+
+    #[event("admin_action")]
+    fn admin_action_event(
+        &self,
+        #[indexed] actor: &ManagedAddress,
+        #[indexed] action: AdminAction,
+        record: &AuditRecord<Self::Api>,
+    );
+
+    #[event("league_hub_synced")]
+    fn league_hub_synced_event(&self, #[indexed] league_hub: &ManagedAddress);
+
+    #[event("council_election_opened")]
+    fn council_election_opened_event(&self, #[indexed] season_id: u32, #[indexed] proposal_id: u32, end_block: u64);
+
+    #[event("council_candidate")]
+    fn council_candidate_event(&self, #[indexed] proposal_id: u32, #[indexed] candidate: &ManagedAddress);
+
+    #[event("council_vote")]
+    fn council_vote_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] candidate: &ManagedAddress,
+    );
+
+    #[event("council_elected")]
+    fn council_elected_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] term_end_block: u64,
+        members: &ManagedVec<ManagedAddress>,
+    );
+
+    #[event("vote_revoked")]
+    fn vote_revoked_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] voter: &ManagedAddress,
+        stake_amount: &BigUint,
+    );
+
+    /// Tallies of a proposal after every vote, reveal or revocation that changed them
+    #[event("proposal_tally_updated")]
+    fn proposal_tally_updated_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] proposal_id: u32,
+        tally: &ProposalTally<Self::Api>,
+    );
+
+    #[event("proposal_status_changed")]
+    fn proposal_status_changed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] proposal_id: u32,
+        transition: &ProposalStatusTransition,
+    );
+
+    /// Every change to a player's DAO score, mirrored shares included
+    #[event("score_changed")]
+    fn score_changed_event(
+        &self,
+        #[indexed] season_id: u32,
+        #[indexed] player: &ManagedAddress,
+        change: &ScoreChange,
+    );
+
+    #[event("stake_withdrawn")]
+    fn stake_withdrawn_event(
+        &self,
+        #[indexed] proposal_id: u32,
+        #[indexed] voter: &ManagedAddress,
+        stake_amount: &BigUint,
+    );
+
+    #[event("proposal_pruned")]
+    fn proposal_pruned_event(&self, #[indexed] season_id: u32, #[indexed] proposal_id: u32, voter_count: usize);
+}
@@ -0,0 +1,1440 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, types::*};
+
+/// Seasons, proposals and their execution, guilds, quantum events and funding rounds
+#[multiversx_sc::module]
+pub trait GovernanceModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::admin::AdminModule
+    + crate::scoring::ScoringModule
+    + crate::rewards::RewardsModule
+    + crate::staking::StakingModule
+{
+    /// Start a new season from a theme preset once the current one has ended.
+    /// Archives the previous season and gives the new one fresh proposals, votes and scores.
+    #[endpoint(startNewSeason)]
+    fn start_new_season(&self, theme_id: u32) {
+        self.require_role(Role::Admin);
+        self.require_game_ended();
+        require!(!self.season_themes(theme_id).is_empty(), "Theme does not exist");
+
+        let theme = self.season_themes(theme_id).get();
+        let previous_season_id = self.current_season_id().get();
+        self.begin_next_season(theme.duration_blocks, theme.scoring_config);
+
+        let season_id = self.current_season_id().get();
+        self.game_config().update(|game_config| {
+            game_config.superposition_mode = theme.superposition_voting;
+            game_config.reveal_duration_blocks = theme.reveal_duration_blocks;
+            game_config.sudden_death = theme.sudden_death;
+        });
+        self.entry_fee(season_id).set(&theme.entry_fee);
+        self.season_theme(season_id).set(theme_id);
+
+        self.audit(AdminAction::StartNewSeason, &theme_id, &previous_season_id, &season_id);
+        self.themed_season_started_event(season_id, theme_id);
+    }
+
+    /// Restart the game after a season ends with new timing, reward token and scoring rules,
+    /// without redeploying the contract.
+    #[endpoint(restartGame)]
+    fn restart_game(
+        &self,
+        duration_blocks: u64,
+        nft_reward_token_id: TokenIdentifier,
+        config: ScoringConfig,
+    ) {
+        self.require_role(Role::Admin);
+        self.require_game_ended();
+        require!(nft_reward_token_id.is_valid_esdt_identifier(), "Invalid reward token");
+
+        let previous_season_id = self.current_season_id().get();
+        self.nft_reward_token_id().set(&nft_reward_token_id);
+        self.begin_next_season(duration_blocks, config);
+
+        let season_id = self.current_season_id().get();
+        self.audit(AdminAction::RestartGame, &(), &previous_season_id, &season_id);
+        self.game_restarted_event(season_id, &nft_reward_token_id, duration_blocks);
+    }
+
+    /// Create a new governance proposal
+    #[payable("EGLD")]
+    #[endpoint(createProposal)]
+    fn create_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+    ) {
+        let gas_at_start = self.gas_probe_start();
+        let season_id = self.current_season_id().get();
+        self.create_player_proposal(season_id, title, description, voting_duration_blocks);
+
+        self.gas_probe_end(b"createProposal", gas_at_start);
+    }
+
+    /// Create a proposal whose body lives off-chain, storing only its 32-byte content hash
+    /// (e.g. the digest of an IPFS CID) in place of the description
+    #[payable("EGLD")]
+    #[endpoint(createProposalWithContentHash)]
+    fn create_proposal_with_content_hash(
+        &self,
+        title: ManagedBuffer,
+        content_hash: ManagedByteArray<Self::Api, 32>,
+        voting_duration_blocks: u64,
+    ) {
+        let season_id = self.current_season_id().get();
+        let proposal_id = self.create_player_proposal(
+            season_id,
+            title,
+            content_hash.as_managed_buffer().clone(),
+            voting_duration_blocks,
+        );
+        self.content_hash_proposals(season_id).insert(proposal_id);
+    }
+
+    /// Attach a counter-proposal to an active proposal.
+    /// Both share the original deadline and voters pick one of the two through `voteCounterPair`.
+    #[payable("EGLD")]
+    #[endpoint(createCounterProposal)]
+    fn create_counter_proposal(
+        &self,
+        original_id: u32,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+    ) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        require!(self.proposal_exists(season_id, original_id), "Proposal does not exist");
+        self.require_standard_proposal(season_id, original_id);
+        require!(
+            self.counter_pair_original(season_id, original_id).is_empty(),
+            "Proposal already contested"
+        );
+        require!(
+            self.guild_spends(season_id, original_id).is_empty(),
+            "Guild proposals cannot be countered"
+        );
+        require!(
+            !self.game_config().get().superposition_mode
+                && !self.superposition_proposals(season_id).contains(&original_id),
+            "Superposition proposals cannot be countered"
+        );
+
+        let original = self.load_proposal(season_id, original_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block <= original.end_block, "Voting ended");
+
+        self.charge_proposal_costs(&caller);
+        let counter_id = self.store_new_proposal(
+            season_id,
+            &caller,
+            title,
+            description,
+            original.end_block - current_block,
+        );
+
+        self.counter_pair_original(season_id, original_id).set(original_id);
+        self.counter_pair_original(season_id, counter_id).set(original_id);
+        self.counter_proposal(season_id, original_id).set(counter_id);
+
+        self.counter_proposal_created_event(original_id, counter_id, &caller);
+
+        counter_id
+    }
+
+    /// Execute a proposal if it has passed. Proposals an archived season left open are rejected,
+    /// which releases their stakes and settles their prediction pools.
+    #[endpoint(executeProposal)]
+    fn execute_proposal(&self, proposal_id: u32, season_id: OptionalValue<u32>) {
+        let gas_at_start = self.gas_probe_start();
+        self.require_not_paused();
+
+        let season_id = self.resolve_season(season_id);
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+
+        self.require_standard_proposal(season_id, proposal_id);
+
+        if self.seasons(season_id).get().archived {
+            self.expire_proposal(season_id, proposal_id);
+            self.gas_probe_end(b"executeProposal", gas_at_start);
+            return;
+        }
+
+        let counter_pair_mapper = self.counter_pair_original(season_id, proposal_id);
+        if !counter_pair_mapper.is_empty() {
+            self.resolve_counter_pair(season_id, counter_pair_mapper.get());
+            self.gas_probe_end(b"executeProposal", gas_at_start);
+            return;
+        }
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        let current_block = self.blockchain().get_block_nonce();
+
+        require!(current_block > proposal.end_block, "Voting still active");
+        require!(proposal.status == ProposalStatus::Active, "Proposal already executed");
+        if self.superposition_proposals(season_id).contains(&proposal_id) {
+            require!(
+                current_block > proposal.end_block + self.game_config().get().reveal_duration_blocks,
+                "Superposition not collapsed yet"
+            );
+        }
+
+        // Check if proposal passed (quorum reached and enough of the stake in favour)
+        if self.has_proposal_passed(&proposal) {
+            self.finalize_passed_proposal(season_id, proposal);
+        } else {
+            self.close_proposal(season_id, proposal_id, false);
+        }
+
+        self.gas_probe_end(b"executeProposal", gas_at_start);
+    }
+
+    /// Reject an open proposal of the current season without counting its votes. Vetoing either
+    /// side of a counter-proposal pair rejects both.
+    #[endpoint(vetoProposal)]
+    fn veto_proposal(&self, proposal_id: u32) {
+        self.require_critical_access(Role::Guardian);
+
+        let season_id = self.current_season_id().get();
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+        self.require_standard_proposal(season_id, proposal_id);
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        require!(proposal.status == ProposalStatus::Active, "Proposal already executed");
+
+        let counter_pair_mapper = self.counter_pair_original(season_id, proposal_id);
+        if counter_pair_mapper.is_empty() {
+            self.close_proposal(season_id, proposal_id, false);
+        } else {
+            let original_id = counter_pair_mapper.get();
+            require!(
+                !self.counter_pair_resolved(season_id, original_id).get(),
+                "Proposal already executed"
+            );
+            self.counter_pair_resolved(season_id, original_id).set(true);
+
+            self.close_proposal(season_id, original_id, false);
+            self.close_proposal(season_id, self.counter_proposal(season_id, original_id).get(), false);
+        }
+
+        self.audit(AdminAction::VetoProposal, &proposal_id, &false, &true);
+        self.proposal_vetoed_event(proposal_id, &self.blockchain().get_caller());
+    }
+
+    /// Clear the per-voter storage of a closed proposal, at most `MAX_ITERATIONS_PER_CALL` voters
+    /// per call. Open to anyone `PROPOSAL_RETENTION_BLOCKS` after execution once every stake has been
+    /// withdrawn. The tally is kept as the result record and pruned voters stay counted.
+    /// Returns true once nothing is left to prune.
+    #[endpoint(pruneProposal)]
+    fn prune_proposal(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> bool {
+        self.require_not_paused();
+
+        let season_id = self.resolve_season(season_id);
+        let execution_block_mapper = self.proposal_execution_block(season_id, proposal_id);
+        require!(!execution_block_mapper.is_empty(), "Proposal not executed yet");
+        require!(
+            self.blockchain().get_block_nonce() > execution_block_mapper.get() + PROPOSAL_RETENTION_BLOCKS,
+            "Retention period not over"
+        );
+
+        let mut voters_mapper = self.proposal_voters(season_id, proposal_id);
+        let pruned = core::cmp::min(voters_mapper.len(), MAX_ITERATIONS_PER_CALL);
+        for _ in 0..pruned {
+            let voter = voters_mapper.get_by_index(voters_mapper.len());
+            let voter_id = self.player_id(&voter);
+            require!(self.stake_withdrawn(season_id, proposal_id, voter_id).take(), "Stakes not withdrawn");
+            self.user_votes(season_id, proposal_id, voter_id).clear();
+            self.vote_commitments(season_id, proposal_id, &voter).clear();
+            self.vote_weight(season_id, proposal_id, &voter).clear();
+            self.vote_points(season_id, proposal_id, &voter).clear();
+            self.vote_mirrored_points(season_id, proposal_id, &voter).clear();
+            self.vote_quest_counted(season_id, proposal_id, &voter).clear();
+            voters_mapper.swap_remove(&voter);
+        }
+        let voter_count = self
+            .pruned_voter_count(season_id, proposal_id)
+            .update(|count| {
+                *count += pruned;
+                *count
+            });
+
+        if !voters_mapper.is_empty() {
+            return false;
+        }
+
+        self.proposal_stake(season_id, proposal_id).clear();
+        self.committed_stake(season_id, proposal_id).clear();
+        self.proposal_pruned_event(season_id, proposal_id, voter_count);
+        true
+    }
+
+    /// Consume a power-up NFT against an open proposal.
+    /// The effect is read from the NFT attributes and the token is burned.
+    #[payable("*")]
+    #[endpoint(usePowerUp)]
+    fn use_power_up(&self, proposal_id: u32) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.power_up_token_id().get(),
+            "Invalid power-up token"
+        );
+        require!(payment.amount == 1u32, "Only one power-up can be used at a time");
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block <= proposal.end_block, "Voting ended");
+
+        let token_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            &payment.token_identifier,
+            payment.token_nonce,
+        );
+        let kind: PowerUpKind = token_data.decode_attributes();
+
+        match kind {
+            PowerUpKind::Entangler => {
+                require!(
+                    self.user_votes(season_id, proposal_id, self.player_id(&caller)).is_empty(),
+                    "Already voted"
+                );
+                let entangled_mapper = self.entangled_votes(season_id, proposal_id, &caller);
+                require!(!entangled_mapper.get(), "Entangler already active");
+                entangled_mapper.set(true);
+            },
+            PowerUpKind::Decoherence => {
+                require!(proposal.creator != caller, "Cannot decohere own proposal");
+                require!(
+                    self.decohered_proposals(season_id).insert(proposal_id),
+                    "Proposal already decohered"
+                );
+            },
+        }
+
+        self.send().esdt_local_burn(
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+
+        self.power_up_used_event(proposal_id, &caller, kind);
+    }
+
+    /// Trigger the next scheduled quantum event once the event interval has elapsed.
+    /// The event kind and any beneficiary are picked using the block random seed, so only
+    /// keepers may trigger it: anyone else could retry until the outcome suits them.
+    #[endpoint(triggerQuantumEvent)]
+    fn trigger_quantum_event(&self) {
+        self.require_role(Role::Keeper);
+        self.require_not_paused();
+        self.require_game_active();
+
+        let config = self.game_config().get().quantum_events;
+        let current_block = self.blockchain().get_block_nonce();
+        let last_event_block = self.last_quantum_event_block().get();
+        require!(
+            last_event_block == 0 || current_block >= last_event_block + config.interval_blocks,
+            "Quantum event not due yet"
+        );
+
+        let season_id = self.current_season_id().get();
+        let mut rand_source = RandomnessSource::new();
+        let kind = match rand_source.next_u8_in_range(0, 3) {
+            0 => QuantumEventKind::DoublePoints,
+            1 => QuantumEventKind::FlashProposal,
+            _ => QuantumEventKind::BonusDrop,
+        };
+
+        let mut event = QuantumEvent {
+            kind,
+            start_block: current_block,
+            end_block: current_block,
+            proposal_id: 0,
+            beneficiary: ManagedAddress::zero(),
+            bonus_points: 0,
+        };
+
+        match kind {
+            QuantumEventKind::DoublePoints => {
+                event.end_block = current_block + config.double_points_duration_blocks;
+                self.double_points_end_block(season_id).set(event.end_block);
+            },
+            QuantumEventKind::FlashProposal => {
+                let sc_address = self.blockchain().get_sc_address();
+                event.end_block = current_block + config.flash_proposal_duration_blocks;
+                event.proposal_id = self.store_new_proposal(
+                    season_id,
+                    &sc_address,
+                    ManagedBuffer::from(b"Flash proposal"),
+                    ManagedBuffer::from(b"A surprise proposal spawned by a quantum fluctuation"),
+                    config.flash_proposal_duration_blocks,
+                );
+            },
+            QuantumEventKind::BonusDrop => {
+                let players = self.players(season_id);
+                if !players.is_empty() {
+                    let index = rand_source.next_usize_in_range(1, players.len() + 1);
+                    event.beneficiary = players.get_by_index(index);
+                    event.bonus_points = config.bonus_drop_points;
+                    self.add_dao_points(season_id, &event.beneficiary, config.bonus_drop_points);
+                }
+            },
+        }
+
+        self.last_quantum_event_block().set(current_block);
+        self.quantum_events(season_id).push(&event);
+
+        self.quantum_event_triggered_event(season_id, kind, &event);
+    }
+
+    /// Found a new guild; the caller becomes its first member
+    #[endpoint(createGuild)]
+    fn create_guild(&self, name: ManagedBuffer) -> u32 {
+        let caller = self.blockchain().get_caller();
+        require!(!name.is_empty() && name.len() <= MAX_GUILD_NAME_LENGTH, "Invalid guild name");
+        require!(self.guild_by_name(&name).is_empty(), "Guild name taken");
+        require!(self.player_guild(&caller).is_empty(), "Already in a guild");
+
+        let guild_id = self.last_guild_id().update(|id| {
+            *id += 1;
+            *id
+        });
+
+        let guild = Guild {
+            id: guild_id,
+            name: name.clone(),
+            founder: caller.clone(),
+            created_block: self.blockchain().get_block_nonce(),
+        };
+        self.guilds(guild_id).set(&guild);
+        self.guild_by_name(&name).set(guild_id);
+        self.guild_members(guild_id).insert(caller.clone());
+        self.player_guild(&caller).set(guild_id);
+
+        self.guild_created_event(guild_id, &caller, &name);
+
+        guild_id
+    }
+
+    #[endpoint(joinGuild)]
+    fn join_guild(&self, guild_id: u32) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.guilds(guild_id).is_empty(), "Guild does not exist");
+        require!(self.player_guild(&caller).is_empty(), "Already in a guild");
+
+        self.guild_members(guild_id).insert(caller.clone());
+        self.player_guild(&caller).set(guild_id);
+
+        self.guild_joined_event(guild_id, &caller);
+    }
+
+    /// Leave the caller's guild; contributions stay in the guild treasury
+    #[endpoint(leaveGuild)]
+    fn leave_guild(&self) {
+        let caller = self.blockchain().get_caller();
+        let player_guild_mapper = self.player_guild(&caller);
+        require!(!player_guild_mapper.is_empty(), "Not in a guild");
+
+        let guild_id = player_guild_mapper.get();
+        self.guild_members(guild_id).swap_remove(&caller);
+        player_guild_mapper.clear();
+
+        self.guild_left_event(guild_id, &caller);
+    }
+
+    /// Contribute EGLD to the caller's guild treasury
+    #[payable("EGLD")]
+    #[endpoint(contributeToGuild)]
+    fn contribute_to_guild(&self) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment > 0, "Must send EGLD");
+        require!(!self.player_guild(&caller).is_empty(), "Not in a guild");
+
+        let guild_id = self.player_guild(&caller).get();
+        self.guild_treasury(guild_id).update(|treasury| *treasury += &payment);
+
+        self.guild_contribution_event(guild_id, &caller, &payment);
+    }
+
+    /// Create a guild proposal that, if passed, pays `amount` from the guild treasury to `recipient`.
+    /// Only guild members may vote on it.
+    #[payable("EGLD")]
+    #[endpoint(createGuildProposal)]
+    fn create_guild_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+        recipient: ManagedAddress,
+        amount: BigUint,
+    ) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        require!(!self.player_guild(&caller).is_empty(), "Not in a guild");
+        require!(amount > 0, "Spend amount must be positive");
+
+        let guild_id = self.player_guild(&caller).get();
+        require!(amount <= self.guild_treasury(guild_id).get(), "Insufficient guild treasury");
+
+        self.charge_proposal_costs(&caller);
+        let proposal_id =
+            self.store_new_proposal(season_id, &caller, title, description, voting_duration_blocks);
+        self.guild_spends(season_id, proposal_id).set(GuildSpend {
+            guild_id,
+            recipient,
+            amount,
+        });
+
+        proposal_id
+    }
+
+    /// Propose extending the current season by `extension_blocks`.
+    /// If passed and executed while the season is still running, the game end moves back accordingly.
+    #[payable("EGLD")]
+    #[endpoint(createExtensionProposal)]
+    fn create_extension_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+        extension_blocks: u64,
+    ) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        require!(extension_blocks > 0, "Extension must be positive");
+        require!(
+            self.season_extension_blocks(season_id).get() + extension_blocks
+                <= self.game_config().get().max_game_extension_blocks,
+            "Extension exceeds the season maximum"
+        );
+
+        self.charge_proposal_costs(&caller);
+        let proposal_id =
+            self.store_new_proposal(season_id, &caller, title, description, voting_duration_blocks);
+        self.duration_extensions(season_id, proposal_id).set(extension_blocks);
+
+        proposal_id
+    }
+
+    /// Propose a change to a game parameter, applied by `executeProposal` if it passes.
+    /// Values are bounded so governance cannot brick the game.
+    #[payable("EGLD")]
+    #[endpoint(createParameterProposal)]
+    fn create_parameter_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+        change: ParameterChange<Self::Api>,
+    ) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        require!(self.is_valid_parameter_change(&change), "Parameter change out of bounds");
+
+        self.charge_proposal_costs(&caller);
+        let proposal_id =
+            self.store_new_proposal(season_id, &caller, title, description, voting_duration_blocks);
+        self.parameter_changes(season_id, proposal_id).set(&change);
+
+        proposal_id
+    }
+
+    /// Open a quadratic funding round, moving `matching_pool` from the treasury.
+    /// The round must close before the current season ends.
+    #[endpoint(startFundingRound)]
+    fn start_funding_round(&self, duration_blocks: u64, matching_pool: BigUint) {
+        self.require_role(Role::Admin);
+        self.require_game_active();
+
+        let round_id = self.current_funding_round_id().get();
+        require!(
+            round_id == 0 || self.funding_rounds(round_id).get().settled,
+            "Previous funding round not settled"
+        );
+
+        let current_block = self.blockchain().get_block_nonce();
+        let game_end = self.game_start_block().get() + self.game_duration_blocks().get();
+        require!(
+            duration_blocks > 0 && current_block + duration_blocks <= game_end,
+            "Round must fall within the season"
+        );
+
+        let treasury = self.treasury().get();
+        require!(matching_pool <= treasury, "Insufficient treasury");
+        let remaining = &treasury - &matching_pool;
+        self.treasury().set(&remaining);
+
+        let round_id = round_id + 1;
+        let round = FundingRound {
+            id: round_id,
+            season_id: self.current_season_id().get(),
+            start_block: current_block,
+            end_block: current_block + duration_blocks,
+            matching_pool,
+            settled: false,
+        };
+        self.funding_rounds(round_id).set(&round);
+        self.current_funding_round_id().set(round_id);
+
+        self.audit(AdminAction::StartFundingRound, &round_id, &treasury, &remaining);
+        self.funding_round_started_event(round_id, round.end_block, &round.matching_pool);
+    }
+
+    /// Submit a community project to the open funding round. The creator receives
+    /// the donations plus the project's share of the matching pool at settlement.
+    #[payable("EGLD")]
+    #[endpoint(submitProject)]
+    fn submit_project(&self, title: ManagedBuffer, description: ManagedBuffer) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let round = self.require_open_funding_round();
+        require!(
+            self.round_projects(round.id).len() < MAX_ROUND_PROJECTS,
+            "Too many projects in this round"
+        );
+
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        self.charge_proposal_costs(&caller);
+
+        // Projects collect donations until the round closes instead of being voted on
+        let current_block = self.blockchain().get_block_nonce();
+        let voting_duration_blocks = round.end_block - current_block;
+        let proposal_id = self.store_new_proposal(season_id, &caller, title, description, voting_duration_blocks);
+        self.proposal_category(season_id, proposal_id).set(ProposalCategory::ProjectFunding);
+        self.project_round(season_id, proposal_id).set(round.id);
+        self.round_projects(round.id).push(&proposal_id);
+
+        self.project_submitted_event(round.id, proposal_id, &caller);
+
+        proposal_id
+    }
+
+    /// Donate EGLD to a project in the open funding round
+    #[payable("EGLD")]
+    #[endpoint(donate)]
+    fn donate(&self, proposal_id: u32) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_value().clone_value();
+        let round = self.require_open_funding_round();
+        let season_id = round.season_id;
+
+        require!(payment > 0, "Must donate EGLD");
+        require!(
+            self.proposal_category(season_id, proposal_id).get() == ProposalCategory::ProjectFunding
+                && self.project_round(season_id, proposal_id).get() == round.id,
+            "Not a project in the open round"
+        );
+
+        // Quadratic funding weighs each donor by the square root of their total donation
+        let donation_mapper = self.project_donations(season_id, proposal_id, &caller);
+        let previous = donation_mapper.get();
+        let donated = &previous + &payment;
+        donation_mapper.set(&donated);
+
+        self.project_sqrt_sum(season_id, proposal_id)
+            .update(|sum| *sum = sum.clone() + donated.sqrt() - previous.sqrt());
+        self.project_total(season_id, proposal_id).update(|total| *total += &payment);
+
+        self.project_donation_event(round.id, proposal_id, &caller, &payment);
+    }
+
+    /// Close an ended funding round. Each project receives its donations plus a share of the
+    /// matching pool proportional to (sum of sqrt(donation))^2 - sum of donations.
+    #[endpoint(settleFundingRound)]
+    fn settle_funding_round(&self) {
+        self.require_not_paused();
+
+        let round_id = self.current_funding_round_id().get();
+        require!(round_id > 0, "No funding round");
+
+        let mut round = self.funding_rounds(round_id).get();
+        require!(!round.settled, "Funding round already settled");
+        require!(self.blockchain().get_block_nonce() > round.end_block, "Funding round still open");
+
+        let season_id = round.season_id;
+        let mut weights: ManagedVec<BigUint> = ManagedVec::new();
+        let mut total_weight = BigUint::zero();
+        for proposal_id in self.round_projects(round_id).iter() {
+            let weight = self.project_match_weight(season_id, proposal_id);
+            total_weight += &weight;
+            weights.push(weight);
+        }
+
+        let mut distributed = BigUint::zero();
+        for (index, proposal_id) in self.round_projects(round_id).iter().enumerate() {
+            let matched = if total_weight > 0 {
+                round.matching_pool.clone() * &*weights.get(index) / &total_weight
+            } else {
+                BigUint::zero()
+            };
+            distributed += &matched;
+
+            let mut proposal = self.load_proposal(season_id, proposal_id);
+            self.set_proposal_status(season_id, &mut proposal, ProposalStatus::Funded);
+            self.save_proposal_tally(season_id, proposal_id, &proposal);
+            self.active_proposals(season_id).swap_remove(&proposal_id);
+            self.proposal_execution_block(season_id, proposal_id)
+                .set(self.blockchain().get_block_nonce());
+
+            let payout = self.project_total(season_id, proposal_id).get() + &matched;
+            if payout > 0 {
+                self.send().direct_egld(&proposal.creator, &payout);
+            }
+
+            self.project_funded_event(round_id, proposal_id, &matched, &payout);
+        }
+
+        // Rounding dust and unmatched pools return to the treasury
+        let remainder = round.matching_pool.clone() - distributed;
+        if remainder > 0 {
+            self.treasury().update(|treasury| *treasury += &remainder);
+        }
+
+        round.settled = true;
+        self.funding_rounds(round_id).set(&round);
+
+        self.funding_round_settled_event(round_id);
+    }
+
+    /// Pay EGLD to feature your open proposal. Boosts on the same proposal stack and refresh
+    /// its expiry; featured proposals are ranked by total boost. The fee goes to the prize pool.
+    #[payable("EGLD")]
+    #[endpoint(boostProposal)]
+    fn boost_proposal(&self, proposal_id: u32) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        require!(
+            payment > 0 && payment >= self.game_config().get().fees.min_boost_amount,
+            "Boost below the minimum price"
+        );
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(proposal.creator == caller, "Only the creator can boost");
+        require!(current_block <= proposal.end_block, "Voting ended");
+
+        // Rebuild the ranking without expired entries and without this proposal's old entry
+        let mut boost_amount = payment.clone();
+        let mut ranking: ManagedVec<FeaturedProposal<Self::Api>> = ManagedVec::new();
+        for featured in self.featured_proposals(season_id).iter() {
+            if featured.proposal_id == proposal_id {
+                boost_amount += &featured.boost_amount;
+            } else if featured.expires_block >= current_block {
+                ranking.push(featured);
+            }
+        }
+
+        let boost_end = current_block + self.game_config().get().boost_duration_blocks;
+        let expires_block = core::cmp::min(boost_end, proposal.end_block);
+        let entry = FeaturedProposal {
+            proposal_id,
+            boost_amount,
+            expires_block,
+        };
+
+        let mut featured_mapper = self.featured_proposals(season_id);
+        featured_mapper.clear();
+        let mut inserted = false;
+        for featured in ranking.iter() {
+            if featured_mapper.len() >= MAX_FEATURED_PROPOSALS {
+                break;
+            }
+            if !inserted && entry.boost_amount > featured.boost_amount {
+                featured_mapper.push(&entry);
+                inserted = true;
+                if featured_mapper.len() >= MAX_FEATURED_PROPOSALS {
+                    break;
+                }
+            }
+            featured_mapper.push(&featured);
+        }
+        if !inserted && featured_mapper.len() < MAX_FEATURED_PROPOSALS {
+            featured_mapper.push(&entry);
+            inserted = true;
+        }
+        require!(inserted, "Boost too small to be featured");
+
+        self.prize_pool(season_id).update(|pool| *pool += &payment);
+        self.season_fees_collected(season_id).update(|fees| *fees += &payment);
+
+        self.proposal_boosted_event(season_id, proposal_id, &caller, &payment);
+    }
+
+    /// Open the recurring council election once the sitting council enters the last
+    /// `election_duration_blocks` of its term, or when no council has been elected yet
+    #[endpoint(openCouncilElection)]
+    fn open_council_election(&self) {
+        self.require_role(Role::Keeper);
+        self.require_not_paused();
+        self.require_game_active();
+
+        let config = self.game_config().get().council;
+        require!(config.enabled, "Council mode disabled");
+        require!(self.council_election().is_empty(), "Council election already open");
+
+        let current_block = self.blockchain().get_block_nonce();
+        if !self.council_members().is_empty() {
+            require!(
+                current_block + config.election_duration_blocks >= self.council_term_end().get(),
+                "Council term still running"
+            );
+        }
+
+        let season_id = self.current_season_id().get();
+        let sc_address = self.blockchain().get_sc_address();
+        let proposal_id = self.store_new_proposal(
+            season_id,
+            &sc_address,
+            ManagedBuffer::from(b"Council election"),
+            ManagedBuffer::from(b"Elects the members allowed to create proposals for the next term"),
+            config.election_duration_blocks,
+        );
+        self.proposal_category(season_id, proposal_id).set(ProposalCategory::CouncilElection);
+        self.council_election().set(CouncilElection {
+            season_id,
+            proposal_id,
+        });
+
+        self.audit(AdminAction::OpenCouncilElection, &season_id, &0u32, &proposal_id);
+        self.council_election_opened_event(
+            season_id,
+            proposal_id,
+            current_block + config.election_duration_blocks,
+        );
+    }
+
+    /// Register as a candidate in the open council election
+    #[endpoint(standForCouncil)]
+    fn stand_for_council(&self) {
+        self.require_not_paused();
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let election = self.require_open_council_election();
+        self.require_joined(election.season_id, &caller);
+
+        let mut candidates = self.council_candidates(election.season_id, election.proposal_id);
+        require!(candidates.len() < MAX_COUNCIL_CANDIDATES, "Too many candidates");
+        require!(candidates.insert(caller.clone()), "Already a candidate");
+
+        self.council_candidate_event(election.proposal_id, &caller);
+    }
+
+    /// Every joined player gets one vote per council election
+    #[endpoint(voteForCouncil)]
+    fn vote_for_council(&self, candidate: ManagedAddress) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let election = self.require_open_council_election();
+        self.require_joined(election.season_id, &caller);
+        require!(
+            self.council_candidates(election.season_id, election.proposal_id)
+                .contains(&candidate),
+            "Not a candidate"
+        );
+        require!(
+            self.council_voters(election.season_id, election.proposal_id)
+                .insert(caller.clone()),
+            "Already voted in this election"
+        );
+
+        self.council_votes(election.season_id, election.proposal_id, &candidate)
+            .update(|votes| *votes += 1);
+
+        self.council_vote_event(election.proposal_id, &caller, &candidate);
+    }
+
+    /// Seat the most voted candidates for a new term once the election has ended.
+    /// Without any votes the sitting council stays in office.
+    #[endpoint(closeCouncilElection)]
+    fn close_council_election(&self) {
+        self.require_not_paused();
+        require!(!self.council_election().is_empty(), "No council election open");
+
+        let election = self.council_election().get();
+        let mut proposal = self.load_proposal(election.season_id, election.proposal_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block > proposal.end_block, "Council election still running");
+
+        let config = self.game_config().get().council;
+        let mut remaining: ManagedVec<ManagedAddress> = ManagedVec::new();
+        for candidate in self
+            .council_candidates(election.season_id, election.proposal_id)
+            .iter()
+        {
+            if self.council_votes(election.season_id, election.proposal_id, &candidate).get() > 0 {
+                remaining.push(candidate);
+            }
+        }
+
+        let mut elected: ManagedVec<ManagedAddress> = ManagedVec::new();
+        while elected.len() < config.seats as usize && !remaining.is_empty() {
+            let mut best_index = 0;
+            let mut best_votes = 0u64;
+            for (index, candidate) in remaining.iter().enumerate() {
+                let votes = self.council_votes(election.season_id, election.proposal_id, &candidate).get();
+                if votes > best_votes {
+                    best_votes = votes;
+                    best_index = index;
+                }
+            }
+            elected.push(remaining.get(best_index).clone_value());
+            remaining.remove(best_index);
+        }
+
+        self.council_election().clear();
+        self.active_proposals(election.season_id)
+            .swap_remove(&election.proposal_id);
+        self.proposal_execution_block(election.season_id, election.proposal_id)
+            .set(current_block);
+        if elected.is_empty() {
+            self.set_proposal_status(election.season_id, &mut proposal, ProposalStatus::Rejected);
+            self.save_proposal_tally(election.season_id, election.proposal_id, &proposal);
+            self.proposal_executed_event(election.proposal_id, false);
+            return;
+        }
+
+        self.set_proposal_status(election.season_id, &mut proposal, ProposalStatus::Passed);
+        self.save_proposal_tally(election.season_id, election.proposal_id, &proposal);
+
+        let term_end_block = current_block + config.term_blocks;
+        self.council_members().clear();
+        for member in elected.iter() {
+            self.council_members().insert(member.clone_value());
+        }
+        self.council_term_end().set(term_end_block);
+
+        self.proposal_executed_event(election.proposal_id, true);
+        self.council_elected_event(election.proposal_id, term_end_block, &elected);
+    }
+
+    // Private functions
+    fn begin_next_season(&self, duration_blocks: u64, config: ScoringConfig) {
+        require!(duration_blocks > 0, "Season duration must be positive");
+
+        let previous_season_id = self.current_season_id().get();
+        self.archive_season(previous_season_id);
+
+        let season_id = previous_season_id + 1;
+        self.current_season_id().set(season_id);
+        self.game_duration_blocks().set(duration_blocks);
+        self.game_start_block().set(self.blockchain().get_block_nonce());
+        self.game_config().update(|game_config| game_config.scoring = config);
+
+        self.open_season(season_id);
+    }
+
+    fn open_season(&self, season_id: u32) {
+        let start_block = self.game_start_block().get();
+        let season = Season {
+            id: season_id,
+            start_block,
+            end_block: start_block + self.game_duration_blocks().get(),
+            scoring_config: self.game_config().get().scoring,
+            total_proposals: 0,
+            archived: false,
+        };
+
+        self.seasons(season_id).set(&season);
+        self.current_proposal_id(season_id).set(1u32);
+
+        self.season_started_event(season_id, start_block, season.end_block);
+    }
+
+    fn archive_season(&self, season_id: u32) {
+        let total_proposals = self.current_proposal_id(season_id).get() - 1;
+        self.seasons(season_id).update(|season| {
+            season.total_proposals = total_proposals;
+            season.archived = true;
+        });
+
+        let leader_mapper = self.season_leader(season_id);
+        if !leader_mapper.is_empty() {
+            self.notify(&leader_mapper.get(), NotificationKind::RewardClaimable, season_id, 0);
+        }
+
+        self.season_archived_event(season_id, total_proposals);
+    }
+
+    fn create_player_proposal(
+        &self,
+        season_id: u32,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+    ) -> u32 {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::ProposalCreation);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        self.require_joined(season_id, &caller);
+        self.require_can_propose(&caller);
+        self.charge_proposal_costs(&caller);
+        let proposal_id =
+            self.store_new_proposal(season_id, &caller, title, description, voting_duration_blocks);
+
+        // Reward creator with DAO points
+        let config = self.game_config().get().scoring;
+        self.add_dao_points(season_id, &caller, config.proposal_creation_points);
+        self.record_quest_progress(season_id, &caller, QuestKind::CreateProposals);
+
+        proposal_id
+    }
+
+    fn store_new_proposal(
+        &self,
+        season_id: u32,
+        creator: &ManagedAddress,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        voting_duration_blocks: u64,
+    ) -> u32 {
+        let proposal_id = self.current_proposal_id(season_id).get();
+        let current_block = self.blockchain().get_block_nonce();
+
+        // Sudden death: short voting windows and a single proposal per player
+        let mut voting_duration_blocks = voting_duration_blocks;
+        if self.is_sudden_death() {
+            let config = self.game_config().get().sudden_death;
+            voting_duration_blocks = core::cmp::min(voting_duration_blocks, config.max_voting_blocks);
+            if creator != &self.blockchain().get_sc_address() {
+                require!(
+                    self.sudden_death_proposers(season_id).insert(creator.clone()),
+                    "Only one proposal per player in sudden death"
+                );
+            }
+        }
+
+        let proposal = Proposal {
+            id: proposal_id,
+            creator: creator.clone(),
+            title,
+            description,
+            votes_for: BigUint::zero(),
+            votes_against: BigUint::zero(),
+            start_block: current_block,
+            end_block: current_block + voting_duration_blocks,
+            status: ProposalStatus::Active,
+        };
+
+        self.push_proposal(season_id, &proposal);
+        self.current_proposal_id(season_id).set(proposal_id + 1);
+        self.active_proposals(season_id).insert(proposal_id);
+        self.proposal_status_count(season_id, ProposalStatus::Active)
+            .update(|count| *count += 1);
+        self.creator_proposals(creator).push(&ProposalRef { season_id, proposal_id });
+
+        if self.game_config().get().superposition_mode {
+            self.superposition_proposals(season_id).insert(proposal_id);
+        }
+
+        self.proposal_created_event(season_id, proposal_id, creator, &proposal.title);
+
+        proposal_id
+    }
+
+    fn spend_guild_treasury(&self, guild_id: u32, recipient: &ManagedAddress, amount: &BigUint) {
+        let treasury = self.guild_treasury(guild_id).get();
+        require!(amount <= &treasury, "Insufficient guild treasury");
+
+        self.guild_treasury(guild_id).set(&(treasury - amount));
+        self.send().direct_egld(recipient, amount);
+
+        self.guild_treasury_spent_event(guild_id, recipient, amount);
+    }
+
+    fn apply_duration_extension(&self, season_id: u32, extension_blocks: u64) {
+        let total_extension = self.season_extension_blocks(season_id).get() + extension_blocks;
+        let max_extension_blocks = self.game_config().get().max_game_extension_blocks;
+        if !self.is_game_active() || total_extension > max_extension_blocks {
+            self.game_extension_skipped_event(season_id, extension_blocks);
+            return;
+        }
+
+        self.season_extension_blocks(season_id).set(total_extension);
+        self.game_duration_blocks().update(|duration| *duration += extension_blocks);
+        self.seasons(season_id).update(|season| season.end_block += extension_blocks);
+
+        self.game_extended_event(season_id, extension_blocks, total_extension);
+    }
+
+    fn finalize_passed_proposal(&self, season_id: u32, mut proposal: Proposal<Self::Api>) {
+        let proposal_id = proposal.id;
+        self.set_proposal_status(season_id, &mut proposal, ProposalStatus::Passed);
+        self.save_proposal_tally(season_id, proposal_id, &proposal);
+
+        // Flash proposals are authored by the contract itself and earn nothing
+        if proposal.creator != self.blockchain().get_sc_address() {
+            // Reward proposal creator with bonus points for successful proposal,
+            // halved if a Decoherence power-up targeted it
+            let config = self.game_config().get().scoring;
+            let bonus = if self.decohered_proposals(season_id).contains(&proposal_id) {
+                config.proposal_passed_bonus / 2
+            } else {
+                config.proposal_passed_bonus
+            };
+            self.add_dao_points(season_id, &proposal.creator, bonus);
+            self.record_quest_progress(season_id, &proposal.creator, QuestKind::PassProposals);
+        }
+
+        // Extension proposals prolong the running season, within the configured bound
+        let extension_mapper = self.duration_extensions(season_id, proposal_id);
+        if !extension_mapper.is_empty() {
+            self.apply_duration_extension(season_id, extension_mapper.get());
+        }
+
+        // Parameter proposals reconfigure the game, re-checked against current bounds
+        let parameter_mapper = self.parameter_changes(season_id, proposal_id);
+        if !parameter_mapper.is_empty() {
+            self.apply_parameter_change(season_id, proposal_id, parameter_mapper.get());
+        }
+
+        // Guild proposals pay out of the guild treasury
+        let guild_spend_mapper = self.guild_spends(season_id, proposal_id);
+        if !guild_spend_mapper.is_empty() {
+            let spend = guild_spend_mapper.get();
+            self.spend_guild_treasury(spend.guild_id, &spend.recipient, &spend.amount);
+        }
+
+        self.close_proposal(season_id, proposal_id, true);
+    }
+
+    /// The side with more backing stake wins, provided it also meets quorum and the pass
+    /// threshold on its own; a tie, or a winner that would not pass alone, closes both
+    fn resolve_counter_pair(&self, season_id: u32, original_id: u32) {
+        let original = self.load_proposal(season_id, original_id);
+        let counter_id = self.counter_proposal(season_id, original_id).get();
+        let counter = self.load_proposal(season_id, counter_id);
+
+        require!(
+            self.blockchain().get_block_nonce() > original.end_block,
+            "Voting still active"
+        );
+        require!(
+            !self.counter_pair_resolved(season_id, original_id).get(),
+            "Proposal already executed"
+        );
+        self.counter_pair_resolved(season_id, original_id).set(true);
+
+        let winner_id = if original.votes_for > counter.votes_for && self.has_proposal_passed(&original) {
+            self.close_proposal(season_id, counter_id, false);
+            self.finalize_passed_proposal(season_id, original);
+            original_id
+        } else if counter.votes_for > original.votes_for && self.has_proposal_passed(&counter) {
+            self.close_proposal(season_id, original_id, false);
+            self.finalize_passed_proposal(season_id, counter);
+            counter_id
+        } else {
+            self.close_proposal(season_id, original_id, false);
+            self.close_proposal(season_id, counter_id, false);
+            0
+        };
+
+        self.counter_pair_resolved_event(original_id, counter_id, winner_id);
+    }
+
+    /// An archived season's scores are final and quorum is measured against the current season,
+    /// so a proposal it left open can no longer pass. Rejects it, or both sides of its counter pair.
+    fn expire_proposal(&self, season_id: u32, proposal_id: u32) {
+        let counter_pair_mapper = self.counter_pair_original(season_id, proposal_id);
+        if counter_pair_mapper.is_empty() {
+            let proposal = self.load_proposal(season_id, proposal_id);
+            require!(proposal.status == ProposalStatus::Active, "Proposal already executed");
+            self.close_proposal(season_id, proposal_id, false);
+            return;
+        }
+
+        let original_id = counter_pair_mapper.get();
+        require!(
+            !self.counter_pair_resolved(season_id, original_id).get(),
+            "Proposal already executed"
+        );
+        self.counter_pair_resolved(season_id, original_id).set(true);
+
+        let counter_id = self.counter_proposal(season_id, original_id).get();
+        self.close_proposal(season_id, original_id, false);
+        self.close_proposal(season_id, counter_id, false);
+
+        self.counter_pair_resolved_event(original_id, counter_id, 0);
+    }
+
+    /// Emits the execution outcome and settles everything that depends on it
+    fn close_proposal(&self, season_id: u32, proposal_id: u32, passed: bool) {
+        self.settle_prediction_pool(season_id, proposal_id, passed);
+        self.active_proposals(season_id).swap_remove(&proposal_id);
+        self.proposal_execution_block(season_id, proposal_id)
+            .set(self.blockchain().get_block_nonce());
+
+        let mut proposal = self.load_proposal(season_id, proposal_id);
+        if !passed {
+            self.set_proposal_status(season_id, &mut proposal, ProposalStatus::Rejected);
+            self.save_proposal_tally(season_id, proposal_id, &proposal);
+        }
+
+        let creator = proposal.creator;
+        if creator != self.blockchain().get_sc_address() {
+            self.notify(&creator, NotificationKind::ProposalExecuted, season_id, proposal_id);
+        }
+        for voter in self.proposal_voters(season_id, proposal_id).iter().take(MAX_NOTIFIED_VOTERS) {
+            self.notify(&voter, NotificationKind::ProposalExecuted, season_id, proposal_id);
+        }
+
+        self.proposal_executed_event(proposal_id, passed);
+    }
+
+    /// Proposal creation costs energy and, when configured, an EGLD fee sent to the treasury
+    fn charge_proposal_costs(&self, caller: &ManagedAddress) {
+        self.require_not_spectator(caller);
+        let energy_config = self.game_config().get().energy;
+        self.consume_energy(caller, &energy_config, energy_config.proposal_cost);
+
+        let payment = self.call_value().egld_value().clone_value();
+        let proposal_fee = if self.is_booster_active(caller, BoosterType::ProposalFeeWaiver) {
+            BigUint::zero()
+        } else {
+            self.current_proposal_fee()
+        };
+        require!(payment == proposal_fee, "Payment must equal the proposal fee");
+
+        if proposal_fee > 0 {
+            self.treasury().update(|treasury| *treasury += &proposal_fee);
+            self.season_fees_collected(self.current_season_id().get())
+                .update(|fees| *fees += &proposal_fee);
+        }
+    }
+
+    fn has_proposal_passed(&self, proposal: &Proposal<Self::Api>) -> bool {
+        let total_votes = &proposal.votes_for + &proposal.votes_against;
+        if total_votes < self.current_quorum() {
+            return false;
+        }
+
+        proposal.votes_for.clone() * MAX_BPS > total_votes * self.current_pass_threshold_bps()
+    }
+
+    /// quorum = base + per_player * players + total_staked * stake_bps, clamped to [min, max]
+    fn current_quorum(&self) -> BigUint {
+        let config_mapper = self.difficulty_config();
+        if config_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let config = config_mapper.get();
+        let season_id = self.current_season_id().get();
+        let active_players = self.players(season_id).len() as u64;
+        let quorum = config.base_quorum
+            + config.quorum_per_player * active_players
+            + self.season_staked(season_id).get() * config.quorum_stake_bps / MAX_BPS;
+
+        if quorum < config.min_quorum {
+            config.min_quorum
+        } else if quorum > config.max_quorum {
+            config.max_quorum
+        } else {
+            quorum
+        }
+    }
+
+    /// threshold = base + per_100_players * players / 100, capped at max
+    fn current_pass_threshold_bps(&self) -> u64 {
+        let config_mapper = self.difficulty_config();
+        if config_mapper.is_empty() {
+            return HALF_BPS;
+        }
+
+        let config = config_mapper.get();
+        let season_id = self.current_season_id().get();
+        let active_players = self.players(season_id).len() as u64;
+        let threshold =
+            config.base_pass_threshold_bps + config.pass_threshold_bps_per_100_players * active_players / 100;
+        core::cmp::min(threshold, config.max_pass_threshold_bps)
+    }
+
+    /// fee = base fee * (1 + players / step), capped at max
+    fn current_proposal_fee(&self) -> BigUint {
+        let base_fee = self.game_config().get().fees.proposal_fee;
+        let config_mapper = self.difficulty_config();
+        if config_mapper.is_empty() || base_fee == 0 {
+            return base_fee;
+        }
+
+        let config = config_mapper.get();
+        let season_id = self.current_season_id().get();
+        let active_players = self.players(season_id).len() as u64;
+        let fee = base_fee * (1 + active_players / config.fee_player_step);
+        if fee > config.max_proposal_fee {
+            config.max_proposal_fee
+        } else {
+            fee
+        }
+    }
+
+    fn require_open_funding_round(&self) -> FundingRound<Self::Api> {
+        let round_id = self.current_funding_round_id().get();
+        require!(round_id > 0, "No funding round");
+
+        let round = self.funding_rounds(round_id).get();
+        require!(
+            !round.settled && self.blockchain().get_block_nonce() <= round.end_block,
+            "Funding round not open"
+        );
+        round
+    }
+
+    /// (sum of sqrt(donation))^2 - sum of donations, floored at zero since square roots round down
+    fn project_match_weight(&self, season_id: u32, proposal_id: u32) -> BigUint {
+        let sqrt_sum = self.project_sqrt_sum(season_id, proposal_id).get();
+        let total = self.project_total(season_id, proposal_id).get();
+        let squared = sqrt_sum.clone() * sqrt_sum;
+        if squared > total {
+            squared - total
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Quorum and threshold changes need dynamic difficulty configured and must stay within its bounds
+    fn is_valid_parameter_change(&self, change: &ParameterChange<Self::Api>) -> bool {
+        let duration_in_bounds = |blocks: u64| {
+            (MIN_GOVERNED_DURATION_BLOCKS..=MAX_GOVERNED_DURATION_BLOCKS).contains(&blocks)
+        };
+
+        match change {
+            ParameterChange::BaseQuorum(quorum) => {
+                let config_mapper = self.difficulty_config();
+                !config_mapper.is_empty()
+                    && *quorum > 0u32
+                    && *quorum <= config_mapper.get().max_quorum
+            },
+            ParameterChange::BasePassThresholdBps(threshold_bps) => {
+                let config_mapper = self.difficulty_config();
+                !config_mapper.is_empty()
+                    && *threshold_bps >= HALF_BPS
+                    && *threshold_bps <= MAX_GOVERNED_PASS_THRESHOLD_BPS
+                    && *threshold_bps <= config_mapper.get().max_pass_threshold_bps
+            },
+            ParameterChange::RevealDuration(blocks) => duration_in_bounds(*blocks),
+            ParameterChange::BoostDuration(blocks) => duration_in_bounds(*blocks),
+            ParameterChange::ProposalFee(fee) => {
+                let config_mapper = self.difficulty_config();
+                config_mapper.is_empty() || *fee <= config_mapper.get().max_proposal_fee
+            },
+            ParameterChange::PredictionFeeBps(fee_bps) => *fee_bps <= MAX_FEE_BPS,
+            ParameterChange::BettingFeeBps(fee_bps) => *fee_bps <= MAX_FEE_BPS,
+            ParameterChange::Scoring(config) => {
+                config.proposal_creation_points <= MAX_GOVERNED_POINTS
+                    && config.vote_points_multiplier <= MAX_GOVERNED_POINTS
+                    && config.proposal_passed_bonus <= MAX_GOVERNED_POINTS
+            },
+        }
+    }
+
+    fn apply_parameter_change(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        change: ParameterChange<Self::Api>,
+    ) {
+        if !self.is_valid_parameter_change(&change) {
+            self.parameter_change_skipped_event(season_id, proposal_id);
+            return;
+        }
+
+        let mut game_config = self.game_config().get();
+        match change.clone() {
+            ParameterChange::BaseQuorum(quorum) => {
+                self.difficulty_config().update(|config| config.base_quorum = quorum);
+            },
+            ParameterChange::BasePassThresholdBps(threshold_bps) => {
+                self.difficulty_config()
+                    .update(|config| config.base_pass_threshold_bps = threshold_bps);
+            },
+            ParameterChange::RevealDuration(blocks) => game_config.reveal_duration_blocks = blocks,
+            ParameterChange::BoostDuration(blocks) => game_config.boost_duration_blocks = blocks,
+            ParameterChange::ProposalFee(fee) => game_config.fees.proposal_fee = fee,
+            ParameterChange::PredictionFeeBps(fee_bps) => game_config.fees.prediction_fee_bps = fee_bps,
+            ParameterChange::BettingFeeBps(fee_bps) => game_config.fees.betting_fee_bps = fee_bps,
+            ParameterChange::Scoring(config) => {
+                self.seasons(season_id)
+                    .update(|season| season.scoring_config = config.clone());
+                game_config.scoring = config;
+            },
+        }
+        self.game_config().set(&game_config);
+
+        self.parameter_changed_event(season_id, proposal_id, &change);
+    }
+
+    fn require_open_council_election(&self) -> CouncilElection {
+        require!(!self.council_election().is_empty(), "No council election open");
+
+        let election = self.council_election().get();
+        let proposal = self.load_proposal(election.season_id, election.proposal_id);
+        require!(
+            self.blockchain().get_block_nonce() <= proposal.end_block,
+            "Council election ended"
+        );
+        election
+    }
+}
@@ -0,0 +1,245 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, league_hub_proxy, raffle_proxy, registry_proxy, types::*};
+
+/// Score reporting and DAO wars through the suite's league hub, resolvable via the suite registry,
+/// and raffle ticket reporting
+#[multiversx_sc::module]
+pub trait LeagueModule:
+    crate::storage::StorageModule + crate::events::EventsModule + crate::admin::AdminModule
+{
+    #[endpoint(setLeagueHub)]
+    fn set_league_hub(&self, league_hub: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&league_hub), "League hub must be a contract");
+        let old_league_hub = if self.league_hub_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.league_hub_address().get()
+        };
+        self.league_hub_address().set(&league_hub);
+
+        self.audit(AdminAction::SetLeagueHub, &(), &old_league_hub, &league_hub);
+    }
+
+    /// Suite registry the league hub address can be resolved from with `syncLeagueHub`
+    #[endpoint(setRegistry)]
+    fn set_registry(&self, registry: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&registry), "Registry must be a contract");
+        let old_registry = if self.registry_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.registry_address().get()
+        };
+        self.registry_address().set(&registry);
+
+        self.audit(AdminAction::SetRegistry, &(), &old_registry, &registry);
+    }
+
+    /// Replace the league hub address with the one currently registered in the suite registry
+    #[endpoint(syncLeagueHub)]
+    fn sync_league_hub(&self) {
+        self.require_role(Role::Keeper);
+        require!(!self.registry_address().is_empty(), "Registry not configured");
+
+        let registry = self.registry_address().get();
+        self.registry_proxy(registry)
+            .get_address(ManagedBuffer::from(REGISTRY_LEAGUE_HUB_NAME))
+            .async_call()
+            .with_callback(self.callbacks().league_hub_sync_callback())
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn league_hub_sync_callback(&self, #[call_result] result: ManagedAsyncCallResult<ManagedAddress>) {
+        if let ManagedAsyncCallResult::Ok(league_hub) = result {
+            self.league_hub_address().set(&league_hub);
+            self.league_hub_synced_event(&league_hub);
+        }
+    }
+
+    /// Report final scores of an ended season to the league hub, in caller-chosen batches of at
+    /// most `MAX_ITERATIONS_PER_CALL` players.
+    /// Players already reported for that season are skipped.
+    #[endpoint(reportSeasonToLeague)]
+    fn report_season_to_league(&self, season_id: u32, players: MultiValueEncoded<ManagedAddress>) {
+        self.require_not_paused();
+        require!(!self.league_hub_address().is_empty(), "League hub not configured");
+
+        self.require_season_ended(season_id);
+        self.require_bounded_batch(players.len());
+
+        let mut scores = MultiValueEncoded::new();
+        let mut reported_players = ManagedVec::new();
+        for player in players {
+            let score = self.dao_scores(season_id, self.player_id(&player)).get();
+            if score == 0 || !self.league_reported(season_id).insert(player.clone()) {
+                continue;
+            }
+
+            scores.push((player.clone(), score).into());
+            reported_players.push(player);
+        }
+        require!(!reported_players.is_empty(), "Nothing to report");
+
+        let league_hub = self.league_hub_address().get();
+        self.league_hub_proxy(league_hub)
+            .report_scores(scores)
+            .async_call()
+            .with_callback(
+                self.callbacks()
+                    .league_report_callback(season_id, reported_players),
+            )
+            .call_and_exit();
+    }
+
+    /// Unmark the batch if the hub rejected it so it can be reported again
+    #[callback]
+    fn league_report_callback(
+        &self,
+        season_id: u32,
+        players: ManagedVec<ManagedAddress>,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.league_scores_reported_event(season_id, players.len());
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                for player in players.iter() {
+                    self.league_reported(season_id).swap_remove(&player);
+                }
+            },
+        }
+    }
+
+    #[endpoint(setRaffle)]
+    fn set_raffle(&self, raffle: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&raffle), "Raffle must be a contract");
+        let old_raffle = if self.raffle_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.raffle_address().get()
+        };
+        self.raffle_address().set(&raffle);
+
+        self.audit(AdminAction::SetRaffle, &(), &old_raffle, &raffle);
+    }
+
+    /// Report the raffle tickets players earned by voting in an ended season, in caller-chosen
+    /// batches of at most `MAX_ITERATIONS_PER_CALL` players.
+    /// Players already reported for that season are skipped.
+    #[endpoint(reportRaffleTickets)]
+    fn report_raffle_tickets(&self, season_id: u32, players: MultiValueEncoded<ManagedAddress>) {
+        self.require_not_paused();
+        require!(!self.raffle_address().is_empty(), "Raffle not configured");
+
+        self.require_season_ended(season_id);
+        self.require_bounded_batch(players.len());
+
+        let mut tickets = MultiValueEncoded::new();
+        let mut reported_players = ManagedVec::new();
+        for player in players {
+            let votes = self.player_vote_count(season_id, self.player_id(&player)).get();
+            if votes == 0 || !self.raffle_reported(season_id).insert(player.clone()) {
+                continue;
+            }
+
+            tickets.push((player.clone(), votes as u64 * RAFFLE_TICKETS_PER_VOTE).into());
+            reported_players.push(player);
+        }
+        require!(!reported_players.is_empty(), "Nothing to report");
+
+        let raffle = self.raffle_address().get();
+        self.raffle_proxy(raffle)
+            .add_tickets(season_id, tickets)
+            .async_call()
+            .with_callback(
+                self.callbacks()
+                    .raffle_report_callback(season_id, reported_players),
+            )
+            .call_and_exit();
+    }
+
+    /// Unmark the batch if the raffle rejected it so it can be reported again
+    #[callback]
+    fn raffle_report_callback(
+        &self,
+        season_id: u32,
+        players: ManagedVec<ManagedAddress>,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.raffle_tickets_reported_event(season_id, players.len());
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                for player in players.iter() {
+                    self.raffle_reported(season_id).swap_remove(&player);
+                }
+            },
+        }
+    }
+
+    /// Challenge another instance registered with the league hub to a DAO war.
+    /// Both instances' aggregate season scores are compared after `deadline_block`.
+    #[endpoint(challengeInstance)]
+    fn challenge_instance(&self, opponent: ManagedAddress, deadline_block: u64) {
+        self.require_role(Role::Admin);
+        self.require_game_active();
+        require!(!self.league_hub_address().is_empty(), "League hub not configured");
+
+        let game_end = self.game_start_block().get() + self.game_duration_blocks().get();
+        require!(deadline_block <= game_end, "Deadline must fall within the season");
+
+        self.audit(AdminAction::ChallengeInstance, &opponent, &0u64, &deadline_block);
+
+        let league_hub = self.league_hub_address().get();
+        self.league_hub_proxy(league_hub)
+            .register_war(opponent, deadline_block, self.current_season_id().get())
+            .async_call()
+            .call_and_exit();
+    }
+
+    #[endpoint(acceptWarChallenge)]
+    fn accept_war_challenge(&self, war_id: u32) {
+        self.require_role(Role::Admin);
+        self.require_game_active();
+        require!(!self.league_hub_address().is_empty(), "League hub not configured");
+
+        self.audit(AdminAction::AcceptWarChallenge, &war_id, &false, &true);
+
+        let league_hub = self.league_hub_address().get();
+        self.league_hub_proxy(league_hub)
+            .accept_war(war_id, self.current_season_id().get())
+            .async_call()
+            .call_and_exit();
+    }
+
+    /// DAO war bonuses paid by the league hub go to the treasury
+    #[payable("EGLD")]
+    #[endpoint(receiveWarBonus)]
+    fn receive_war_bonus(&self, war_id: u32) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.league_hub_address().is_empty() && caller == self.league_hub_address().get(),
+            "Only the league hub can pay war bonuses"
+        );
+
+        let payment = self.call_value().egld_value().clone_value();
+        self.treasury().update(|treasury| *treasury += &payment);
+
+        self.war_bonus_received_event(war_id, &payment);
+    }
+
+    #[proxy]
+    fn league_hub_proxy(&self, sc_address: ManagedAddress) -> league_hub_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn registry_proxy(&self, sc_address: ManagedAddress) -> registry_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn raffle_proxy(&self, sc_address: ManagedAddress) -> raffle_proxy::Proxy<Self::Api>;
+}
@@ -0,0 +1,14 @@
+multiversx_sc::imports!();
+
+/// Endpoints of the suite's league hub called by game instances
+#[multiversx_sc::proxy]
+pub trait LeagueHub {
+    #[endpoint(reportScores)]
+    fn report_scores(&self, scores: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>);
+
+    #[endpoint(registerWar)]
+    fn register_war(&self, opponent: ManagedAddress, deadline_block: u64, season_id: u32) -> u32;
+
+    #[endpoint(acceptWar)]
+    fn accept_war(&self, war_id: u32, season_id: u32);
+}
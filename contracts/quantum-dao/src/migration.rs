@@ -0,0 +1,344 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, types::*};
+
+/// Batched storage migration after an upgrade, from the original single-season layout or any
+/// later one up to `CONTRACT_VERSION`
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::admin::AdminModule
+    + crate::scoring::ScoringModule
+    + crate::staking::StakingModule
+{
+    /// Migrate up to `limit` items (at most `MAX_ITERATIONS_PER_CALL`), resuming where the previous call stopped and stopping early
+    /// once less than `MIGRATION_GAS_RESERVE` gas is left. Each season's
+    /// proposals move from older layouts into the split metadata and tally lists (converting
+    /// the legacy `executed: bool` layout to `ProposalStatus`), followed by the season's players:
+    /// each one's score and NFT claim, then its vote on every proposal, all re-keyed from
+    /// addresses to player ids. Earlier layouts kept no voter list per proposal, so votes are
+    /// found through the players instead. The season's leaderboard comes last.
+    ///
+    /// The original single-season layout moves into `BASELINE_SEASON_ID`. It kept no list of
+    /// voters, so after its proposals the migration waits until `migrateBaselineVotes` has
+    /// brought over every vote, or `finalizeBaselineVotes` has given up on the rest, then
+    /// credits the scores of creators and voters.
+    #[endpoint(migrateStorageBatch)]
+    fn migrate_storage_batch(&self, limit: usize) {
+        self.require_critical_access(Role::Keeper);
+
+        require!(!self.migration_cursor().is_empty(), "No migration in progress");
+        let limit = self.iteration_limit(limit);
+
+        let mut cursor = self.migration_cursor().get();
+        let old_cursor = self.audit_encode(&cursor);
+        let current_season = self.current_season_id().get();
+        let mut processed = 0;
+        while processed < limit
+            && cursor.season_id <= current_season
+            && self.blockchain().get_gas_left() >= MIGRATION_GAS_RESERVE
+        {
+            let season_id = cursor.season_id;
+            if self.is_baseline_layout() {
+                if cursor.proposal_id < self.baseline_current_proposal_id().get() {
+                    self.migrate_baseline_proposal(cursor.proposal_id);
+                    cursor.proposal_id += 1;
+                    processed += 1;
+                    continue;
+                }
+
+                if self.baseline_proposals_awaiting_votes().get() > 0 {
+                    break;
+                }
+
+                let players = self.players(season_id);
+                if cursor.player_index < players.len() {
+                    cursor.player_index += 1;
+                    self.migrate_baseline_player(&players.get_by_index(cursor.player_index));
+                    processed += 1;
+                    continue;
+                }
+
+                self.baseline_current_proposal_id().clear();
+                cursor.season_id += 1;
+                cursor.proposal_id = 1;
+                cursor.player_proposal_id = 0;
+                cursor.player_index = 0;
+                continue;
+            }
+
+            let next_proposal_id = self.current_proposal_id(season_id).get();
+            if cursor.proposal_id < next_proposal_id {
+                self.migrate_proposal(season_id, cursor.proposal_id);
+                cursor.proposal_id += 1;
+                processed += 1;
+                continue;
+            }
+
+            let players = self.players(season_id);
+            if cursor.player_index < players.len() {
+                if cursor.player_proposal_id == next_proposal_id {
+                    cursor.player_index += 1;
+                    cursor.player_proposal_id = 0;
+                    continue;
+                }
+
+                let player = players.get_by_index(cursor.player_index + 1);
+                if cursor.player_proposal_id == 0 {
+                    self.migrate_player(season_id, &player);
+                } else {
+                    self.migrate_vote(season_id, cursor.player_proposal_id, &player);
+                }
+                cursor.player_proposal_id += 1;
+                processed += 1;
+                continue;
+            }
+
+            if self.contract_version().get() < 6 {
+                self.migrate_leaderboard(season_id);
+            }
+            cursor.season_id += 1;
+            cursor.proposal_id = 1;
+            cursor.player_proposal_id = 0;
+            cursor.player_index = 0;
+        }
+
+        if cursor.season_id > current_season {
+            self.migration_cursor().clear();
+            self.contract_version().set(CONTRACT_VERSION);
+
+            self.audit(AdminAction::Migrate, &(), &old_cursor, &ManagedBuffer::new());
+            self.migration_completed_event(CONTRACT_VERSION);
+        } else {
+            self.migration_cursor().set(&cursor);
+
+            self.audit(AdminAction::Migrate, &(), &old_cursor, &self.audit_encode(&cursor));
+        }
+    }
+
+    /// Bring over the original layout's votes on `proposal_id` cast by `voters`, taken from the
+    /// proposal's `vote_cast` events. That layout stored votes by address only, so each voter
+    /// is looked up under its own key; addresses without a vote are skipped. The proposal is
+    /// done once the migrated stakes add up to its tallies.
+    #[endpoint(migrateBaselineVotes)]
+    fn migrate_baseline_votes(&self, proposal_id: u32, voters: MultiValueEncoded<ManagedAddress>) {
+        self.require_critical_access(Role::Keeper);
+        self.require_bounded_batch(voters.len());
+
+        require!(!self.migration_cursor().is_empty(), "No migration in progress");
+        let stake_mapper = self.baseline_unmigrated_stake(proposal_id);
+        require!(!stake_mapper.is_empty(), "No votes awaiting migration");
+
+        let old_stake = stake_mapper.get();
+        let mut unmigrated_stake = old_stake.clone();
+        for voter in voters {
+            let vote_mapper = self.baseline_user_votes(proposal_id, &voter);
+            if vote_mapper.is_empty() {
+                continue;
+            }
+
+            let vote = vote_mapper.take();
+            unmigrated_stake -= &vote.stake_amount;
+            self.migrate_baseline_vote(proposal_id, &voter, vote);
+        }
+
+        if unmigrated_stake == 0 {
+            stake_mapper.clear();
+            self.baseline_proposals_awaiting_votes().update(|count| *count -= 1);
+        } else {
+            stake_mapper.set(&unmigrated_stake);
+        }
+
+        self.audit(AdminAction::Migrate, &proposal_id, &old_stake, &unmigrated_stake);
+    }
+
+    /// Stop waiting for the original layout's votes on `proposal_id` when some can no longer
+    /// be recovered from events. The proposal keeps its tallies; the remaining stake is
+    /// recorded in the audit log and its votes stay under their original keys.
+    #[endpoint(finalizeBaselineVotes)]
+    fn finalize_baseline_votes(&self, proposal_id: u32) {
+        self.require_critical_access(Role::Keeper);
+
+        require!(!self.migration_cursor().is_empty(), "No migration in progress");
+        let stake_mapper = self.baseline_unmigrated_stake(proposal_id);
+        require!(!stake_mapper.is_empty(), "No votes awaiting migration");
+
+        let unmigrated_stake = stake_mapper.take();
+        self.baseline_proposals_awaiting_votes().update(|count| *count -= 1);
+
+        self.audit(AdminAction::Migrate, &proposal_id, &unmigrated_stake, &BigUint::zero());
+    }
+
+    // Private functions
+    /// The original deployment always wrote its proposal counter, which no later layout uses
+    fn is_baseline_layout(&self) -> bool {
+        !self.baseline_current_proposal_id().is_empty()
+    }
+
+    /// Ids are dense and migrated in order, so each proposal lands at its own index
+    fn migrate_proposal(&self, season_id: u32, proposal_id: u32) {
+        let version = self.contract_version().get();
+        let proposal = if version < 3 {
+            let keyed_mapper = self.keyed_proposals(season_id, proposal_id);
+            if keyed_mapper.is_empty() {
+                return;
+            }
+
+            let proposal = if version < 2 {
+                self.convert_legacy_proposal(season_id, self.legacy_proposals(season_id, proposal_id).get())
+            } else {
+                keyed_mapper.get()
+            };
+            keyed_mapper.clear();
+            proposal
+        } else {
+            let mut season_proposals = self.season_proposals(season_id);
+            if proposal_id as usize > season_proposals.len() {
+                return;
+            }
+
+            let proposal = season_proposals.get(proposal_id as usize);
+            season_proposals.clear_entry(proposal_id as usize);
+            proposal
+        };
+        self.push_proposal(season_id, &proposal);
+    }
+
+    /// Rewrites a full vote into the compact `VoteRecord`, re-keying it by player id when it
+    /// predates contract version 5. Most players did not vote on most proposals, so a missing
+    /// vote costs a single read.
+    fn migrate_vote(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) {
+        let vote = if self.contract_version().get() < 5 {
+            let legacy_mapper = self.legacy_user_votes(season_id, proposal_id, voter);
+            if legacy_mapper.is_empty() {
+                return;
+            }
+            legacy_mapper.take()
+        } else {
+            let keyed_mapper = self.keyed_user_votes(season_id, proposal_id, self.player_id(voter));
+            if keyed_mapper.is_empty() {
+                return;
+            }
+            keyed_mapper.take()
+        };
+        let voter_id = self.register_player_id(voter);
+
+        let hidden = !self.vote_commitments(season_id, proposal_id, voter).is_empty();
+        self.user_votes(season_id, proposal_id, voter_id).set(VoteRecord::new(
+            vote.vote_for,
+            hidden,
+            vote.stake_amount,
+            vote.block_number,
+        ));
+    }
+
+    fn migrate_player(&self, season_id: u32, player: &ManagedAddress) {
+        let legacy_score_mapper = self.legacy_dao_scores(season_id, player);
+        let legacy_nft_mapper = self.legacy_nft_claimed(season_id, player);
+        if legacy_score_mapper.is_empty() && legacy_nft_mapper.is_empty() {
+            return;
+        }
+
+        let player_id = self.register_player_id(player);
+        self.dao_scores(season_id, player_id).set(legacy_score_mapper.take());
+        if legacy_nft_mapper.take() {
+            self.nft_claimed(season_id, player_id).set(true);
+        }
+    }
+
+    /// Scores the address-only ranking of earlier versions; bounded by `TOP_PLAYERS_SIZE`
+    fn migrate_leaderboard(&self, season_id: u32) {
+        let mut legacy_mapper = self.legacy_top_players(season_id);
+        let mut leaderboard = self.leaderboard(season_id);
+        for player in legacy_mapper.iter() {
+            let player_id = self.player_id(&player);
+            let score = self.dao_scores(season_id, player_id).get();
+            let position = leaderboard.push(&LeaderboardEntry { player, score });
+            self.leaderboard_position(season_id, player_id).set(position);
+        }
+        legacy_mapper.clear();
+    }
+
+    /// Executed proposals become `Passed`, or `Funded` for funding round projects.
+    /// Unexecuted ones stay `Active` so they can still be executed or rejected normally.
+    fn convert_legacy_proposal(&self, season_id: u32, legacy: LegacyProposal<Self::Api>) -> Proposal<Self::Api> {
+        let status = if !legacy.executed {
+            self.active_proposals(season_id).insert(legacy.id);
+            ProposalStatus::Active
+        } else if self.proposal_category(season_id, legacy.id).get() == ProposalCategory::ProjectFunding {
+            ProposalStatus::Funded
+        } else {
+            ProposalStatus::Passed
+        };
+
+        let proposal = Proposal {
+            id: legacy.id,
+            creator: legacy.creator,
+            title: legacy.title,
+            description: legacy.description,
+            votes_for: legacy.votes_for,
+            votes_against: legacy.votes_against,
+            start_block: legacy.start_block,
+            end_block: legacy.end_block,
+            status,
+        };
+        self.proposal_status_count(season_id, status).update(|count| *count += 1);
+        proposal
+    }
+
+    /// Its creator joins the season so their score is credited once all votes are in.
+    /// An executed proposal ended before the upgrade, so its stakes are withdrawable at once.
+    fn migrate_baseline_proposal(&self, proposal_id: u32) {
+        let season_id = BASELINE_SEASON_ID;
+        let proposal = self.convert_legacy_proposal(season_id, self.baseline_proposals(proposal_id).take());
+
+        let stake = &proposal.votes_for + &proposal.votes_against;
+        if stake > 0 {
+            self.baseline_unmigrated_stake(proposal_id).set(&stake);
+            self.baseline_proposals_awaiting_votes().update(|count| *count += 1);
+        }
+        if proposal.status != ProposalStatus::Active {
+            self.proposal_execution_block(season_id, proposal_id)
+                .set(proposal.end_block);
+        }
+
+        self.creator_proposals(&proposal.creator)
+            .push(&ProposalRef { season_id, proposal_id });
+        self.players(season_id).insert(proposal.creator.clone());
+        self.push_proposal(season_id, &proposal);
+    }
+
+    /// Records the vote as `cast_vote` would, without tallying or rewarding it again:
+    /// the proposal's tallies and the voter's score already include it
+    fn migrate_baseline_vote(&self, proposal_id: u32, voter: &ManagedAddress, vote: Vote<Self::Api>) {
+        let season_id = BASELINE_SEASON_ID;
+        let voter_id = self.register_player_id(voter);
+
+        self.record_stake(season_id, proposal_id, &vote.stake_amount);
+        self.count_vote(season_id, voter_id);
+        self.user_votes(season_id, proposal_id, voter_id).set(VoteRecord::new(
+            vote.vote_for,
+            false,
+            vote.stake_amount,
+            vote.block_number,
+        ));
+        self.proposal_voters(season_id, proposal_id).insert(voter.clone());
+        self.push_vote_history(season_id, proposal_id, voter);
+        self.players(season_id).insert(voter.clone());
+    }
+
+    /// Scores are credited rather than copied, so the season total, score buckets and
+    /// leaderboard account for them
+    fn migrate_baseline_player(&self, player: &ManagedAddress) {
+        let score = self.baseline_dao_scores(player).take();
+        if score > 0 {
+            self.credit_points(BASELINE_SEASON_ID, player, score);
+        }
+
+        if self.baseline_nft_claimed(player).take() {
+            self.nft_claimed(BASELINE_SEASON_ID, self.register_player_id(player))
+                .set(true);
+        }
+    }
+}
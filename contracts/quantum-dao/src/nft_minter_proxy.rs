@@ -0,0 +1,8 @@
+multiversx_sc::imports!();
+
+/// Endpoint of the suite's NFT minter that game instances mint their rewards through
+#[multiversx_sc::proxy]
+pub trait NftMinter {
+    #[endpoint(mintFor)]
+    fn mint_for(&self, recipient: ManagedAddress, tier: u8, attributes: ManagedBuffer) -> u64;
+}
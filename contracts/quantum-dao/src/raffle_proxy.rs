@@ -0,0 +1,8 @@
+multiversx_sc::imports!();
+
+/// Endpoint of the suite's raffle that game instances report tickets to
+#[multiversx_sc::proxy]
+pub trait Raffle {
+    #[endpoint(addTickets)]
+    fn add_tickets(&self, season_id: u32, tickets: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>);
+}
@@ -0,0 +1,8 @@
+multiversx_sc::imports!();
+
+/// Views of the suite's registry resolved by game instances
+#[multiversx_sc::proxy]
+pub trait Registry {
+    #[view(getAddress)]
+    fn get_address(&self, name: ManagedBuffer) -> ManagedAddress;
+}
@@ -0,0 +1,658 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, nft_minter_proxy, types::*, vesting_proxy};
+
+/// Prize pools, quests, tournaments, prediction markets, boosters and spectator betting
+#[multiversx_sc::module]
+pub trait RewardsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::admin::AdminModule
+    + crate::scoring::ScoringModule
+{
+    /// Define a quest for the current season.
+    /// Token rewards must be fully funded up front: `reward_amount * max_claims` sent with the call.
+    #[payable("*")]
+    #[endpoint(createQuest)]
+    fn create_quest(
+        &self,
+        kind: QuestKind,
+        target: u32,
+        start_block: u64,
+        end_block: u64,
+        reward_points: u64,
+        max_claims: u32,
+    ) -> usize {
+        self.require_role(Role::Admin);
+
+        require!(target > 0, "Quest target must be positive");
+        require!(start_block < end_block, "Invalid quest window");
+        require!(max_claims > 0, "Quest must allow at least one claim");
+
+        let season_id = self.current_season_id().get();
+        let quests = self.quests(season_id);
+        require!(quests.len() < MAX_QUESTS_PER_SEASON, "Too many quests this season");
+
+        let (reward_token, reward_funding) = self.call_value().egld_or_single_fungible_esdt();
+        let reward_amount = reward_funding.clone() / max_claims;
+        require!(
+            reward_amount.clone() * max_claims == reward_funding,
+            "Reward funding must be divisible by max claims"
+        );
+
+        let quest = Quest {
+            kind,
+            target,
+            start_block,
+            end_block,
+            reward_points,
+            reward_token,
+            reward_amount,
+            max_claims,
+            claims: 0,
+        };
+        let quest_id = self.quests(season_id).push(&quest);
+
+        self.audit(
+            AdminAction::CreateQuest,
+            &(season_id, quest_id),
+            &ManagedBuffer::new(),
+            &self.audit_encode(&quest),
+        );
+        self.quest_created_event(season_id, quest_id, &quest);
+
+        quest_id
+    }
+
+    /// Claim the reward of a completed quest
+    #[endpoint(claimQuestReward)]
+    fn claim_quest_reward(&self, quest_id: usize) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Claims);
+        self.require_not_blacklisted();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let quests = self.quests(season_id);
+        require!(quest_id > 0 && quest_id <= quests.len(), "Quest does not exist");
+
+        let mut quest = quests.get(quest_id);
+        require!(quest.claims < quest.max_claims, "Quest rewards exhausted");
+        require!(
+            self.quest_progress(season_id, quest_id, &caller).get() >= quest.target,
+            "Quest not completed"
+        );
+        require!(
+            self.quest_claimed(season_id, quest_id).insert(caller.clone()),
+            "Quest reward already claimed"
+        );
+
+        quest.claims += 1;
+        self.quests(season_id).set(quest_id, &quest);
+
+        self.add_dao_points(season_id, &caller, quest.reward_points);
+        if quest.reward_amount > 0 {
+            self.send().direct(&caller, &quest.reward_token, 0, &quest.reward_amount);
+        }
+
+        self.quest_reward_claimed_event(season_id, quest_id, &caller);
+    }
+
+    /// Join the current season by paying its entry fee into the prize pool.
+    /// Only required when the season has an entry fee configured.
+    #[payable("EGLD")]
+    #[endpoint(joinGame)]
+    fn join_game(&self) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let entry_fee = self.entry_fee(season_id).get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        self.require_whitelisted(&caller);
+        require!(entry_fee > 0, "Season has no entry fee");
+        require!(payment == entry_fee, "Payment must equal the entry fee");
+        require!(self.joined_players(season_id).insert(caller.clone()), "Already joined");
+
+        self.prize_pool(season_id).update(|pool| *pool += &payment);
+
+        self.player_joined_event(season_id, &caller, &payment);
+    }
+
+    /// Claim a share of the season prize pool, proportional to the player's DAO score
+    #[endpoint(claimPrize)]
+    fn claim_prize(&self, season_id: u32) {
+        let caller = self.blockchain().get_caller();
+        self.claim_prize_as(&caller, season_id);
+    }
+
+    /// Claim `player`'s prize share for them. Only the trusted router can call this; the
+    /// share is paid to `player`.
+    #[endpoint(claimPrizeFor)]
+    fn claim_prize_for(&self, player: ManagedAddress, season_id: u32) {
+        self.require_trusted_router();
+        self.claim_prize_as(&player, season_id);
+    }
+
+    /// Move what is left of an archived season's prize pool to the treasury once its claim
+    /// window has passed; open to anyone. Shares of players who never joined, and of joined
+    /// players who did not claim in time, would otherwise stay locked in the contract.
+    #[endpoint(sweepPrizePool)]
+    fn sweep_prize_pool(&self, season_id: u32) {
+        self.require_not_paused();
+        require!(!self.seasons(season_id).is_empty(), "Season does not exist");
+
+        let season = self.seasons(season_id).get();
+        require!(season.archived, "Season not archived");
+        require!(
+            self.blockchain().get_block_nonce() > season.end_block + PRIZE_CLAIM_WINDOW_BLOCKS,
+            "Claim window still open"
+        );
+        require!(self.is_betting_settled(season_id), "Betting result not frozen yet");
+        let swept_mapper = self.prize_pool_swept(season_id);
+        require!(!swept_mapper.get(), "Prize pool already swept");
+        swept_mapper.set(true);
+
+        let unclaimed = self.prize_pool(season_id).get() - self.prize_paid(season_id).get();
+        self.treasury().update(|treasury| *treasury += &unclaimed);
+
+        self.prize_pool_swept_event(season_id, &unclaimed);
+    }
+
+    /// Claim the NFT reward of a season, open to its top `NFT_REWARD_WINNERS` players once it
+    /// ended, including seasons archived since
+    #[endpoint(claimReward)]
+    fn claim_nft_reward(&self, season_id: u32) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Claims);
+        self.require_not_blacklisted();
+        self.require_season_ended(season_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(self.nft_claimed(season_id, self.player_id(&caller)).is_empty(), "NFT already claimed");
+
+        let player_score = self.dao_scores(season_id, self.player_id(&caller)).get();
+        require!(player_score > 0, "No DAO score recorded");
+
+        require!(self.is_eligible_for_reward(season_id, &caller), "Not eligible for reward");
+
+        self.nft_claimed(season_id, self.register_player_id(&caller)).set(true);
+        let attributes = TopPlayerNftAttributes {
+            season_id,
+            position: self.leaderboard_position(season_id, self.player_id(&caller)).get() as u32,
+        };
+        self.mint_reward_nft(&caller, TOP_PLAYER_NFT_TIER, &self.audit_encode(&attributes));
+
+        self.nft_claimed_event(&caller, player_score);
+    }
+
+    /// Open tournament registration for the current season with the given group size
+    #[endpoint(setupTournament)]
+    fn setup_tournament(&self, group_size: u32) {
+        self.require_role(Role::Admin);
+        self.require_game_active();
+        require!(group_size >= 2, "Groups need at least two players");
+
+        let season_id = self.current_season_id().get();
+        require!(self.tournament_group_size(season_id).is_empty(), "Tournament already set up");
+
+        self.tournament_group_size(season_id).set(group_size);
+
+        self.audit(AdminAction::SetupTournament, &season_id, &0u32, &group_size);
+    }
+
+    /// Register the caller for the current season's tournament
+    #[endpoint(registerForTournament)]
+    fn register_for_tournament(&self) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+
+        require!(!self.tournament_group_size(season_id).is_empty(), "No tournament this season");
+        require!(self.tournament_phase(season_id).get() == 0, "Registration closed");
+
+        let entrants = self.tournament_participants(season_id, 0);
+        require!(entrants.len() < MAX_TOURNAMENT_PLAYERS, "Tournament is full");
+        require!(self.tournament_group(season_id, 0, &caller).is_empty(), "Already registered");
+
+        let position = entrants.push(&caller);
+        self.tournament_group(season_id, 0, &caller).set(position as u32);
+
+        self.tournament_registered_event(season_id, &caller);
+    }
+
+    /// Close the current phase: group winners by phase points advance to the next phase.
+    /// From registration, entrants are shuffled into groups using the block random seed.
+    /// When a single player remains they win the tournament and receive the grand NFT.
+    #[endpoint(advancePhase)]
+    fn advance_phase(&self) {
+        self.require_role(Role::Keeper);
+
+        let season_id = self.current_season_id().get();
+        require!(!self.tournament_group_size(season_id).is_empty(), "No tournament this season");
+        require!(self.tournament_winner(season_id).is_empty(), "Tournament already finished");
+
+        let phase = self.tournament_phase(season_id).get();
+        let next_phase = phase + 1;
+        let group_size = self.tournament_group_size(season_id).get() as usize;
+
+        let mut advancing: ManagedVec<ManagedAddress> = ManagedVec::new();
+        if phase == 0 {
+            for entrant in self.tournament_participants(season_id, 0).iter() {
+                advancing.push(entrant);
+            }
+            let mut rand_source = RandomnessSource::new();
+            let len = advancing.len();
+            for i in 0..len {
+                let j = rand_source.next_usize_in_range(i, len);
+                let entrant_i = advancing.get(i).clone_value();
+                let entrant_j = advancing.get(j).clone_value();
+                let _ = advancing.set(i, &entrant_j);
+                let _ = advancing.set(j, &entrant_i);
+            }
+        } else {
+            let participants = self.tournament_participants(season_id, phase);
+            let mut group_start = 1;
+            while group_start <= participants.len() {
+                let group_end = core::cmp::min(group_start + group_size, participants.len() + 1);
+                let mut group_winner = participants.get(group_start);
+                let mut best_score = self.tournament_phase_scores(season_id, phase, &group_winner).get();
+                for position in (group_start + 1)..group_end {
+                    let player = participants.get(position);
+                    let score = self.tournament_phase_scores(season_id, phase, &player).get();
+                    if score > best_score {
+                        best_score = score;
+                        group_winner = player;
+                    }
+                }
+                advancing.push(group_winner);
+                group_start = group_end;
+            }
+        }
+
+        require!(!advancing.is_empty(), "No tournament participants");
+        self.tournament_phase(season_id).set(next_phase);
+        self.audit(AdminAction::AdvanceTournamentPhase, &season_id, &phase, &next_phase);
+
+        if phase > 0 && advancing.len() == 1 {
+            let winner = advancing.get(0).clone_value();
+            self.tournament_winner(season_id).set(&winner);
+            self.mint_reward_nft(&winner, GRAND_NFT_TIER, &self.audit_encode(&GrandNftAttributes { season_id }));
+
+            self.tournament_won_event(season_id, &winner);
+            return;
+        }
+
+        let next_participants = self.tournament_participants(season_id, next_phase);
+        for (index, player) in advancing.iter().enumerate() {
+            next_participants.push(&player);
+            let group = (index / group_size) as u32 + 1;
+            self.tournament_group(season_id, next_phase, &player).set(group);
+        }
+
+        self.tournament_phase_advanced_event(season_id, next_phase, advancing.len());
+    }
+
+    /// Stake EGLD on whether a proposal will pass. Only open to players who have not voted on it.
+    #[payable("EGLD")]
+    #[endpoint(predictOutcome)]
+    fn predict_outcome(&self, proposal_id: u32, predicts_pass: bool) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        require!(payment > 0, "Must stake EGLD to predict");
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+        self.require_standard_proposal(season_id, proposal_id);
+        require!(
+            self.blockchain().get_block_nonce() <= self.proposal_info(season_id).get(proposal_id as usize).end_block,
+            "Voting ended"
+        );
+        require!(self.user_votes(season_id, proposal_id, self.player_id(&caller)).is_empty(), "Voters cannot predict");
+        require!(
+            self.prediction_stakes(season_id, proposal_id, &caller).is_empty(),
+            "Already predicted"
+        );
+
+        self.prediction_stakes(season_id, proposal_id, &caller).set(PredictionStake {
+            predicts_pass,
+            amount: payment.clone(),
+        });
+        let pool_mapper = self.prediction_pools(season_id, proposal_id);
+        let mut pool = if pool_mapper.is_empty() {
+            PredictionPool::new()
+        } else {
+            pool_mapper.get()
+        };
+        if predicts_pass {
+            pool.staked_pass += &payment;
+        } else {
+            pool.staked_fail += &payment;
+        }
+        pool_mapper.set(&pool);
+
+        self.prediction_placed_event(proposal_id, &caller, predicts_pass, &payment);
+    }
+
+    /// Collect winnings (or a refund if nobody backed the winning side) from a settled prediction pool
+    #[endpoint(claimPrediction)]
+    fn claim_prediction(&self, season_id: u32, proposal_id: u32) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Claims);
+        self.require_not_blacklisted();
+
+        let caller = self.blockchain().get_caller();
+        let stake_mapper = self.prediction_stakes(season_id, proposal_id, &caller);
+        require!(!stake_mapper.is_empty(), "No prediction placed");
+
+        let pool = self.prediction_pools(season_id, proposal_id).get();
+        require!(pool.settled, "Prediction pool not settled");
+
+        let stake = stake_mapper.get();
+        stake_mapper.clear();
+
+        let payout = self.prediction_payout(&pool, &stake);
+
+        if payout > 0 {
+            self.send().direct_egld(&caller, &payout);
+        }
+
+        self.prediction_claimed_event(proposal_id, &caller, &payout);
+    }
+
+    /// Buy a time-limited booster. Buying again while active extends it.
+    /// Revenue is split between the season prize pool and the treasury; the prize pool part
+    /// nobody claims reaches the treasury through `sweepPrizePool`.
+    #[payable("EGLD")]
+    #[endpoint(buyBooster)]
+    fn buy_booster(&self, booster_type: BoosterType) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+
+        let offer_mapper = self.booster_offers(booster_type);
+        require!(!offer_mapper.is_empty(), "Booster not for sale");
+
+        let offer = offer_mapper.get();
+        let payment = self.call_value().egld_value().clone_value();
+        require!(payment == offer.price, "Payment must equal the booster price");
+
+        let current_block = self.blockchain().get_block_nonce();
+        let expiry_mapper = self.active_boosters(&caller, booster_type);
+        let start_block = core::cmp::max(expiry_mapper.get(), current_block);
+        let expiry_block = start_block + offer.duration_blocks;
+        expiry_mapper.set(expiry_block);
+
+        let share_bps = self.game_config().get().fees.booster_prize_pool_share_bps;
+        let prize_pool_share = payment.clone() * share_bps / MAX_BPS;
+        let treasury_share = &payment - &prize_pool_share;
+        self.prize_pool(season_id).update(|pool| *pool += &prize_pool_share);
+        self.treasury().update(|treasury| *treasury += &treasury_share);
+
+        self.booster_bought_event(&caller, booster_type, expiry_block, &payment);
+    }
+
+    /// Open spectator betting on the current season's winner until `cutoff_block`
+    #[endpoint(openBetting)]
+    fn open_betting(&self, cutoff_block: u64) {
+        self.require_role(Role::Admin);
+        self.require_game_active();
+
+        let season_id = self.current_season_id().get();
+        let game_end = self.game_start_block().get() + self.game_duration_blocks().get();
+        require!(self.betting_cutoff_block(season_id).is_empty(), "Betting already opened");
+        require!(
+            cutoff_block > self.blockchain().get_block_nonce() && cutoff_block <= game_end,
+            "Cutoff must fall within the season"
+        );
+
+        self.betting_cutoff_block(season_id).set(cutoff_block);
+
+        self.audit(AdminAction::OpenBetting, &season_id, &0u64, &cutoff_block);
+    }
+
+    /// Bet on which address finishes the season at #1. Players of the season cannot bet.
+    /// The zero address stands for "no leader" when the result is frozen, so it cannot be backed.
+    #[payable("EGLD")]
+    #[endpoint(placeBet)]
+    fn place_bet(&self, candidate: ManagedAddress) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        let cutoff_mapper = self.betting_cutoff_block(season_id);
+        require!(!cutoff_mapper.is_empty(), "Betting not open");
+        require!(self.blockchain().get_block_nonce() <= cutoff_mapper.get(), "Betting closed");
+        require!(payment > 0, "Must stake EGLD to bet");
+        require!(!candidate.is_zero(), "Invalid candidate");
+        require!(
+            !self.players(season_id).contains(&caller) && !self.joined_players(season_id).contains(&caller),
+            "Players cannot bet"
+        );
+        require!(self.season_bets(season_id, &caller).is_empty(), "Already placed a bet");
+
+        self.season_bets(season_id, &caller).set(SeasonBet {
+            candidate: candidate.clone(),
+            amount: payment.clone(),
+        });
+        self.bets_on_candidate(season_id, &candidate).update(|total| *total += &payment);
+        self.bets_total(season_id).update(|total| *total += &payment);
+
+        self.bet_placed_event(season_id, &caller, &candidate, &payment);
+    }
+
+    /// Freeze the season winner once the season has ended and take the betting fee into the prize pool
+    #[endpoint(freezeBettingResult)]
+    fn freeze_betting_result(&self, season_id: u32) {
+        self.require_not_paused();
+        self.require_season_ended(season_id);
+        require!(!self.betting_cutoff_block(season_id).is_empty(), "Betting not open");
+        require!(self.bet_winner(season_id).is_empty(), "Result already frozen");
+
+        let leader_mapper = self.season_leader(season_id);
+        let winner = if leader_mapper.is_empty() {
+            ManagedAddress::zero()
+        } else {
+            leader_mapper.get()
+        };
+        self.bet_winner(season_id).set(&winner);
+
+        // Nobody backed the winner: every bet is refunded without a fee
+        if self.bets_on_candidate(season_id, &winner).get() > 0 {
+            let fee = self.bets_total(season_id).get() * self.game_config().get().fees.betting_fee_bps / MAX_BPS;
+            self.betting_fee(season_id).set(&fee);
+            self.prize_pool(season_id).update(|pool| *pool += &fee);
+            self.season_fees_collected(season_id).update(|fees| *fees += &fee);
+        }
+
+        self.betting_result_frozen_event(season_id, &winner);
+    }
+
+    #[endpoint(claimBet)]
+    fn claim_bet(&self, season_id: u32) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Claims);
+        self.require_not_blacklisted();
+
+        let caller = self.blockchain().get_caller();
+        let bet_mapper = self.season_bets(season_id, &caller);
+        require!(!bet_mapper.is_empty(), "No bet placed");
+        require!(!self.bet_winner(season_id).is_empty(), "Result not frozen yet");
+
+        let bet = bet_mapper.get();
+        bet_mapper.clear();
+
+        let payout = self.bet_payout(season_id, &bet);
+
+        if payout > 0 {
+            self.send().direct_egld(&caller, &payout);
+        }
+
+        self.bet_claimed_event(season_id, &caller, &payout);
+    }
+
+    /// Mint reward NFTs through the suite's NFT minter, which must have this game registered.
+    /// The minter must live on the same shard, as it is called synchronously.
+    #[endpoint(setNftMinter)]
+    fn set_nft_minter(&self, nft_minter: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&nft_minter), "NFT minter must be a contract");
+        let old_nft_minter = if self.nft_minter_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.nft_minter_address().get()
+        };
+        self.nft_minter_address().set(&nft_minter);
+
+        self.audit(AdminAction::SetNftMinter, &(), &old_nft_minter, &nft_minter);
+    }
+
+    /// List grants of the suite's vesting contract among players' claimable rewards.
+    /// The contract must live on the same shard, as it is queried synchronously.
+    #[endpoint(setVesting)]
+    fn set_vesting(&self, vesting: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&vesting), "Vesting must be a contract");
+        let old_vesting = if self.vesting_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.vesting_address().get()
+        };
+        self.vesting_address().set(&vesting);
+
+        self.audit(AdminAction::SetVesting, &(), &old_vesting, &vesting);
+    }
+
+    // Private functions
+    fn claim_prize_as(&self, player: &ManagedAddress, season_id: u32) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Claims);
+        self.require_player_not_blacklisted(player);
+
+        self.require_season_ended(season_id);
+
+        require!(self.joined_players(season_id).contains(player), "Not a joined player");
+        require!(!self.prize_pool_swept(season_id).get(), "Prize pool swept");
+        require!(self.is_betting_settled(season_id), "Betting result not frozen yet");
+        require!(self.prize_claimed(season_id).insert(player.clone()), "Prize already claimed");
+
+        require!(self.dao_scores(season_id, self.player_id(player)).get() > 0, "No DAO score recorded");
+
+        let share = self.prize_share(season_id, player);
+        if share > 0 {
+            self.prize_paid(season_id).update(|paid| *paid += &share);
+            self.send().direct_egld(player, &share);
+        }
+
+        self.prize_claimed_event(season_id, player, &share);
+    }
+
+    /// The betting fee joins the prize pool when the result is frozen, so prize shares are only
+    /// final once betting was never opened or its result is frozen
+    fn is_betting_settled(&self, season_id: u32) -> bool {
+        self.betting_cutoff_block(season_id).is_empty() || !self.bet_winner(season_id).is_empty()
+    }
+
+    /// The minter sends the NFT straight to `recipient`
+    fn mint_reward_nft(&self, recipient: &ManagedAddress, tier: u8, attributes: &ManagedBuffer) {
+        require!(!self.nft_minter_address().is_empty(), "NFT minter not configured");
+
+        self.nft_minter_proxy(self.nft_minter_address().get())
+            .mint_for(recipient.clone(), tier, attributes.clone())
+            .execute_on_dest_context::<u64>();
+    }
+
+    /// The fee is only charged on the losing side, and only when there are winners to pay
+    fn settle_prediction_pool(&self, season_id: u32, proposal_id: u32, passed: bool) {
+        let pool_mapper = self.prediction_pools(season_id, proposal_id);
+        if pool_mapper.is_empty() {
+            return;
+        }
+
+        let mut pool = pool_mapper.get();
+        let (winning_total, losing_total) = if passed {
+            (&pool.staked_pass, &pool.staked_fail)
+        } else {
+            (&pool.staked_fail, &pool.staked_pass)
+        };
+
+        let fee = if *winning_total == 0 {
+            BigUint::zero()
+        } else {
+            losing_total.clone() * self.game_config().get().fees.prediction_fee_bps / MAX_BPS
+        };
+        self.treasury().update(|treasury| *treasury += &fee);
+        self.season_fees_collected(season_id).update(|fees| *fees += &fee);
+
+        pool.settled = true;
+        pool.passed = passed;
+        pool.fee = fee;
+        pool_mapper.set(&pool);
+
+        self.prediction_pool_settled_event(proposal_id, passed, &pool.fee);
+    }
+
+    fn prize_share(&self, season_id: u32, player: &ManagedAddress) -> BigUint {
+        let total_score = self.total_score(season_id).get();
+        if total_score == 0 {
+            return BigUint::zero();
+        }
+
+        self.prize_pool(season_id).get() * self.dao_scores(season_id, self.player_id(player)).get() / total_score
+    }
+
+    /// Winners split the losing side minus the fee; if nobody backed the outcome, stakes are refunded
+    fn prediction_payout(
+        &self,
+        pool: &PredictionPool<Self::Api>,
+        stake: &PredictionStake<Self::Api>,
+    ) -> BigUint {
+        let (winning_total, losing_total) = if pool.passed {
+            (&pool.staked_pass, &pool.staked_fail)
+        } else {
+            (&pool.staked_fail, &pool.staked_pass)
+        };
+
+        if *winning_total == 0 {
+            stake.amount.clone()
+        } else if stake.predicts_pass == pool.passed {
+            let distributable = losing_total - &pool.fee;
+            &stake.amount + &(&stake.amount * &distributable / winning_total)
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Backers of the frozen winner split the pot minus the fee; if nobody backed them, bets are refunded
+    fn bet_payout(&self, season_id: u32, bet: &SeasonBet<Self::Api>) -> BigUint {
+        let winner = self.bet_winner(season_id).get();
+        let winning_total = self.bets_on_candidate(season_id, &winner).get();
+        if winning_total == 0 {
+            bet.amount.clone()
+        } else if bet.candidate == winner {
+            let distributable = self.bets_total(season_id).get() - self.betting_fee(season_id).get();
+            &bet.amount * &distributable / &winning_total
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    #[proxy]
+    fn nft_minter_proxy(&self, sc_address: ManagedAddress) -> nft_minter_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn vesting_proxy(&self, sc_address: ManagedAddress) -> vesting_proxy::Proxy<Self::Api>;
+}
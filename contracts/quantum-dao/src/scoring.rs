@@ -0,0 +1,484 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, types::*};
+
+/// DAO points, energy, check-ins, entanglement and player notifications
+#[multiversx_sc::module]
+pub trait ScoringModule:
+    crate::storage::StorageModule + crate::events::EventsModule + crate::admin::AdminModule
+{
+    /// Daily check-in, claimable once per check-in epoch.
+    /// Consecutive epochs build a streak that escalates the points granted.
+    #[endpoint(checkIn)]
+    fn check_in(&self) {
+        self.require_not_paused();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        self.require_joined(season_id, &caller);
+
+        let config = self.game_config().get().check_in;
+        let current_block = self.blockchain().get_block_nonce();
+        let current_epoch = current_block / config.epoch_blocks;
+
+        let last_check_in_mapper = self.last_check_in_block(&caller);
+        let streak = if last_check_in_mapper.is_empty() {
+            1
+        } else {
+            let last_epoch = last_check_in_mapper.get() / config.epoch_blocks;
+            require!(last_epoch < current_epoch, "Already checked in this epoch");
+
+            if last_epoch + 1 == current_epoch {
+                core::cmp::min(self.check_in_streak(&caller).get() + 1, config.max_streak)
+            } else {
+                1
+            }
+        };
+
+        last_check_in_mapper.set(current_block);
+        self.check_in_streak(&caller).set(streak);
+
+        let points = config.base_points + config.streak_bonus_points * (streak - 1);
+        self.add_dao_points(season_id, &caller, points);
+
+        self.checked_in_event(&caller, streak, points);
+    }
+
+    /// Create or update the caller's public profile.
+    /// Nicknames are unique; changing nickname releases the previous one.
+    #[endpoint(setProfile)]
+    fn set_profile(
+        &self,
+        nickname: ManagedBuffer,
+        bio_hash: ManagedBuffer,
+        avatar: OptionalValue<MultiValue2<TokenIdentifier, u64>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            nickname.len() >= MIN_NICKNAME_LENGTH && nickname.len() <= MAX_NICKNAME_LENGTH,
+            "Invalid nickname length"
+        );
+        require!(
+            bio_hash.is_empty() || bio_hash.len() == BIO_HASH_LENGTH,
+            "Invalid bio hash"
+        );
+
+        let nickname_owner_mapper = self.nickname_owner(&nickname);
+        require!(
+            nickname_owner_mapper.is_empty() || nickname_owner_mapper.get() == caller,
+            "Nickname taken"
+        );
+
+        let profile_mapper = self.profiles(&caller);
+        if !profile_mapper.is_empty() {
+            let previous_nickname = profile_mapper.get().nickname;
+            if previous_nickname != nickname {
+                self.nickname_owner(&previous_nickname).clear();
+            }
+        }
+
+        let avatar = avatar.into_option().map(|avatar| {
+            let (token_id, nonce) = avatar.into_tuple();
+            require!(token_id.is_valid_esdt_identifier() && nonce > 0, "Invalid avatar NFT");
+            AvatarRef { token_id, nonce }
+        });
+
+        let profile = Profile {
+            nickname: nickname.clone(),
+            avatar,
+            bio_hash,
+            updated_block: self.blockchain().get_block_nonce(),
+        };
+        profile_mapper.set(&profile);
+        nickname_owner_mapper.set(&caller);
+
+        self.profile_updated_event(&caller, &nickname);
+    }
+
+    /// Ask another player to entangle; they complete the pairing with `acceptEntanglement`
+    #[endpoint(requestEntanglement)]
+    fn request_entanglement(&self, partner: ManagedAddress) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        require!(caller != partner, "Cannot entangle with yourself");
+        require!(self.entanglement_partner(&caller).is_empty(), "Already entangled");
+        require!(self.entanglement_partner(&partner).is_empty(), "Partner already entangled");
+
+        self.pending_entanglement(&caller).set(&partner);
+
+        self.entanglement_requested_event(&caller, &partner);
+    }
+
+    #[endpoint(acceptEntanglement)]
+    fn accept_entanglement(&self, requester: ManagedAddress) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let pending_mapper = self.pending_entanglement(&requester);
+        require!(
+            !pending_mapper.is_empty() && pending_mapper.get() == caller,
+            "No entanglement request from this player"
+        );
+        require!(self.entanglement_partner(&caller).is_empty(), "Already entangled");
+        require!(self.entanglement_partner(&requester).is_empty(), "Partner already entangled");
+
+        pending_mapper.clear();
+        self.pending_entanglement(&caller).clear();
+
+        let current_block = self.blockchain().get_block_nonce();
+        self.entanglement_partner(&caller).set(&requester);
+        self.entanglement_partner(&requester).set(&caller);
+        self.entangled_since_block(&requester).set(current_block);
+        self.entangled_pairs().insert(requester.clone());
+
+        self.entanglement_formed_event(&requester, &caller);
+    }
+
+    /// Break the caller's pairing once the minimum lock period has passed
+    #[endpoint(disentangle)]
+    fn disentangle(&self) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let partner_mapper = self.entanglement_partner(&caller);
+        require!(!partner_mapper.is_empty(), "Not entangled");
+
+        let partner = partner_mapper.get();
+        let pair_key = if self.entangled_pairs().contains(&caller) {
+            caller.clone()
+        } else {
+            partner.clone()
+        };
+
+        let unlock_block =
+            self.entangled_since_block(&pair_key).get() + self.game_config().get().entanglement.min_lock_blocks;
+        require!(self.blockchain().get_block_nonce() >= unlock_block, "Entanglement still locked");
+
+        partner_mapper.clear();
+        self.entanglement_partner(&partner).clear();
+        self.entangled_since_block(&pair_key).clear();
+        self.entangled_pairs().swap_remove(&pair_key);
+
+        self.entanglement_broken_event(&caller, &partner);
+    }
+
+    /// Drop the `count` oldest notifications once a frontend has shown them
+    #[endpoint(ackNotifications)]
+    fn ack_notifications(&self, count: usize) {
+        let caller = self.blockchain().get_caller();
+        let mut queue = self.notifications(&caller);
+        for _ in 0..count {
+            if queue.pop_front().is_none() {
+                break;
+            }
+        }
+    }
+
+    // View functions
+    #[view(isDoublePointsActive)]
+    fn is_double_points_active(&self) -> bool {
+        let season_id = self.current_season_id().get();
+        self.blockchain().get_block_nonce() <= self.double_points_end_block(season_id).get()
+    }
+
+    /// True during the last `duration_blocks` of an active season
+    #[view(isSuddenDeath)]
+    fn is_sudden_death(&self) -> bool {
+        let config = self.game_config().get().sudden_death;
+        if config.duration_blocks == 0 || !self.is_game_active() {
+            return false;
+        }
+
+        let game_end = self.game_start_block().get() + self.game_duration_blocks().get();
+        self.blockchain().get_block_nonce() + config.duration_blocks >= game_end
+    }
+
+    // Private functions
+    fn record_quest_progress(&self, season_id: u32, player: &ManagedAddress, kind: QuestKind) {
+        let current_block = self.blockchain().get_block_nonce();
+        for (index, quest) in self.quests(season_id).iter().enumerate() {
+            if quest.kind == kind
+                && quest.start_block <= current_block
+                && current_block <= quest.end_block
+            {
+                let progress = self.quest_progress(season_id, index + 1, player).update(|progress| {
+                    *progress += 1;
+                    *progress
+                });
+                if progress == quest.target {
+                    self.notify(player, NotificationKind::RewardClaimable, season_id, index as u32 + 1);
+                }
+            }
+        }
+    }
+
+    /// Returns the points credited to `player` once multipliers are applied
+    fn add_dao_points(&self, season_id: u32, player: &ManagedAddress, points: u64) -> u64 {
+        self.add_dao_points_mirrored(season_id, player, points).0
+    }
+
+    /// Like `add_dao_points`, also returning the share mirrored to `player`'s entangled partner
+    fn add_dao_points_mirrored(
+        &self,
+        season_id: u32,
+        player: &ManagedAddress,
+        points: u64,
+    ) -> (u64, Option<MirroredPoints<Self::Api>>) {
+        let points = if self.is_double_points_active() {
+            points * 2
+        } else {
+            points
+        };
+        let points = if self.is_sudden_death() {
+            points * 2
+        } else {
+            points
+        };
+        let points = if self.is_booster_active(player, BoosterType::PointMultiplier) {
+            points * BOOSTED_POINTS_PERCENT / 100
+        } else {
+            points
+        };
+
+        self.credit_points(season_id, player, points);
+
+        // Entangled partners mirror a share of each other's earnings; mirrored points are not re-mirrored
+        let partner_mapper = self.entanglement_partner(player);
+        if partner_mapper.is_empty() {
+            return (points, None);
+        }
+
+        let share_bps = self.game_config().get().entanglement.share_bps;
+        let mirrored_points = points * share_bps / MAX_BPS;
+        if mirrored_points == 0 {
+            return (points, None);
+        }
+
+        let partner = partner_mapper.get();
+        self.credit_points(season_id, &partner, mirrored_points);
+        (
+            points,
+            Some(MirroredPoints {
+                partner,
+                points: mirrored_points,
+            }),
+        )
+    }
+
+    /// Takes back points credited earlier, e.g. for a revoked vote
+    fn deduct_dao_points(&self, season_id: u32, player: &ManagedAddress, points: u64) {
+        let score_mapper = self.dao_scores(season_id, self.player_id(player));
+        let old_score = score_mapper.get();
+        let deducted = core::cmp::min(points, old_score);
+        if deducted == 0 {
+            return;
+        }
+
+        let score = old_score - deducted;
+        score_mapper.set(score);
+        self.score_changed_event(
+            season_id,
+            player,
+            &ScoreChange {
+                previous_score: old_score,
+                new_score: score,
+            },
+        );
+        self.total_score(season_id).update(|total| *total -= deducted);
+        self.move_score_bucket(season_id, old_score, score);
+
+        if self.leaderboard_position(season_id, self.player_id(player)).get() == 0 {
+            return;
+        }
+        self.update_leaderboard(season_id, player, score);
+
+        let leader_mapper = self.season_leader(season_id);
+        if &leader_mapper.get() == player {
+            leader_mapper.set(self.leaderboard(season_id).get(1).player);
+        }
+    }
+
+    fn credit_points(&self, season_id: u32, player: &ManagedAddress, points: u64) {
+        let score = self.dao_scores(season_id, self.register_player_id(player)).update(|score| {
+            *score += points;
+            *score
+        });
+        self.total_score(season_id).update(|total| *total += points);
+        self.players(season_id).insert(player.clone());
+        self.move_score_bucket(season_id, score - points, score);
+        self.score_changed_event(
+            season_id,
+            player,
+            &ScoreChange {
+                previous_score: score - points,
+                new_score: score,
+            },
+        );
+
+        let leader_mapper = self.season_leader(season_id);
+        if leader_mapper.is_empty() {
+            leader_mapper.set(player);
+        } else {
+            let leader = leader_mapper.get();
+            if score > self.dao_scores(season_id, self.player_id(&leader)).get() {
+                if &leader != player {
+                    self.notify(&leader, NotificationKind::Overtaken, season_id, 0);
+                }
+                leader_mapper.set(player);
+            }
+        }
+        self.update_leaderboard(season_id, player, score);
+
+        // Tournament phases score independently of the season total
+        let phase = self.tournament_phase(season_id).get();
+        if phase > 0 && !self.tournament_group(season_id, phase, player).is_empty() {
+            self.tournament_phase_scores(season_id, phase, player)
+                .update(|score| *score += points);
+        }
+    }
+
+    fn move_score_bucket(&self, season_id: u32, old_score: u64, new_score: u64) {
+        let old_bucket = self.score_bucket(old_score);
+        let new_bucket = self.score_bucket(new_score);
+        if old_bucket == new_bucket {
+            return;
+        }
+
+        if old_bucket > 0 {
+            self.score_bucket_players(season_id, old_bucket).update(|count| *count -= 1);
+        }
+        self.score_bucket_players(season_id, new_bucket).update(|count| *count += 1);
+    }
+
+    /// Number of significant bits of `score`; 0 for players without points
+    fn score_bucket(&self, score: u64) -> u32 {
+        u64::BITS - score.leading_zeros()
+    }
+
+    /// Moves `player` to its slot in the season leaderboard after its score changed to `score`.
+    /// An unranked player at or below the cutoff of a full leaderboard costs two reads and no
+    /// writes. Otherwise each step shifts one neighbour by a single slot, so an update writes at
+    /// most `TOP_PLAYERS_SIZE` entries and positions whatever the scores.
+    /// A ranked player sinking after a deduction keeps their place over unranked players that
+    /// now outscore them until one of those crosses the cutoff.
+    fn update_leaderboard(&self, season_id: u32, player: &ManagedAddress, score: u64) {
+        let mut leaderboard = self.leaderboard(season_id);
+        let position_mapper = self.leaderboard_position(season_id, self.player_id(player));
+        let mut position = position_mapper.get();
+
+        if position == 0 {
+            let len = leaderboard.len();
+            if len < TOP_PLAYERS_SIZE {
+                position = leaderboard.push(&LeaderboardEntry {
+                    player: player.clone(),
+                    score,
+                });
+            } else {
+                let cutoff = leaderboard.get(len);
+                if score <= cutoff.score {
+                    return;
+                }
+                self.leaderboard_position(season_id, self.player_id(&cutoff.player)).clear();
+                position = len;
+            }
+        }
+
+        while position > 1 {
+            let ahead = leaderboard.get(position - 1);
+            if ahead.score >= score {
+                break;
+            }
+            self.place_leaderboard_entry(season_id, &mut leaderboard, position, &ahead);
+            position -= 1;
+        }
+        while position < leaderboard.len() {
+            let behind = leaderboard.get(position + 1);
+            if behind.score <= score {
+                break;
+            }
+            self.place_leaderboard_entry(season_id, &mut leaderboard, position, &behind);
+            position += 1;
+        }
+
+        leaderboard.set(
+            position,
+            &LeaderboardEntry {
+                player: player.clone(),
+                score,
+            },
+        );
+        position_mapper.set(position);
+    }
+
+    fn place_leaderboard_entry(
+        &self,
+        season_id: u32,
+        leaderboard: &mut VecMapper<LeaderboardEntry<Self::Api>>,
+        position: usize,
+        entry: &LeaderboardEntry<Self::Api>,
+    ) {
+        leaderboard.set(position, entry);
+        self.leaderboard_position(season_id, self.player_id(&entry.player))
+            .set(position);
+    }
+
+    fn current_energy(&self, player: &ManagedAddress, config: &EnergyConfig) -> u64 {
+        let energy_mapper = self.energy(player);
+        if energy_mapper.is_empty() {
+            return config.max_energy;
+        }
+
+        let state = energy_mapper.get();
+        let elapsed_blocks = self.blockchain().get_block_nonce() - state.last_update_block;
+        let regenerated = elapsed_blocks / config.blocks_per_energy_point;
+        core::cmp::min(state.energy + regenerated, config.max_energy)
+    }
+
+    fn consume_energy(&self, player: &ManagedAddress, config: &EnergyConfig, cost: u64) {
+        if cost == 0 {
+            return;
+        }
+
+        let cost = if self.is_booster_active(player, BoosterType::ExtraEnergy) {
+            cost / 2
+        } else {
+            cost
+        };
+
+        let energy = self.current_energy(player, config);
+        require!(energy >= cost, "Not enough energy");
+
+        self.energy(player).set(EnergyState {
+            energy: energy - cost,
+            last_update_block: self.blockchain().get_block_nonce(),
+        });
+    }
+
+    fn is_booster_active(&self, player: &ManagedAddress, booster_type: BoosterType) -> bool {
+        self.blockchain().get_block_nonce() <= self.active_boosters(player, booster_type).get()
+    }
+
+    fn notify(&self, player: &ManagedAddress, kind: NotificationKind, season_id: u32, reference: u32) {
+        let mut queue = self.notifications(player);
+        if queue.len() >= MAX_NOTIFICATIONS {
+            queue.pop_front();
+        }
+
+        queue.push_back(Notification {
+            kind,
+            season_id,
+            reference,
+            block: self.blockchain().get_block_nonce(),
+        });
+    }
+
+    /// Whether `player` ranks within the top `NFT_REWARD_WINNERS` of the season leaderboard.
+    /// Tied players keep the order in which they reached the score, so a tie at the cutoff
+    /// goes to whoever got there first.
+    fn is_eligible_for_reward(&self, season_id: u32, player: &ManagedAddress) -> bool {
+        let position = self.leaderboard_position(season_id, self.player_id(player)).get();
+        position > 0 && position <= NFT_REWARD_WINNERS
+    }
+}
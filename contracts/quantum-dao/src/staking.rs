@@ -0,0 +1,538 @@
+use multiversx_sc::imports::*;
+
+use crate::{staking_pool_proxy, types::*};
+
+/// Staked voting: open votes, commit-reveal votes and counter-pair votes
+#[multiversx_sc::module]
+pub trait StakingModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::admin::AdminModule
+    + crate::scoring::ScoringModule
+{
+    /// Vote on a proposal
+    #[endpoint(vote)]
+    #[payable("EGLD")]
+    fn vote(&self, proposal_id: u32, vote_for: bool) {
+        let gas_at_start = self.gas_probe_start();
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_value().clone_value();
+        self.vote_as(&caller, proposal_id, vote_for, payment);
+
+        self.gas_probe_end(b"vote", gas_at_start);
+    }
+
+    /// Vote on behalf of `player`, with the EGLD sent as their stake. Only the trusted router
+    /// can call this; the vote and its stake belong to `player`.
+    #[endpoint(voteFor)]
+    #[payable("EGLD")]
+    fn vote_for_player(&self, player: ManagedAddress, proposal_id: u32, vote_for: bool) {
+        self.require_trusted_router();
+        let payment = self.call_value().egld_value().clone_value();
+        self.vote_as(&player, proposal_id, vote_for, payment);
+    }
+
+    /// Cast votes collected by a vote aggregator on another shard, one
+    /// `(player, proposal_id, vote_for, stake)` entry per vote. The EGLD sent must equal the sum
+    /// of the stakes; each vote and its stake belong to its player. A failing vote reverts the
+    /// whole batch, returning the EGLD to the aggregator.
+    #[endpoint(submitAggregatedVotes)]
+    #[payable("EGLD")]
+    fn submit_aggregated_votes(&self, votes: MultiValueEncoded<MultiValue4<ManagedAddress, u32, bool, BigUint>>) {
+        require!(
+            self.vote_aggregators().contains(&self.blockchain().get_caller()),
+            "Only vote aggregators"
+        );
+        self.require_bounded_batch(votes.len());
+
+        let mut total_stake = BigUint::zero();
+        for entry in votes {
+            let (player, proposal_id, vote_for, stake) = entry.into_tuple();
+            total_stake += &stake;
+            self.vote_as(&player, proposal_id, vote_for, stake);
+        }
+        require!(
+            total_stake == *self.call_value().egld_value(),
+            "Payment must equal the total stake"
+        );
+    }
+
+    /// Vote in a contested pair: back the original, back the counter, or abstain.
+    /// Abstentions are tallied in the original's `votes_against`, which pairs do not otherwise use.
+    #[endpoint(voteCounterPair)]
+    #[payable("EGLD")]
+    fn vote_counter_pair(&self, original_id: u32, choice: CounterChoice) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        self.require_joined(season_id, &caller);
+        require!(payment > 0, "Must stake EGLD to vote");
+        require!(!self.counter_proposal(season_id, original_id).is_empty(), "No counter-proposal");
+
+        let counter_id = self.counter_proposal(season_id, original_id).get();
+        require!(
+            self.user_votes(season_id, counter_id, self.player_id(&caller)).is_empty(),
+            "Already voted"
+        );
+
+        match choice {
+            CounterChoice::Original => {
+                self.cast_vote(season_id, original_id, &caller, true, payment)
+            },
+            CounterChoice::Counter => {
+                require!(
+                    self.user_votes(season_id, original_id, self.player_id(&caller)).is_empty(),
+                    "Already voted"
+                );
+                self.cast_vote(season_id, counter_id, &caller, true, payment)
+            },
+            CounterChoice::Abstain => {
+                self.cast_vote(season_id, original_id, &caller, false, payment)
+            },
+        }
+    }
+
+    /// Commit a hidden vote on a superposition proposal.
+    /// `commitment` is keccak256(vote_for byte ++ salt ++ caller address); only the stake is public.
+    #[payable("EGLD")]
+    #[endpoint(commitVote)]
+    fn commit_vote(&self, proposal_id: u32, commitment: ManagedByteArray<Self::Api, 32>) {
+        let gas_at_start = self.gas_probe_start();
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let payment = self.call_value().egld_value().clone_value();
+
+        self.require_joined(season_id, &caller);
+        require!(payment > 0, "Must stake EGLD to vote");
+        require!(
+            self.superposition_proposals(season_id).contains(&proposal_id),
+            "Not a superposition proposal"
+        );
+        let game_config = self.game_config().get();
+        let voter_id = self.register_player_id(&caller);
+        let current_block =
+            self.validate_new_vote(season_id, proposal_id, &caller, voter_id, &game_config);
+
+        // The direction stays unknown until reveal
+        let vote = VoteRecord::new(false, true, payment, current_block);
+        let payment = &vote.stake_amount;
+        self.user_votes(season_id, proposal_id, voter_id).set(&vote);
+        self.proposal_voters(season_id, proposal_id).insert(caller.clone());
+        self.push_vote_history(season_id, proposal_id, &caller);
+        self.vote_commitments(season_id, proposal_id, &caller).set(&commitment);
+        self.committed_stake(season_id, proposal_id).update(|stake| *stake += payment);
+        self.record_stake(season_id, proposal_id, payment);
+        self.count_vote(season_id, voter_id);
+
+        self.reward_vote(season_id, proposal_id, &caller, payment, &game_config.scoring);
+
+        self.vote_committed_event(proposal_id, &caller, payment);
+
+        self.gas_probe_end(b"commitVote", gas_at_start);
+    }
+
+    /// Reveal a committed vote after voting ends, collapsing it into the proposal tallies.
+    /// Votes not revealed within the reveal window are not counted.
+    #[endpoint(revealVote)]
+    fn reveal_vote(&self, proposal_id: u32, vote_for: bool, salt: ManagedBuffer) {
+        let gas_at_start = self.gas_probe_start();
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_not_blacklisted();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let commitment_mapper = self.vote_commitments(season_id, proposal_id, &caller);
+        require!(!commitment_mapper.is_empty(), "No committed vote");
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block > proposal.end_block, "Voting still active");
+        require!(
+            current_block <= proposal.end_block + self.game_config().get().reveal_duration_blocks,
+            "Reveal window closed"
+        );
+        require!(
+            self.vote_commitment_hash(&caller, vote_for, &salt) == commitment_mapper.get(),
+            "Commitment mismatch"
+        );
+        commitment_mapper.clear();
+
+        let vote_mapper = self.user_votes(season_id, proposal_id, self.player_id(&caller));
+        let mut vote = vote_mapper.get();
+        vote.reveal(vote_for);
+        vote_mapper.set(&vote);
+
+        self.tally_vote(season_id, proposal_id, &caller, vote_for, &vote.stake_amount);
+
+        self.vote_cast_event(proposal_id, &caller, vote_for, &vote.stake_amount);
+
+        self.gas_probe_end(b"revealVote", gas_at_start);
+    }
+
+    /// Withdraw a vote while voting is still open: the stake is refunded, the tallies and the
+    /// points the vote earned are taken back, and the caller may vote on the proposal again
+    #[endpoint(revokeVote)]
+    fn revoke_vote(&self, proposal_id: u32) {
+        let gas_at_start = self.gas_probe_start();
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_not_blacklisted();
+        self.require_game_active();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.current_season_id().get();
+        let voter_id = self.player_id(&caller);
+        let vote_mapper = self.user_votes(season_id, proposal_id, voter_id);
+        require!(!vote_mapper.is_empty(), "No vote to revoke");
+
+        let end_block = self.proposal_info(season_id).get(proposal_id as usize).end_block;
+        require!(self.blockchain().get_block_nonce() <= end_block, "Voting ended");
+
+        let vote = vote_mapper.take();
+        let commitment_mapper = self.vote_commitments(season_id, proposal_id, &caller);
+        if !commitment_mapper.is_empty() {
+            commitment_mapper.clear();
+            self.committed_stake(season_id, proposal_id)
+                .update(|stake| *stake -= &vote.stake_amount);
+        } else {
+            // Votes tallied before weights were recorded counted their stake
+            let weight_mapper = self.vote_weight(season_id, proposal_id, &caller);
+            let weight = if weight_mapper.is_empty() {
+                vote.stake_amount.clone()
+            } else {
+                weight_mapper.take()
+            };
+            let mut tallies_mapper = self.proposal_tallies(season_id);
+            let mut tally = tallies_mapper.get(proposal_id as usize);
+            if vote.vote_for() {
+                tally.votes_for -= &weight;
+            } else {
+                tally.votes_against -= &weight;
+            }
+            tallies_mapper.set(proposal_id as usize, &tally);
+            self.proposal_tally_updated_event(season_id, proposal_id, &tally);
+        }
+
+        self.proposal_voters(season_id, proposal_id).swap_remove(&caller);
+        self.remove_from_vote_history(season_id, proposal_id, &caller);
+        self.release_stake(season_id, proposal_id, &vote.stake_amount);
+        self.uncount_vote(season_id, voter_id);
+
+        let points = self.vote_points(season_id, proposal_id, &caller).take();
+        self.deduct_dao_points(season_id, &caller, points);
+        let mirrored_mapper = self.vote_mirrored_points(season_id, proposal_id, &caller);
+        if !mirrored_mapper.is_empty() {
+            let mirrored = mirrored_mapper.take();
+            self.deduct_dao_points(season_id, &mirrored.partner, mirrored.points);
+        }
+
+        self.send().direct_egld(&caller, &vote.stake_amount);
+
+        self.vote_revoked_event(proposal_id, &caller, &vote.stake_amount);
+
+        self.gas_probe_end(b"revokeVote", gas_at_start);
+    }
+
+    /// Reclaim the stake of a vote once its proposal has been executed or rejected
+    #[endpoint(withdrawStake)]
+    fn withdraw_stake(&self, proposal_id: u32, season_id: OptionalValue<u32>) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        let season_id = self.resolve_season(season_id);
+        require!(
+            !self.proposal_execution_block(season_id, proposal_id).is_empty(),
+            "Proposal not executed yet"
+        );
+
+        let voter_id = self.player_id(&caller);
+        let vote_mapper = self.user_votes(season_id, proposal_id, voter_id);
+        require!(!vote_mapper.is_empty(), "No vote found");
+        let withdrawn_mapper = self.stake_withdrawn(season_id, proposal_id, voter_id);
+        require!(!withdrawn_mapper.get(), "Stake already withdrawn");
+        withdrawn_mapper.set(true);
+
+        // The season total keeps counting it, so quorums of still open proposals do not move
+        let stake = vote_mapper.get().stake_amount;
+        self.proposal_stake(season_id, proposal_id).update(|staked| *staked -= &stake);
+        self.total_staked().update(|staked| *staked -= &stake);
+
+        self.send().direct_egld(&caller, &stake);
+
+        self.stake_withdrawn_event(proposal_id, &caller, &stake);
+    }
+
+    /// Weight votes by the governance tokens each voter had staked in `staking_pool` before the
+    /// proposal opened rather than by the EGLD sent with the vote, which is still required as a
+    /// refundable stake. Tokens staked later, e.g. moved from an address that already voted, do not count.
+    /// The pool must live on the same shard, as it is queried synchronously.
+    #[endpoint(setStakingPool)]
+    fn set_staking_pool(&self, staking_pool: ManagedAddress) {
+        self.require_role(Role::Owner);
+        require!(self.blockchain().is_smart_contract(&staking_pool), "Staking pool must be a contract");
+        let old_staking_pool = if self.staking_pool_address().is_empty() {
+            ManagedAddress::zero()
+        } else {
+            self.staking_pool_address().get()
+        };
+        self.staking_pool_address().set(&staking_pool);
+
+        self.audit(AdminAction::SetStakingPool, &(), &old_staking_pool, &staking_pool);
+    }
+
+    // Private functions
+    fn vote_as(&self, player: &ManagedAddress, proposal_id: u32, vote_for: bool, payment: BigUint) {
+        self.require_not_paused();
+        self.require_flow_enabled(GameFlow::Voting);
+        self.require_player_not_blacklisted(player);
+        self.require_game_active();
+
+        let season_id = self.current_season_id().get();
+
+        self.require_joined(season_id, player);
+        require!(payment > 0, "Must stake EGLD to vote");
+        require!(
+            self.counter_pair_original(season_id, proposal_id).is_empty(),
+            "Contested proposal, use voteCounterPair"
+        );
+
+        self.cast_vote(season_id, proposal_id, player, vote_for, payment);
+    }
+
+    fn cast_vote(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        caller: &ManagedAddress,
+        vote_for: bool,
+        payment: BigUint,
+    ) {
+        require!(
+            !self.superposition_proposals(season_id).contains(&proposal_id),
+            "Superposition proposal, use commitVote"
+        );
+        // Read once and shared by the checks, the tally and the reward below
+        let game_config = self.game_config().get();
+        let voter_id = self.register_player_id(caller);
+        let current_block =
+            self.validate_new_vote(season_id, proposal_id, caller, voter_id, &game_config);
+
+        // Record the vote; the stake is moved into it rather than cloned
+        let vote = VoteRecord::new(vote_for, false, payment, current_block);
+        let payment = &vote.stake_amount;
+
+        self.user_votes(season_id, proposal_id, voter_id).set(&vote);
+        self.proposal_voters(season_id, proposal_id).insert(caller.clone());
+        self.push_vote_history(season_id, proposal_id, caller);
+        self.record_stake(season_id, proposal_id, payment);
+        self.count_vote(season_id, voter_id);
+
+        self.tally_vote(season_id, proposal_id, caller, vote_for, payment);
+        self.reward_vote(season_id, proposal_id, caller, payment, &game_config.scoring);
+
+        self.vote_cast_event(proposal_id, caller, vote_for, payment);
+    }
+
+    fn record_stake(&self, season_id: u32, proposal_id: u32, payment: &BigUint) {
+        self.season_staked(season_id).update(|staked| *staked += payment);
+        self.proposal_stake(season_id, proposal_id).update(|staked| *staked += payment);
+        self.total_staked().update(|staked| *staked += payment);
+    }
+
+    fn count_vote(&self, season_id: u32, voter_id: AddressId) {
+        self.season_vote_count(season_id).update(|count| *count += 1);
+        let player_votes = self.player_vote_count(season_id, voter_id).update(|count| {
+            *count += 1;
+            *count
+        });
+        if player_votes == 1 {
+            self.season_voter_count(season_id).update(|count| *count += 1);
+        }
+    }
+
+    /// Saturating, as votes cast before the counters existed were never counted
+    fn uncount_vote(&self, season_id: u32, voter_id: AddressId) {
+        self.season_vote_count(season_id).update(|count| *count = count.saturating_sub(1));
+        let player_votes_mapper = self.player_vote_count(season_id, voter_id);
+        let player_votes = player_votes_mapper.get();
+        if player_votes == 0 {
+            return;
+        }
+
+        player_votes_mapper.set(player_votes - 1);
+        if player_votes == 1 {
+            self.season_voter_count(season_id).update(|count| *count = count.saturating_sub(1));
+        }
+    }
+
+    fn release_stake(&self, season_id: u32, proposal_id: u32, amount: &BigUint) {
+        self.season_staked(season_id).update(|staked| *staked -= amount);
+        self.proposal_stake(season_id, proposal_id).update(|staked| *staked -= amount);
+        self.total_staked().update(|staked| *staked -= amount);
+    }
+
+    fn push_vote_history(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) {
+        let index = self
+            .user_vote_history(voter)
+            .push(&ProposalRef { season_id, proposal_id });
+        self.vote_history_index(voter, season_id, proposal_id).set(index);
+    }
+
+    /// Clears the proposal's entry from the voter's history, leaving an empty slot so the
+    /// remaining entries keep their order and position. Entries recorded before their index
+    /// was kept are searched for, from the end since the vote is usually among the most recent.
+    fn remove_from_vote_history(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) {
+        let mut history_mapper = self.user_vote_history(voter);
+        let mut index = self.vote_history_index(voter, season_id, proposal_id).take();
+        if index == 0 {
+            index = history_mapper.len();
+            while index > 0 {
+                if !history_mapper.item_is_empty(index) {
+                    let proposal_ref = history_mapper.get(index);
+                    if proposal_ref.season_id == season_id && proposal_ref.proposal_id == proposal_id {
+                        break;
+                    }
+                }
+                index -= 1;
+            }
+            if index == 0 {
+                return;
+            }
+        }
+
+        history_mapper.clear_entry(index);
+    }
+
+    /// Shared eligibility checks for a first vote on a proposal; also charges the vote energy cost.
+    /// Returns the current block.
+    fn validate_new_vote(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        caller: &ManagedAddress,
+        voter_id: AddressId,
+        game_config: &GameConfig<Self::Api>,
+    ) -> u64 {
+        self.require_not_spectator(caller);
+        require!(self.proposal_exists(season_id, proposal_id), "Proposal does not exist");
+        self.require_standard_proposal(season_id, proposal_id);
+
+        // Only the voting window is needed, so the tallies are not read
+        let info = self.proposal_info(season_id).get(proposal_id as usize);
+        let current_block = self.blockchain().get_block_nonce();
+
+        require!(current_block >= info.start_block, "Voting not started");
+        require!(current_block <= info.end_block, "Voting ended");
+        require!(self.user_votes(season_id, proposal_id, voter_id).is_empty(), "Already voted");
+        require!(
+            self.prediction_stakes(season_id, proposal_id, caller).is_empty(),
+            "Predictors cannot vote"
+        );
+
+        let guild_spend_mapper = self.guild_spends(season_id, proposal_id);
+        if !guild_spend_mapper.is_empty() {
+            let guild_id = guild_spend_mapper.get().guild_id;
+            require!(self.guild_members(guild_id).contains(caller), "Only guild members can vote");
+        }
+
+        self.consume_energy(caller, &game_config.energy, game_config.energy.vote_cost);
+
+        current_block
+    }
+
+    /// Update proposal vote counts by the stake, or by the voter's power in the staking pool
+    /// when one is set, applying an Entangler power-up if one was used
+    fn tally_vote(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        caller: &ManagedAddress,
+        vote_for: bool,
+        payment: &BigUint,
+    ) {
+        // The weight is only stored when it differs from the stake; revocations fall back to it
+        let staking_pool_mapper = self.staking_pool_address();
+        let mut weight = if staking_pool_mapper.is_empty() {
+            None
+        } else {
+            let start_block = self.proposal_info(season_id).get(proposal_id as usize).start_block;
+            Some(
+                self.staking_pool_proxy(staking_pool_mapper.get())
+                    .get_voting_power_at(caller.clone(), start_block.saturating_sub(1))
+                    .execute_on_dest_context::<BigUint>(),
+            )
+        };
+        let entangled_mapper = self.entangled_votes(season_id, proposal_id, caller);
+        if entangled_mapper.get() {
+            entangled_mapper.clear();
+            weight = Some(weight.as_ref().unwrap_or(payment).clone() * 3u32 / 2u32);
+        }
+        if let Some(weight) = &weight {
+            self.vote_weight(season_id, proposal_id, caller).set(weight);
+        }
+        let vote_weight = weight.as_ref().unwrap_or(payment);
+
+        // Only the tallies change, so the proposal metadata is neither read nor rewritten
+        let mut tallies_mapper = self.proposal_tallies(season_id);
+        let mut tally = tallies_mapper.get(proposal_id as usize);
+        if vote_for {
+            tally.votes_for += vote_weight;
+        } else {
+            tally.votes_against += vote_weight;
+        }
+        tallies_mapper.set(proposal_id as usize, &tally);
+        self.proposal_tally_updated_event(season_id, proposal_id, &tally);
+    }
+
+    /// Reward voter with DAO points based on stake
+    fn reward_vote(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        caller: &ManagedAddress,
+        payment: &BigUint,
+        config: &ScoringConfig,
+    ) {
+        let dao_points = (payment / &BigUint::from(1_000_000_000_000_000_000u64)).to_u64().unwrap_or(1);
+        let (credited, mirrored) =
+            self.add_dao_points_mirrored(season_id, caller, dao_points * config.vote_points_multiplier);
+        self.vote_points(season_id, proposal_id, caller).set(credited);
+        if let Some(mirrored) = mirrored {
+            self.vote_mirrored_points(season_id, proposal_id, caller).set(mirrored);
+        }
+
+        let quest_counted_mapper = self.vote_quest_counted(season_id, proposal_id, caller);
+        if !quest_counted_mapper.get() {
+            quest_counted_mapper.set(true);
+            self.record_quest_progress(season_id, caller, QuestKind::CastVotes);
+        }
+    }
+
+    fn vote_commitment_hash(
+        &self,
+        caller: &ManagedAddress,
+        vote_for: bool,
+        salt: &ManagedBuffer,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let mut data = ManagedBuffer::new();
+        data.append_bytes(&[vote_for as u8]);
+        data.append(salt);
+        data.append(caller.as_managed_buffer());
+        self.crypto().keccak256(&data)
+    }
+
+    #[proxy]
+    fn staking_pool_proxy(&self, sc_address: ManagedAddress) -> staking_pool_proxy::Proxy<Self::Api>;
+}
@@ -0,0 +1,8 @@
+multiversx_sc::imports!();
+
+/// Views of the suite's staking contract read when weighting votes
+#[multiversx_sc::proxy]
+pub trait StakingPool {
+    #[view(getVotingPowerAt)]
+    fn get_voting_power_at(&self, address: ManagedAddress, block: u64) -> BigUint;
+}
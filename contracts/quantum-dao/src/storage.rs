@@ -0,0 +1,645 @@
+use multiversx_sc::imports::*;
+
+use crate::types::*;
+
+/// Storage mappers shared by every module of the game
+///
+/// Per-game state (proposals, votes, scores, pools) takes the season id as its first key
+/// argument, so each season starts on empty keys and past seasons stay readable unchanged.
+#[multiversx_sc::module]
+pub trait StorageModule {
+    #[storage_mapper("game_duration_blocks")]
+    fn game_duration_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("game_start_block")]
+    fn game_start_block(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("nft_reward_token_id")]
+    fn nft_reward_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("game_config")]
+    fn game_config(&self) -> SingleValueMapper<GameConfig<Self::Api>>;
+
+    /// Same key as `game_config`, read undecoded to inspect the layout version
+    #[storage_mapper("game_config")]
+    fn game_config_raw(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Same key as `game_config`, decoded with the version 1 layout
+    #[storage_mapper("game_config")]
+    fn game_config_v1(&self) -> SingleValueMapper<GameConfigV1<Self::Api>>;
+
+    #[storage_mapper("current_season_id")]
+    fn current_season_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("seasons")]
+    fn seasons(&self, season_id: u32) -> SingleValueMapper<Season>;
+
+    #[storage_mapper("current_proposal_id")]
+    fn current_proposal_id(&self, season_id: u32) -> SingleValueMapper<u32>;
+
+    /// Immutable proposal metadata of a season, indexed by proposal id
+    #[storage_mapper("proposal_info")]
+    fn proposal_info(&self, season_id: u32) -> VecMapper<ProposalInfo<Self::Api>>;
+
+    /// Vote tallies and status of a season's proposals, indexed by proposal id
+    #[storage_mapper("proposal_tallies")]
+    fn proposal_tallies(&self, season_id: u32) -> VecMapper<ProposalTally<Self::Api>>;
+
+    #[storage_mapper("proposal_execution_block")]
+    fn proposal_execution_block(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposal_status_count")]
+    fn proposal_status_count(&self, season_id: u32, status: ProposalStatus) -> SingleValueMapper<u32>;
+
+    /// Ids of proposals not yet executed, funded or closed
+    #[storage_mapper("active_proposals")]
+    fn active_proposals(&self, season_id: u32) -> UnorderedSetMapper<u32>;
+
+    /// Compact ids keying per-player storage instead of full addresses
+    #[storage_mapper("player_ids")]
+    fn player_ids(&self) -> AddressToIdMapper<Self::Api>;
+
+    #[storage_mapper("player_dao_scores")]
+    fn dao_scores(&self, season_id: u32, player_id: AddressId) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("vote_records")]
+    fn user_votes(&self, season_id: u32, proposal_id: u32, voter_id: AddressId) -> SingleValueMapper<VoteRecord<Self::Api>>;
+
+    #[storage_mapper("player_nft_claimed")]
+    fn nft_claimed(&self, season_id: u32, player_id: AddressId) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("players")]
+    fn players(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("total_score")]
+    fn total_score(&self, season_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("entry_fee")]
+    fn entry_fee(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("joined_players")]
+    fn joined_players(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("prize_pool")]
+    fn prize_pool(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("prize_claimed")]
+    fn prize_claimed(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Prize shares paid out so far, which `sweepPrizePool` leaves out
+    #[storage_mapper("prize_paid")]
+    fn prize_paid(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("prize_pool_swept")]
+    fn prize_pool_swept(&self, season_id: u32) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("quests")]
+    fn quests(&self, season_id: u32) -> VecMapper<Quest<Self::Api>>;
+
+    #[storage_mapper("quest_progress")]
+    fn quest_progress(
+        &self,
+        season_id: u32,
+        quest_id: usize,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("quest_claimed")]
+    fn quest_claimed(&self, season_id: u32, quest_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("last_check_in_block")]
+    fn last_check_in_block(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("check_in_streak")]
+    fn check_in_streak(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("power_up_token_id")]
+    fn power_up_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("entangled_votes")]
+    fn entangled_votes(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("decohered_proposals")]
+    fn decohered_proposals(&self, season_id: u32) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("last_quantum_event_block")]
+    fn last_quantum_event_block(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quantum_events")]
+    fn quantum_events(&self, season_id: u32) -> VecMapper<QuantumEvent<Self::Api>>;
+
+    #[storage_mapper("double_points_end_block")]
+    fn double_points_end_block(&self, season_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("tournament_group_size")]
+    fn tournament_group_size(&self, season_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("tournament_phase")]
+    fn tournament_phase(&self, season_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("tournament_participants")]
+    fn tournament_participants(&self, season_id: u32, phase: u32) -> VecMapper<ManagedAddress>;
+
+    /// Group number of a participant within a phase; for phase 0 this is the registration position
+    #[storage_mapper("tournament_group")]
+    fn tournament_group(
+        &self,
+        season_id: u32,
+        phase: u32,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("tournament_phase_scores")]
+    fn tournament_phase_scores(
+        &self,
+        season_id: u32,
+        phase: u32,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("tournament_winner")]
+    fn tournament_winner(&self, season_id: u32) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("last_guild_id")]
+    fn last_guild_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guilds")]
+    fn guilds(&self, guild_id: u32) -> SingleValueMapper<Guild<Self::Api>>;
+
+    #[storage_mapper("guild_by_name")]
+    fn guild_by_name(&self, name: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guild_members")]
+    fn guild_members(&self, guild_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("player_guild")]
+    fn player_guild(&self, player: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("guild_treasury")]
+    fn guild_treasury(&self, guild_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("guild_spends")]
+    fn guild_spends(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<GuildSpend<Self::Api>>;
+
+    #[storage_mapper("profiles")]
+    fn profiles(&self, player: &ManagedAddress) -> SingleValueMapper<Profile<Self::Api>>;
+
+    #[storage_mapper("nickname_owner")]
+    fn nickname_owner(&self, nickname: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("paused_at_block")]
+    fn paused_at_block(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("paused_flows")]
+    fn paused_flows(&self) -> UnorderedSetMapper<GameFlow>;
+
+    #[storage_mapper("whitelist_mode")]
+    fn whitelist_mode(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("whitelist")]
+    fn whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_requests")]
+    fn access_requests(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("blacklist")]
+    fn blacklist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("admin_multisig")]
+    fn admin_multisig(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Holder of `Role::Owner`; separate from the protocol-level owner, which only controls upgrades
+    #[storage_mapper("game_owner")]
+    fn game_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pending_owner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Proposed new holder of `role`, keyed by the current holder handing it over
+    #[storage_mapper("pending_role_transfer")]
+    fn pending_role_transfer(&self, role: Role, from: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("season_extension_blocks")]
+    fn season_extension_blocks(&self, season_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("duration_extensions")]
+    fn duration_extensions(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("energy")]
+    fn energy(&self, player: &ManagedAddress) -> SingleValueMapper<EnergyState>;
+
+    /// Set on both members of a contested pair, pointing at the original proposal
+    #[storage_mapper("counter_pair_original")]
+    fn counter_pair_original(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("counter_proposal")]
+    fn counter_proposal(&self, season_id: u32, original_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("counter_pair_resolved")]
+    fn counter_pair_resolved(&self, season_id: u32, original_id: u32) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("prediction_pools")]
+    fn prediction_pools(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<PredictionPool<Self::Api>>;
+
+    #[storage_mapper("prediction_stakes")]
+    fn prediction_stakes(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<PredictionStake<Self::Api>>;
+
+    /// EGLD fees collected by the game
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<BigUint>;
+
+    /// Suite treasury vault that `forwardFees` sends the collected fees to
+    #[storage_mapper("fee_vault_address")]
+    fn fee_vault_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("superposition_proposals")]
+    fn superposition_proposals(&self, season_id: u32) -> UnorderedSetMapper<u32>;
+
+    /// Proposals whose stored description is the 32-byte hash of an off-chain body
+    #[storage_mapper("content_hash_proposals")]
+    fn content_hash_proposals(&self, season_id: u32) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("vote_commitments")]
+    fn vote_commitments(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[storage_mapper("committed_stake")]
+    fn committed_stake(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("stake_withdrawn")]
+    fn stake_withdrawn(&self, season_id: u32, proposal_id: u32, voter_id: AddressId) -> SingleValueMapper<bool>;
+
+    /// Voters whose storage `pruneProposal` already cleared, still counted by the voter views
+    #[storage_mapper("pruned_voter_count")]
+    fn pruned_voter_count(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<usize>;
+
+    /// Weight a vote added to the proposal tallies, which differs from its stake when entangled
+    /// or taken from the staking pool
+    #[storage_mapper("vote_weight")]
+    fn vote_weight(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// DAO points a vote earned its voter, taken back if the vote is revoked
+    #[storage_mapper("vote_points")]
+    fn vote_points(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Points a vote mirrored to the voter's entangled partner, taken back if the vote is revoked
+    #[storage_mapper("vote_mirrored_points")]
+    fn vote_mirrored_points(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<MirroredPoints<Self::Api>>;
+
+    /// Set once a vote on the proposal advanced the voter's vote quests, so voting again after
+    /// a revoke does not advance them twice
+    #[storage_mapper("vote_quest_counted")]
+    fn vote_quest_counted(&self, season_id: u32, proposal_id: u32, voter: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("pending_entanglement")]
+    fn pending_entanglement(&self, requester: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("entanglement_partner")]
+    fn entanglement_partner(&self, player: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    /// Pairs are keyed by the player who requested the entanglement
+    #[storage_mapper("entangled_pairs")]
+    fn entangled_pairs(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("entangled_since_block")]
+    fn entangled_since_block(&self, pair_key: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("booster_offers")]
+    fn booster_offers(&self, booster_type: BoosterType) -> SingleValueMapper<BoosterOffer<Self::Api>>;
+
+    /// Expiry block of a player's booster
+    #[storage_mapper("active_boosters")]
+    fn active_boosters(&self, player: &ManagedAddress, booster_type: BoosterType) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sudden_death_proposers")]
+    fn sudden_death_proposers(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("league_hub_address")]
+    fn league_hub_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("registry_address")]
+    fn registry_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Staking contract whose voting power weights votes instead of their stake, when set
+    #[storage_mapper("staking_pool_address")]
+    fn staking_pool_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Router allowed to vote and claim on behalf of the players calling it
+    #[storage_mapper("trusted_router")]
+    fn trusted_router(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Aggregators on other shards allowed to forward their players' votes
+    #[storage_mapper("vote_aggregators")]
+    fn vote_aggregators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("league_reported")]
+    fn league_reported(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Raffle that players' season votes are reported to as tickets
+    #[storage_mapper("raffle_address")]
+    fn raffle_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("raffle_reported")]
+    fn raffle_reported(&self, season_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Suite NFT minter that reward NFTs are minted through
+    #[storage_mapper("nft_minter_address")]
+    fn nft_minter_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Vesting contract whose claimable grants `getClaimableRewards` lists, when set
+    #[storage_mapper("vesting_address")]
+    fn vesting_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Highest scorer of a season, maintained as points are credited
+    #[storage_mapper("season_leader")]
+    fn season_leader(&self, season_id: u32) -> SingleValueMapper<ManagedAddress>;
+
+    /// Highest-scoring players of a season with their scores, best first, at most
+    /// `TOP_PLAYERS_SIZE`; the last entry's score is the cutoff for entering the ranking
+    #[storage_mapper("leaderboard")]
+    fn leaderboard(&self, season_id: u32) -> VecMapper<LeaderboardEntry<Self::Api>>;
+
+    /// 1-based index of a player in `leaderboard`, 0 when not ranked
+    #[storage_mapper("leaderboard_position")]
+    fn leaderboard_position(&self, season_id: u32, player_id: AddressId) -> SingleValueMapper<usize>;
+
+    /// Number of players whose score has `bucket` significant bits, used to estimate ranks
+    /// outside `leaderboard`
+    #[storage_mapper("score_bucket_players")]
+    fn score_bucket_players(&self, season_id: u32, bucket: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("betting_cutoff_block")]
+    fn betting_cutoff_block(&self, season_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("season_bets")]
+    fn season_bets(&self, season_id: u32, bettor: &ManagedAddress) -> SingleValueMapper<SeasonBet<Self::Api>>;
+
+    #[storage_mapper("bets_on_candidate")]
+    fn bets_on_candidate(&self, season_id: u32, candidate: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("bets_total")]
+    fn bets_total(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("bet_winner")]
+    fn bet_winner(&self, season_id: u32) -> SingleValueMapper<ManagedAddress>;
+
+    /// Proposal, prediction, boost and betting fees taken during a season
+    #[storage_mapper("season_fees_collected")]
+    fn season_fees_collected(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("betting_fee")]
+    fn betting_fee(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("theme_ids")]
+    fn theme_ids(&self) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("season_themes")]
+    fn season_themes(&self, theme_id: u32) -> SingleValueMapper<SeasonTheme<Self::Api>>;
+
+    #[storage_mapper("season_theme")]
+    fn season_theme(&self, season_id: u32) -> SingleValueMapper<u32>;
+
+    /// Total EGLD staked in votes during a season
+    #[storage_mapper("season_staked")]
+    fn season_staked(&self, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    /// EGLD currently staked in votes across all seasons
+    #[storage_mapper("total_staked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    /// EGLD staked in votes on a proposal, committed votes included
+    #[storage_mapper("proposal_stake")]
+    fn proposal_stake(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<BigUint>;
+
+    /// Votes currently standing in a season, committed votes included
+    #[storage_mapper("season_vote_count")]
+    fn season_vote_count(&self, season_id: u32) -> SingleValueMapper<u64>;
+
+    /// Standing votes per player, moving `season_voter_count` when it leaves or reaches zero
+    #[storage_mapper("player_vote_count")]
+    fn player_vote_count(&self, season_id: u32, player_id: AddressId) -> SingleValueMapper<u32>;
+
+    /// Players with at least one standing vote in a season
+    #[storage_mapper("season_voter_count")]
+    fn season_voter_count(&self, season_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("difficulty_config")]
+    fn difficulty_config(&self) -> SingleValueMapper<DifficultyConfig<Self::Api>>;
+
+    #[storage_mapper("current_funding_round_id")]
+    fn current_funding_round_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("funding_rounds")]
+    fn funding_rounds(&self, round_id: u32) -> SingleValueMapper<FundingRound<Self::Api>>;
+
+    #[storage_mapper("round_projects")]
+    fn round_projects(&self, round_id: u32) -> VecMapper<u32>;
+
+    #[storage_mapper("proposal_category")]
+    fn proposal_category(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<ProposalCategory>;
+
+    #[storage_mapper("project_round")]
+    fn project_round(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("project_donations")]
+    fn project_donations(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        donor: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("project_total")]
+    fn project_total(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("project_sqrt_sum")]
+    fn project_sqrt_sum(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<BigUint>;
+
+    /// Every vote an address has cast, across seasons, oldest first; revoked votes leave an empty entry
+    #[storage_mapper("user_vote_history")]
+    fn user_vote_history(&self, voter: &ManagedAddress) -> VecMapper<ProposalRef>;
+
+    /// Position of a vote in `user_vote_history`; empty for votes recorded before it was kept
+    #[storage_mapper("vote_history_index")]
+    fn vote_history_index(&self, voter: &ManagedAddress, season_id: u32, proposal_id: u32) -> SingleValueMapper<usize>;
+
+    /// Every proposal an address has created, across seasons, oldest first
+    #[storage_mapper("creator_proposals")]
+    fn creator_proposals(&self, creator: &ManagedAddress) -> VecMapper<ProposalRef>;
+
+    #[storage_mapper("proposal_voters")]
+    fn proposal_voters(&self, season_id: u32, proposal_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("notifications")]
+    fn notifications(&self, player: &ManagedAddress) -> QueueMapper<Notification>;
+
+    #[storage_mapper("featured_proposals")]
+    fn featured_proposals(&self, season_id: u32) -> VecMapper<FeaturedProposal<Self::Api>>;
+
+    #[storage_mapper("contract_version")]
+    fn contract_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("migration_cursor")]
+    fn migration_cursor(&self) -> SingleValueMapper<MigrationCursor>;
+
+    /// Address-keyed player storage used before contract version 4
+    #[storage_mapper("dao_scores")]
+    fn legacy_dao_scores(&self, season_id: u32, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("user_votes")]
+    fn legacy_user_votes(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<Vote<Self::Api>>;
+
+    #[storage_mapper("nft_claimed")]
+    fn legacy_nft_claimed(&self, season_id: u32, player: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Full votes keyed by player id, used by contract versions 5 and 6
+    #[storage_mapper("player_votes")]
+    fn keyed_user_votes(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+        voter_id: AddressId,
+    ) -> SingleValueMapper<Vote<Self::Api>>;
+
+    /// Address-only ranking used before contract version 6
+    #[storage_mapper("top_players")]
+    fn legacy_top_players(&self, season_id: u32) -> VecMapper<ManagedAddress>;
+
+    /// Whole proposals per season, used by contract versions 3 and 4
+    #[storage_mapper("season_proposals")]
+    fn season_proposals(&self, season_id: u32) -> VecMapper<Proposal<Self::Api>>;
+
+    /// Per-id proposal storage used before contract version 3
+    #[storage_mapper("proposals")]
+    fn keyed_proposals(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<Proposal<Self::Api>>;
+
+    /// Same key as `keyed_proposals`, decoded with the layout used before contract version 2
+    #[storage_mapper("proposals")]
+    fn legacy_proposals(&self, season_id: u32, proposal_id: u32) -> SingleValueMapper<LegacyProposal<Self::Api>>;
+
+    // Unprefixed storage of the original single-season deployment, before seasons existed;
+    // `migrateStorageBatch` moves it into `BASELINE_SEASON_ID`
+    #[storage_mapper("current_proposal_id")]
+    fn baseline_current_proposal_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("proposals")]
+    fn baseline_proposals(&self, proposal_id: u32) -> SingleValueMapper<LegacyProposal<Self::Api>>;
+
+    #[storage_mapper("dao_scores")]
+    fn baseline_dao_scores(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("user_votes")]
+    fn baseline_user_votes(&self, proposal_id: u32, voter: &ManagedAddress) -> SingleValueMapper<Vote<Self::Api>>;
+
+    #[storage_mapper("nft_claimed")]
+    fn baseline_nft_claimed(&self, player: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stake of a migrated baseline proposal not yet matched by migrated votes
+    #[storage_mapper("baseline_unmigrated_stake")]
+    fn baseline_unmigrated_stake(&self, proposal_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("baseline_proposals_awaiting_votes")]
+    fn baseline_proposals_awaiting_votes(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("parameter_changes")]
+    fn parameter_changes(
+        &self,
+        season_id: u32,
+        proposal_id: u32,
+    ) -> SingleValueMapper<ParameterChange<Self::Api>>;
+
+    // Settings stored before `GameConfig`, folded into it once by `upgrade`
+    #[storage_mapper("scoring_config")]
+    fn legacy_scoring_config(&self) -> SingleValueMapper<ScoringConfig>;
+
+    #[storage_mapper("check_in_config")]
+    fn legacy_check_in_config(&self) -> SingleValueMapper<CheckInConfig>;
+
+    #[storage_mapper("quantum_event_config")]
+    fn legacy_quantum_event_config(&self) -> SingleValueMapper<QuantumEventConfig>;
+
+    #[storage_mapper("max_game_extension_blocks")]
+    fn legacy_max_game_extension_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("energy_config")]
+    fn legacy_energy_config(&self) -> SingleValueMapper<EnergyConfig>;
+
+    #[storage_mapper("fee_config")]
+    fn legacy_fee_config(&self) -> SingleValueMapper<FeeConfig<Self::Api>>;
+
+    #[storage_mapper("superposition_mode")]
+    fn legacy_superposition_mode(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("reveal_duration_blocks")]
+    fn legacy_reveal_duration_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("entanglement_config")]
+    fn legacy_entanglement_config(&self) -> SingleValueMapper<EntanglementConfig>;
+
+    #[storage_mapper("sudden_death_config")]
+    fn legacy_sudden_death_config(&self) -> SingleValueMapper<SuddenDeathConfig>;
+
+    #[storage_mapper("boost_duration_blocks")]
+    fn legacy_boost_duration_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("emergency_withdraw")]
+    fn emergency_withdraw(&self) -> SingleValueMapper<EmergencyWithdraw<Self::Api>>;
+
+    #[storage_mapper("maintenance_windows")]
+    fn maintenance_windows(&self) -> VecMapper<MaintenanceWindow>;
+
+    #[storage_mapper("council_members")]
+    fn council_members(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("council_term_end")]
+    fn council_term_end(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("council_election")]
+    fn council_election(&self) -> SingleValueMapper<CouncilElection>;
+
+    #[storage_mapper("council_candidates")]
+    fn council_candidates(&self, season_id: u32, proposal_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("council_votes")]
+    fn council_votes(&self, season_id: u32, proposal_id: u32, candidate: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("council_voters")]
+    fn council_voters(&self, season_id: u32, proposal_id: u32) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Filled only by builds with the `gas-instrumentation` feature
+    #[storage_mapper("gas_counters")]
+    fn gas_counters(&self, endpoint: &ManagedBuffer) -> SingleValueMapper<GasCounters>;
+
+    #[storage_mapper("instrumented_endpoints")]
+    fn instrumented_endpoints(&self) -> UnorderedSetMapper<ManagedBuffer>;
+}
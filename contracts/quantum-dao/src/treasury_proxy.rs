@@ -0,0 +1,9 @@
+multiversx_sc::imports!();
+
+/// Endpoint of the suite's treasury vault that game instances send their fees to
+#[multiversx_sc::proxy]
+pub trait Treasury {
+    #[payable("*")]
+    #[endpoint(depositFees)]
+    fn deposit_fees(&self);
+}
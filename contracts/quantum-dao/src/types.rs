@@ -0,0 +1,954 @@
+use multiversx_sc::imports::*;
+
+use crate::constants::*;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub id: u32,
+    pub creator: ManagedAddress<M>,
+    pub title: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub votes_for: BigUint<M>,
+    pub votes_against: BigUint<M>,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub status: ProposalStatus,
+}
+
+/// The part of a `Proposal` fixed at creation, stored apart from the tallies
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalInfo<M: ManagedTypeApi> {
+    pub id: u32,
+    pub creator: ManagedAddress<M>,
+    pub title: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub start_block: u64,
+    pub end_block: u64,
+}
+
+/// The part of a `Proposal` updated by votes and execution
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalTally<M: ManagedTypeApi> {
+    pub votes_for: BigUint<M>,
+    pub votes_against: BigUint<M>,
+    pub status: ProposalStatus,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    /// Funding round project paid out at settlement
+    Funded,
+}
+
+/// A vote as returned by views; storage keeps the compact `VoteRecord`
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Vote<M: ManagedTypeApi> {
+    pub voter: ManagedAddress<M>,
+    pub proposal_id: u32,
+    pub vote_for: bool,
+    pub stake_amount: BigUint<M>,
+    pub block_number: u64,
+}
+
+/// Share of a vote's points mirrored to the voter's entangled partner
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct MirroredPoints<M: ManagedTypeApi> {
+    pub partner: ManagedAddress<M>,
+    pub points: u64,
+}
+
+/// Stored form of a vote. Voter and proposal are part of the storage key, and the direction
+/// and commit-reveal state share a single flags byte.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct VoteRecord<M: ManagedTypeApi> {
+    pub flags: u8,
+    pub block_number: u64,
+    pub stake_amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> VoteRecord<M> {
+    pub fn new(vote_for: bool, hidden: bool, stake_amount: BigUint<M>, block_number: u64) -> Self {
+        let mut flags = 0;
+        if vote_for {
+            flags |= VOTE_FLAG_FOR;
+        }
+        if hidden {
+            flags |= VOTE_FLAG_HIDDEN;
+        }
+        VoteRecord {
+            flags,
+            block_number,
+            stake_amount,
+        }
+    }
+
+    pub fn vote_for(&self) -> bool {
+        self.flags & VOTE_FLAG_FOR != 0
+    }
+
+    /// Committed votes read as `Hidden` until they are revealed
+    pub fn choice(&self) -> VoteChoice {
+        if self.flags & VOTE_FLAG_HIDDEN != 0 {
+            VoteChoice::Hidden
+        } else if self.vote_for() {
+            VoteChoice::For
+        } else {
+            VoteChoice::Against
+        }
+    }
+
+    /// Sets the revealed direction, clearing the hidden flag
+    pub fn reveal(&mut self, vote_for: bool) {
+        self.flags = if vote_for { VOTE_FLAG_FOR } else { 0 };
+    }
+
+    pub fn into_vote(self, voter: ManagedAddress<M>, proposal_id: u32) -> Vote<M> {
+        Vote {
+            voter,
+            proposal_id,
+            vote_for: self.vote_for(),
+            stake_amount: self.stake_amount,
+            block_number: self.block_number,
+        }
+    }
+}
+
+/// Points awarded for each governance action during a season
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ScoringConfig {
+    pub proposal_creation_points: u64,
+    pub vote_points_multiplier: u64,
+    pub proposal_passed_bonus: u64,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        ScoringConfig {
+            proposal_creation_points: 10,
+            vote_points_multiplier: 2,
+            proposal_passed_bonus: 50,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Season {
+    pub id: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub scoring_config: ScoringConfig,
+    pub total_proposals: u32,
+    pub archived: bool,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QuantumEventKind {
+    DoublePoints,
+    FlashProposal,
+    BonusDrop,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct QuantumEvent<M: ManagedTypeApi> {
+    pub kind: QuantumEventKind,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub proposal_id: u32,
+    pub beneficiary: ManagedAddress<M>,
+    pub bonus_points: u64,
+}
+
+/// Scheduling and magnitude of the random quantum events
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct QuantumEventConfig {
+    pub interval_blocks: u64,
+    pub double_points_duration_blocks: u64,
+    pub flash_proposal_duration_blocks: u64,
+    pub bonus_drop_points: u64,
+}
+
+impl Default for QuantumEventConfig {
+    fn default() -> Self {
+        QuantumEventConfig {
+            interval_blocks: 600,
+            double_points_duration_blocks: 100,
+            flash_proposal_duration_blocks: 50,
+            bonus_drop_points: 25,
+        }
+    }
+}
+
+/// Effect encoded in the attributes of a power-up NFT
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PowerUpKind {
+    /// The holder's next vote on the target proposal counts 1.5x
+    Entangler,
+    /// Halves the passed-proposal bonus of the target proposal's creator
+    Decoherence,
+}
+
+/// Action tracked by a quest
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QuestKind {
+    CastVotes,
+    CreateProposals,
+    PassProposals,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Quest<M: ManagedTypeApi> {
+    pub kind: QuestKind,
+    pub target: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub reward_points: u64,
+    pub reward_token: EgldOrEsdtTokenIdentifier<M>,
+    pub reward_amount: BigUint<M>,
+    pub max_claims: u32,
+    pub claims: u32,
+}
+
+/// Daily check-in epoch length and streak escalation
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct CheckInConfig {
+    pub epoch_blocks: u64,
+    pub base_points: u64,
+    pub streak_bonus_points: u64,
+    pub max_streak: u64,
+}
+
+impl Default for CheckInConfig {
+    fn default() -> Self {
+        CheckInConfig {
+            // ~24h at 6s blocks
+            epoch_blocks: 14_400,
+            base_points: 1,
+            streak_bonus_points: 1,
+            max_streak: 7,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GrandNftAttributes {
+    pub season_id: u32,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct TopPlayerNftAttributes {
+    pub season_id: u32,
+    pub position: u32,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Guild<M: ManagedTypeApi> {
+    pub id: u32,
+    pub name: ManagedBuffer<M>,
+    pub founder: ManagedAddress<M>,
+    pub created_block: u64,
+}
+
+/// Treasury payout attached to a guild proposal
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GuildSpend<M: ManagedTypeApi> {
+    pub guild_id: u32,
+    pub recipient: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct AvatarRef<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub nonce: u64,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Profile<M: ManagedTypeApi> {
+    pub nickname: ManagedBuffer<M>,
+    pub avatar: Option<AvatarRef<M>>,
+    pub bio_hash: ManagedBuffer<M>,
+    pub updated_block: u64,
+}
+
+/// Energy pool size, regeneration rate and per-action costs
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct EnergyConfig {
+    pub max_energy: u64,
+    pub blocks_per_energy_point: u64,
+    pub proposal_cost: u64,
+    pub vote_cost: u64,
+}
+
+impl Default for EnergyConfig {
+    fn default() -> Self {
+        EnergyConfig {
+            max_energy: 100,
+            blocks_per_energy_point: 10,
+            proposal_cost: 20,
+            vote_cost: 5,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct EnergyState {
+    pub energy: u64,
+    pub last_update_block: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CounterChoice {
+    Original,
+    Counter,
+    Abstain,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct PredictionPool<M: ManagedTypeApi> {
+    pub staked_pass: BigUint<M>,
+    pub staked_fail: BigUint<M>,
+    pub settled: bool,
+    pub passed: bool,
+    pub fee: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> PredictionPool<M> {
+    pub fn new() -> Self {
+        PredictionPool {
+            staked_pass: BigUint::zero(),
+            staked_fail: BigUint::zero(),
+            settled: false,
+            passed: false,
+            fee: BigUint::zero(),
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct PredictionStake<M: ManagedTypeApi> {
+    pub predicts_pass: bool,
+    pub amount: BigUint<M>,
+}
+
+/// Share of points mirrored between entangled partners and the minimum pairing duration
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct EntanglementConfig {
+    pub share_bps: u64,
+    pub min_lock_blocks: u64,
+}
+
+impl Default for EntanglementConfig {
+    fn default() -> Self {
+        EntanglementConfig {
+            share_bps: 1_000,
+            // ~7 days at 6s blocks
+            min_lock_blocks: 100_800,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoosterType {
+    /// Points earned are multiplied by `BOOSTED_POINTS_PERCENT`
+    PointMultiplier,
+    /// Energy costs are halved
+    ExtraEnergy,
+    /// Proposal creation fee is waived
+    ProposalFeeWaiver,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct BoosterOffer<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub duration_blocks: u64,
+}
+
+/// Final phase of a season with doubled points, short votes and one proposal per player
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug, Default)]
+pub struct SuddenDeathConfig {
+    pub duration_blocks: u64,
+    pub max_voting_blocks: u64,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SeasonBet<M: ManagedTypeApi> {
+    pub candidate: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+}
+
+/// Named preset bundling the settings operators rotate between seasons
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SeasonTheme<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub duration_blocks: u64,
+    pub scoring_config: ScoringConfig,
+    pub superposition_voting: bool,
+    pub reveal_duration_blocks: u64,
+    pub sudden_death: SuddenDeathConfig,
+    pub entry_fee: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SeasonThemeEntry<M: ManagedTypeApi> {
+    pub theme_id: u32,
+    pub theme: SeasonTheme<M>,
+}
+
+/// Coefficients and bounds for scaling difficulty with active players and total stake
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct DifficultyConfig<M: ManagedTypeApi> {
+    pub base_quorum: BigUint<M>,
+    pub quorum_per_player: BigUint<M>,
+    pub quorum_stake_bps: u64,
+    pub min_quorum: BigUint<M>,
+    pub max_quorum: BigUint<M>,
+    pub base_pass_threshold_bps: u64,
+    pub pass_threshold_bps_per_100_players: u64,
+    pub max_pass_threshold_bps: u64,
+    pub fee_player_step: u64,
+    pub max_proposal_fee: BigUint<M>,
+}
+
+/// Proposals default to `Standard`; `ProjectFunding` proposals belong to a funding round
+/// and `CouncilElection` proposals elect the proposer council
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalCategory {
+    Standard,
+    ProjectFunding,
+    CouncilElection,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct FundingRound<M: ManagedTypeApi> {
+    pub id: u32,
+    pub season_id: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub matching_pool: BigUint<M>,
+    pub settled: bool,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotificationKind {
+    /// `reference` is the closed proposal
+    ProposalExecuted,
+    /// `reference` is unused; another player took the season lead
+    Overtaken,
+    /// `reference` is the completed quest, or zero for the season prize
+    RewardClaimable,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub season_id: u32,
+    pub reference: u32,
+    pub block: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct FeaturedProposal<M: ManagedTypeApi> {
+    pub proposal_id: u32,
+    pub boost_amount: BigUint<M>,
+    pub expires_block: u64,
+}
+
+/// Proposal layout before contract version 2, read only by `migrateStorageBatch`
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyProposal<M: ManagedTypeApi> {
+    pub id: u32,
+    pub creator: ManagedAddress<M>,
+    pub title: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub votes_for: BigUint<M>,
+    pub votes_against: BigUint<M>,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub executed: bool,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct MigrationCursor {
+    pub season_id: u32,
+    pub proposal_id: u32,
+    /// Proposals checked for votes of the current player; 0 until the player itself is migrated
+    pub player_proposal_id: u32,
+    /// Players of `season_id` migrated so far, once all its proposals are done
+    pub player_index: usize,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// The game owner, set to the deployer and moved with `transferOwnership`/`acceptOwnership`
+    Owner,
+    /// Configures the game and seasons
+    Admin,
+    /// Handles player conduct
+    Moderator,
+    /// Runs routine maintenance calls
+    Keeper,
+    /// Pauses and resumes the game in emergencies
+    Guardian,
+}
+
+/// Groups of endpoints that can be paused independently of the global pause
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameFlow {
+    ProposalCreation,
+    Voting,
+    Claims,
+}
+
+/// Every fee the game charges, in one place
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct FeeConfig<M: ManagedTypeApi> {
+    /// Base EGLD fee for creating proposals, scaled by `DifficultyConfig`
+    pub proposal_fee: BigUint<M>,
+    /// Taken from the losing side of prediction pools
+    pub prediction_fee_bps: u64,
+    /// Taken from season betting pools
+    pub betting_fee_bps: u64,
+    /// Share of booster sales sent to the prize pool; the rest goes to the treasury
+    pub booster_prize_pool_share_bps: u64,
+    /// Minimum EGLD per `boostProposal` call
+    pub min_boost_amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Default for FeeConfig<M> {
+    fn default() -> Self {
+        FeeConfig {
+            proposal_fee: BigUint::zero(),
+            prediction_fee_bps: DEFAULT_PREDICTION_FEE_BPS,
+            betting_fee_bps: DEFAULT_BETTING_FEE_BPS,
+            booster_prize_pool_share_bps: 0,
+            min_boost_amount: BigUint::zero(),
+        }
+    }
+}
+
+/// Payload of a parameter proposal
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub enum ParameterChange<M: ManagedTypeApi> {
+    /// `DifficultyConfig::base_quorum`
+    BaseQuorum(BigUint<M>),
+    /// `DifficultyConfig::base_pass_threshold_bps`
+    BasePassThresholdBps(u64),
+    RevealDuration(u64),
+    BoostDuration(u64),
+    ProposalFee(BigUint<M>),
+    PredictionFeeBps(u64),
+    BettingFeeBps(u64),
+    Scoring(ScoringConfig),
+}
+
+/// Every tunable game setting in one storage entry.
+/// `version` leads the encoding so `upgrade` can recognise and convert older layouts.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GameConfig<M: ManagedTypeApi> {
+    pub version: u8,
+    pub scoring: ScoringConfig,
+    pub quantum_events: QuantumEventConfig,
+    pub check_in: CheckInConfig,
+    pub energy: EnergyConfig,
+    pub entanglement: EntanglementConfig,
+    pub sudden_death: SuddenDeathConfig,
+    pub fees: FeeConfig<M>,
+    pub superposition_mode: bool,
+    pub reveal_duration_blocks: u64,
+    pub boost_duration_blocks: u64,
+    pub max_game_extension_blocks: u64,
+    pub council: CouncilConfig,
+}
+
+impl<M: ManagedTypeApi> Default for GameConfig<M> {
+    fn default() -> Self {
+        GameConfig {
+            version: GAME_CONFIG_VERSION,
+            scoring: ScoringConfig::default(),
+            quantum_events: QuantumEventConfig::default(),
+            check_in: CheckInConfig::default(),
+            energy: EnergyConfig::default(),
+            entanglement: EntanglementConfig::default(),
+            sudden_death: SuddenDeathConfig::default(),
+            fees: FeeConfig::default(),
+            superposition_mode: false,
+            reveal_duration_blocks: 0,
+            boost_duration_blocks: DEFAULT_BOOST_DURATION_BLOCKS,
+            max_game_extension_blocks: 0,
+            council: CouncilConfig::default(),
+        }
+    }
+}
+
+/// `GameConfig` layout version 1, before council mode
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GameConfigV1<M: ManagedTypeApi> {
+    pub version: u8,
+    pub scoring: ScoringConfig,
+    pub quantum_events: QuantumEventConfig,
+    pub check_in: CheckInConfig,
+    pub energy: EnergyConfig,
+    pub entanglement: EntanglementConfig,
+    pub sudden_death: SuddenDeathConfig,
+    pub fees: FeeConfig<M>,
+    pub superposition_mode: bool,
+    pub reveal_duration_blocks: u64,
+    pub boost_duration_blocks: u64,
+    pub max_game_extension_blocks: u64,
+}
+
+impl<M: ManagedTypeApi> From<GameConfigV1<M>> for GameConfig<M> {
+    fn from(config: GameConfigV1<M>) -> Self {
+        GameConfig {
+            version: GAME_CONFIG_VERSION,
+            scoring: config.scoring,
+            quantum_events: config.quantum_events,
+            check_in: config.check_in,
+            energy: config.energy,
+            entanglement: config.entanglement,
+            sudden_death: config.sudden_death,
+            fees: config.fees,
+            superposition_mode: config.superposition_mode,
+            reveal_duration_blocks: config.reveal_duration_blocks,
+            boost_duration_blocks: config.boost_duration_blocks,
+            max_game_extension_blocks: config.max_game_extension_blocks,
+            council: CouncilConfig::default(),
+        }
+    }
+}
+
+/// Optional behaviour switched on for this deployment
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ContractFeatures {
+    pub seasons: bool,
+    pub commit_reveal_voting: bool,
+    pub superposition_mode: bool,
+    pub quadratic_funding: bool,
+    pub whitelist_mode: bool,
+    pub admin_multisig: bool,
+    pub league_linked: bool,
+    pub council_mode: bool,
+}
+
+/// Deployment metadata for frontends and tooling
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ContractInfo<M: ManagedTypeApi> {
+    pub version: u32,
+    pub game_config_version: u8,
+    pub features: ContractFeatures,
+    pub modules: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// Pending evacuation of all contract funds; cancellable until `executable_block`
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct EmergencyWithdraw<M: ManagedTypeApi> {
+    pub recovery_address: ManagedAddress<M>,
+    pub initiated_by: ManagedAddress<M>,
+    pub initiated_block: u64,
+    pub executable_block: u64,
+}
+
+/// Blocks `[start_block, end_block)` during which player endpoints are closed
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MaintenanceWindow {
+    pub start_block: u64,
+    pub end_block: u64,
+}
+
+/// Admin and owner mutations reported through the `admin_action` audit event
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    SetSeasonTheme,
+    RemoveSeasonTheme,
+    SetPowerUpToken,
+    SetCheckInConfig,
+    SetQuantumEventConfig,
+    SetEntryFee,
+    SetPaused,
+    SetFlowPaused,
+    Blacklist,
+    SetWhitelistMode,
+    Whitelist,
+    RejectAccess,
+    SetAdminMultisig,
+    SetRole,
+    SetMaxGameExtension,
+    SetEnergyConfig,
+    SetFeeConfig,
+    WithdrawTreasury,
+    SetSuperpositionMode,
+    SetEntanglementConfig,
+    SetBoosterOffer,
+    SetSuddenDeathConfig,
+    SetDifficultyConfig,
+    SetBoostDuration,
+    Migrate,
+    SetGameDuration,
+    SetNftRewardToken,
+    EmergencyWithdraw,
+    Maintenance,
+    StartNewSeason,
+    RestartGame,
+    StartFundingRound,
+    SetLeagueHub,
+    ChallengeInstance,
+    AcceptWarChallenge,
+    CreateQuest,
+    SetupTournament,
+    AdvanceTournamentPhase,
+    OpenBetting,
+    TransferOwnership,
+    TransferRole,
+    SetRegistry,
+    SetCouncilConfig,
+    OpenCouncilElection,
+    SetStakingPool,
+    SetRaffle,
+    VetoProposal,
+    SetTrustedRouter,
+    SetVoteAggregator,
+    SetVesting,
+    SetNftMinter,
+    SetFeeVault,
+    ForwardFees,
+}
+
+/// Top-encoded subject and before/after values of an admin mutation;
+/// an empty buffer stands for "unset"
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct AuditRecord<M: ManagedTypeApi> {
+    pub target: ManagedBuffer<M>,
+    pub old_value: ManagedBuffer<M>,
+    pub new_value: ManagedBuffer<M>,
+}
+
+/// Permissioned proposer mode: when enabled only council members create proposals
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct CouncilConfig {
+    pub enabled: bool,
+    pub seats: u32,
+    pub term_blocks: u64,
+    pub election_duration_blocks: u64,
+}
+
+impl Default for CouncilConfig {
+    fn default() -> Self {
+        CouncilConfig {
+            enabled: false,
+            seats: DEFAULT_COUNCIL_SEATS,
+            term_blocks: DEFAULT_COUNCIL_TERM_BLOCKS,
+            election_duration_blocks: DEFAULT_COUNCIL_ELECTION_BLOCKS,
+        }
+    }
+}
+
+/// The `CouncilElection` proposal currently collecting candidates and votes
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CouncilElection {
+    pub season_id: u32,
+    pub proposal_id: u32,
+}
+
+/// Direction of a vote as publicly known; committed votes stay `Hidden` until revealed
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoteChoice {
+    For,
+    Against,
+    Hidden,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalVoter<M: ManagedTypeApi> {
+    pub voter: ManagedAddress<M>,
+    pub choice: VoteChoice,
+    pub stake: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalRef {
+    pub season_id: u32,
+    pub proposal_id: u32,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct AuthoredProposal<M: ManagedTypeApi> {
+    pub season_id: u32,
+    pub proposal: Proposal<M>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct VoteHistoryEntry<M: ManagedTypeApi> {
+    pub season_id: u32,
+    pub proposal_id: u32,
+    pub choice: VoteChoice,
+    pub stake: BigUint<M>,
+    pub block: u64,
+}
+
+/// Outcome figures of a proposal; quorum and pass threshold are the values currently in force
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalResult<M: ManagedTypeApi> {
+    pub status: ProposalStatus,
+    pub votes_for: BigUint<M>,
+    pub votes_against: BigUint<M>,
+    pub quorum: BigUint<M>,
+    pub quorum_reached: bool,
+    pub voter_count: usize,
+    /// Voters as a share of the season's players
+    pub turnout_bps: u64,
+    /// Stake in favour as a share of all counted stake
+    pub for_share_bps: u64,
+    pub pass_threshold_bps: u64,
+    /// Absolute difference between stake for and against
+    pub margin: BigUint<M>,
+    /// Zero until the proposal is executed, funded or closed
+    pub execution_block: u64,
+}
+
+/// Snapshot of the current season for dashboards
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct GameStatus<M: ManagedTypeApi> {
+    pub season_id: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub blocks_remaining: u64,
+    pub total_players: usize,
+    pub total_proposals: u32,
+    pub total_votes: u64,
+    pub total_voters: u32,
+    pub total_staked: BigUint<M>,
+    pub paused: bool,
+    pub under_maintenance: bool,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClaimKind {
+    /// `reference` is the quest id
+    QuestReward,
+    Prize,
+    NftReward,
+    /// `reference` is the proposal id
+    Prediction,
+    Bet,
+    /// `reference` is the proposal id whose vote stake can be withdrawn
+    StakeRefund,
+    /// `reference` is the grant id in the vesting contract
+    Vested,
+}
+
+/// Something an address can currently claim; `amount` may be zero for points-only quests
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ClaimableReward<M: ManagedTypeApi> {
+    pub kind: ClaimKind,
+    pub season_id: u32,
+    pub reference: u64,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+/// Countdown to a deadline block; seconds are estimated from the network round duration
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TimeRemaining {
+    pub end_block: u64,
+    pub blocks_remaining: u64,
+    pub estimated_seconds: u64,
+}
+
+/// Leaderboard position; `exact` is false when the player is outside the ranked top and
+/// `rank` is estimated from score buckets
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PlayerRank {
+    pub rank: u32,
+    pub exact: bool,
+}
+
+/// Number of proposals of a season in each `ProposalStatus`
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalCounts {
+    pub active: u32,
+    pub passed: u32,
+    pub rejected: u32,
+    pub funded: u32,
+}
+
+/// Every tunable of a deployment, for snapshotting and diffing configuration off-chain
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigSnapshot<M: ManagedTypeApi> {
+    pub contract_version: u32,
+    pub game_duration_blocks: u64,
+    pub nft_reward_token_id: TokenIdentifier<M>,
+    pub power_up_token_id: Option<TokenIdentifier<M>>,
+    pub whitelist_mode: bool,
+    pub difficulty: Option<DifficultyConfig<M>>,
+    pub game_config: GameConfig<M>,
+}
+
+/// A season with its prize pool and, once it has ended, its final top players
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SeasonInfo<M: ManagedTypeApi> {
+    pub season: Season,
+    pub theme_id: u32,
+    pub entry_fee: BigUint<M>,
+    pub prize_pool: BigUint<M>,
+    pub ended: bool,
+    pub winners: ManagedVec<M, ManagedAddress<M>>,
+}
+
+/// Per-address state the anti-spam checks consult, so clients can explain a rejection up front.
+/// Energy costs already account for an active ExtraEnergy booster.
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ActivityLimits {
+    pub energy: u64,
+    pub max_energy: u64,
+    pub proposal_energy_cost: u64,
+    pub vote_energy_cost: u64,
+    pub proposals_this_season: u32,
+    pub sudden_death_proposal_used: bool,
+    pub checked_in_this_epoch: bool,
+    pub blacklisted: bool,
+}
+
+/// Balances tracked by the game's own accounting, all in EGLD
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct PoolBalances<M: ManagedTypeApi> {
+    pub treasury: BigUint<M>,
+    pub prize_pool: BigUint<M>,
+    pub fees_collected: BigUint<M>,
+    pub staked: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalStatusTransition {
+    pub from: ProposalStatus,
+    pub to: ProposalStatus,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScoreChange {
+    pub previous_score: u64,
+    pub new_score: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct LeaderboardEntry<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub score: u64,
+}
+
+/// A proposal description as stored: the full text, or the hash of an off-chain body
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalDescription<M: ManagedTypeApi> {
+    Text(ManagedBuffer<M>),
+    ContentHash(ManagedByteArray<M, 32>),
+}
+
+/// Gas spent by one endpoint, recorded only in builds with the `gas-instrumentation` feature
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct GasCounters {
+    pub calls: u64,
+    pub total_gas: u64,
+    pub max_gas: u64,
+}
@@ -0,0 +1,11 @@
+multiversx_sc::imports!();
+
+/// Views of the suite's vesting contract listed among a player's claimable rewards
+#[multiversx_sc::proxy]
+pub trait Vesting {
+    #[view(getClaimableGrants)]
+    fn get_claimable_grants(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<u64, EgldOrEsdtTokenIdentifier, BigUint>>;
+}
@@ -0,0 +1,1487 @@
+use multiversx_sc::imports::*;
+
+use crate::{constants::*, types::*};
+
+/// Read-only views
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::admin::AdminModule
+    + crate::scoring::ScoringModule
+    + crate::rewards::RewardsModule
+    + crate::staking::StakingModule
+    + crate::governance::GovernanceModule
+    + crate::league::LeagueModule
+{
+    #[view(getProposal)]
+    fn get_proposal(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<Proposal<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        if !self.proposal_exists(season_id, proposal_id) {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.load_proposal(season_id, proposal_id))
+    }
+
+    /// Description of a proposal in the form it was stored: full text or off-chain content hash
+    #[view(getProposalDescription)]
+    fn get_proposal_description(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<ProposalDescription<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        if !self.proposal_exists(season_id, proposal_id) {
+            return OptionalValue::None;
+        }
+
+        let description = self.proposal_info(season_id).get(proposal_id as usize).description;
+        if !self.content_hash_proposals(season_id).contains(&proposal_id) {
+            return OptionalValue::Some(ProposalDescription::Text(description));
+        }
+
+        OptionalValue::Some(ProposalDescription::ContentHash(
+            ManagedByteArray::try_from(description).unwrap_or_default(),
+        ))
+    }
+
+    /// Up to `count` proposals starting at `start_id`, capped at `MAX_PAGE_SIZE`
+    #[view(getProposalsPaged)]
+    fn get_proposals_paged(
+        &self,
+        start_id: u32,
+        count: u32,
+        season_id: OptionalValue<u32>,
+    ) -> ManagedVec<Proposal<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        let start_id = core::cmp::max(start_id, 1);
+        let end_id = core::cmp::min(
+            start_id.saturating_add(core::cmp::min(count, MAX_PAGE_SIZE)),
+            self.proposal_tallies(season_id).len() as u32 + 1,
+        );
+
+        let mut proposals = ManagedVec::new();
+        for proposal_id in start_id..end_id {
+            proposals.push(self.load_proposal(season_id, proposal_id));
+        }
+        proposals
+    }
+
+    /// Current-season proposals among `proposal_ids`, at most `MAX_PAGE_SIZE`; missing ids are skipped
+    #[view(getProposalsByIds)]
+    fn get_proposals_by_ids(&self, proposal_ids: MultiValueEncoded<u32>) -> ManagedVec<Proposal<Self::Api>> {
+        let season_id = self.current_season_id().get();
+
+        let mut proposals = ManagedVec::new();
+        for proposal_id in proposal_ids.into_iter().take(MAX_PAGE_SIZE as usize) {
+            if self.proposal_exists(season_id, proposal_id) {
+                proposals.push(self.load_proposal(season_id, proposal_id));
+            }
+        }
+        proposals
+    }
+
+    /// Proposals that can still be voted on, with their deadlines in `end_block`, among the open
+    /// proposals from offset `from`, at most `size` (capped at `MAX_PAGE_SIZE`).
+    /// Comes with the offset of the next page, or 0 once the list is exhausted.
+    #[view(getActiveProposals)]
+    fn get_active_proposals(
+        &self,
+        from: usize,
+        size: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<usize, ManagedVec<Proposal<Self::Api>>> {
+        let season_id = self.resolve_season(season_id);
+        let current_block = self.blockchain().get_block_nonce();
+        let active_mapper = self.active_proposals(season_id);
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            active_mapper.len(),
+        );
+
+        let mut proposals = ManagedVec::new();
+        for index in from..end {
+            let proposal = self.load_proposal(season_id, active_mapper.get_by_index(index + 1));
+            if current_block <= proposal.end_block {
+                proposals.push(proposal);
+            }
+        }
+        (self.next_page(end, active_mapper.len()), proposals).into()
+    }
+
+    /// Voters of a proposal from `offset`, at most `limit`, with the offset of the next page
+    /// (zero once exhausted)
+    #[view(getProposalVoters)]
+    fn get_proposal_voters(
+        &self,
+        proposal_id: u32,
+        offset: usize,
+        limit: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<ManagedVec<ProposalVoter<Self::Api>>, usize> {
+        let season_id = self.resolve_season(season_id);
+        let voters_mapper = self.proposal_voters(season_id, proposal_id);
+        let end = self.page_end(offset, limit, voters_mapper.len());
+
+        let mut voters = ManagedVec::new();
+        for index in offset..end {
+            let voter = voters_mapper.get_by_index(index + 1);
+            let vote = self.user_votes(season_id, proposal_id, self.player_id(&voter)).get();
+            voters.push(ProposalVoter {
+                voter,
+                choice: vote.choice(),
+                stake: vote.stake_amount,
+            });
+        }
+        (voters, self.next_page(end, voters_mapper.len())).into()
+    }
+
+    /// Votes cast by `address` across all seasons from offset `from`, at most `size`
+    /// (capped at `MAX_PAGE_SIZE`), oldest first. Revoked votes are skipped, so a page may
+    /// hold fewer entries.
+    #[view(getUserVoteHistory)]
+    fn get_user_vote_history(
+        &self,
+        address: ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> ManagedVec<VoteHistoryEntry<Self::Api>> {
+        let history_mapper = self.user_vote_history(&address);
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            history_mapper.len(),
+        );
+
+        let mut history = ManagedVec::new();
+        for index in from..end {
+            if history_mapper.item_is_empty(index + 1) {
+                continue;
+            }
+
+            let proposal_ref = history_mapper.get(index + 1);
+            // Votes on pruned proposals are no longer stored
+            let vote_mapper = self.user_votes(proposal_ref.season_id, proposal_ref.proposal_id, self.player_id(&address));
+            if vote_mapper.is_empty() {
+                continue;
+            }
+            let vote = vote_mapper.get();
+            history.push(VoteHistoryEntry {
+                season_id: proposal_ref.season_id,
+                proposal_id: proposal_ref.proposal_id,
+                choice: vote.choice(),
+                stake: vote.stake_amount,
+                block: vote.block_number,
+            });
+        }
+        history
+    }
+
+    /// Proposals created by `address`, across seasons, oldest first
+    #[view(getProposalsByCreator)]
+    fn get_proposals_by_creator(
+        &self,
+        address: ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> ManagedVec<AuthoredProposal<Self::Api>> {
+        let authored_mapper = self.creator_proposals(&address);
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            authored_mapper.len(),
+        );
+
+        let mut proposals = ManagedVec::new();
+        for index in from..end {
+            let proposal_ref = authored_mapper.get(index + 1);
+            proposals.push(AuthoredProposal {
+                season_id: proposal_ref.season_id,
+                proposal: self.load_proposal(proposal_ref.season_id, proposal_ref.proposal_id),
+            });
+        }
+        proposals
+    }
+
+    #[view(getCreatorProposalCount)]
+    fn get_creator_proposal_count(&self, address: ManagedAddress) -> usize {
+        self.creator_proposals(&address).len()
+    }
+
+    /// EGLD currently staked in votes across all seasons
+    #[view(getTotalStaked)]
+    fn get_total_staked(&self) -> BigUint {
+        self.total_staked().get()
+    }
+
+    #[view(getProposalStake)]
+    fn get_proposal_stake(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> BigUint {
+        let season_id = self.resolve_season(season_id);
+        self.proposal_stake(season_id, proposal_id).get()
+    }
+
+    /// A committed superposition vote reads `vote_for: false` until it is revealed
+    #[view(getUserVote)]
+    fn get_user_vote(
+        &self,
+        proposal_id: u32,
+        address: ManagedAddress,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<Vote<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        let vote_mapper = self.user_votes(season_id, proposal_id, self.player_id(&address));
+        if vote_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(vote_mapper.get().into_vote(address, proposal_id))
+    }
+
+    #[view(getProposalVoterCount)]
+    fn get_proposal_voter_count(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> usize {
+        let season_id = self.resolve_season(season_id);
+        self.proposal_voter_count(season_id, proposal_id)
+    }
+
+    #[view(getProposalResult)]
+    fn get_proposal_result(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<ProposalResult<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        if !self.proposal_exists(season_id, proposal_id) {
+            return OptionalValue::None;
+        }
+
+        let proposal = self.load_proposal(season_id, proposal_id);
+        let total_votes = &proposal.votes_for + &proposal.votes_against;
+        let quorum = self.current_quorum();
+        let for_share_bps = if total_votes > 0 {
+            (proposal.votes_for.clone() * MAX_BPS / &total_votes).to_u64().unwrap_or(0)
+        } else {
+            0
+        };
+        let margin = if proposal.votes_for >= proposal.votes_against {
+            &proposal.votes_for - &proposal.votes_against
+        } else {
+            &proposal.votes_against - &proposal.votes_for
+        };
+
+        let voter_count = self.proposal_voter_count(season_id, proposal_id);
+        let player_count = self.players(season_id).len();
+        let turnout_bps = if player_count > 0 {
+            voter_count as u64 * MAX_BPS / player_count as u64
+        } else {
+            0
+        };
+
+        OptionalValue::Some(ProposalResult {
+            status: proposal.status,
+            quorum_reached: total_votes >= quorum,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            quorum,
+            voter_count,
+            turnout_bps,
+            for_share_bps,
+            pass_threshold_bps: self.current_pass_threshold_bps(),
+            margin,
+            execution_block: self.proposal_execution_block(season_id, proposal_id).get(),
+        })
+    }
+
+    #[view(getProposalCount)]
+    fn get_proposal_count(&self, season_id: OptionalValue<u32>) -> u32 {
+        let season_id = self.resolve_season(season_id);
+        self.current_proposal_id(season_id).get().saturating_sub(1)
+    }
+
+    #[view(getProposalCounts)]
+    fn get_proposal_counts(&self, season_id: OptionalValue<u32>) -> ProposalCounts {
+        let season_id = self.resolve_season(season_id);
+        ProposalCounts {
+            active: self.proposal_status_count(season_id, ProposalStatus::Active).get(),
+            passed: self.proposal_status_count(season_id, ProposalStatus::Passed).get(),
+            rejected: self.proposal_status_count(season_id, ProposalStatus::Rejected).get(),
+            funded: self.proposal_status_count(season_id, ProposalStatus::Funded).get(),
+        }
+    }
+
+    #[view(getPlayerScore)]
+    fn get_player_score(&self, player: &ManagedAddress, season_id: OptionalValue<u32>) -> u64 {
+        let season_id = self.resolve_season(season_id);
+        self.dao_scores(season_id, self.player_id(player)).get()
+    }
+
+    /// None for players without points this season
+    #[view(getPlayerRank)]
+    fn get_player_rank(
+        &self,
+        address: ManagedAddress,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<PlayerRank> {
+        let season_id = self.resolve_season(season_id);
+        let score = self.dao_scores(season_id, self.player_id(&address)).get();
+        if score == 0 {
+            return OptionalValue::None;
+        }
+
+        let position = self.leaderboard_position(season_id, self.player_id(&address)).get();
+        if position > 0 {
+            return OptionalValue::Some(PlayerRank {
+                rank: position as u32,
+                exact: true,
+            });
+        }
+
+        // Everyone in higher buckets is ahead; assume the player sits mid-way through their own
+        let bucket = self.score_bucket(score);
+        let mut ahead = 0u32;
+        for higher_bucket in bucket + 1..=u64::BITS {
+            ahead += self.score_bucket_players(season_id, higher_bucket).get();
+        }
+        ahead += self.score_bucket_players(season_id, bucket).get() / 2;
+
+        OptionalValue::Some(PlayerRank {
+            rank: core::cmp::max(ahead + 1, self.leaderboard(season_id).len() as u32 + 1),
+            exact: false,
+        })
+    }
+
+    /// Up to `count` best players as (address, score, rank), capped at `TOP_PLAYERS_SIZE`
+    #[view(getTopPlayers)]
+    fn get_top_players(
+        &self,
+        count: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, u64, u32>> {
+        let season_id = self.resolve_season(season_id);
+        let leaderboard = self.leaderboard(season_id);
+        let end = core::cmp::min(count, leaderboard.len());
+
+        let mut result = MultiValueEncoded::new();
+        for rank in 1..=end {
+            let entry = leaderboard.get(rank);
+            result.push((entry.player, entry.score, rank as u32).into());
+        }
+        result
+    }
+
+    #[view(getGameStatus)]
+    fn get_game_status(&self) -> GameStatus<Self::Api> {
+        let season_id = self.current_season_id().get();
+        let season = self.seasons(season_id).get();
+        let current_block = self.blockchain().get_block_nonce();
+
+        GameStatus {
+            season_id,
+            start_block: season.start_block,
+            end_block: season.end_block,
+            blocks_remaining: season.end_block.saturating_sub(current_block),
+            total_players: self.players(season_id).len(),
+            total_proposals: self.current_proposal_id(season_id).get().saturating_sub(1),
+            total_votes: self.season_vote_count(season_id).get(),
+            total_voters: self.season_voter_count(season_id).get(),
+            total_staked: self.season_staked(season_id).get(),
+            paused: self.paused().get(),
+            under_maintenance: self.is_under_maintenance(),
+        }
+    }
+
+    #[view(getVotingTimeRemaining)]
+    fn get_voting_time_remaining(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<TimeRemaining> {
+        let season_id = self.resolve_season(season_id);
+        if !self.proposal_exists(season_id, proposal_id) {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.time_remaining(self.proposal_info(season_id).get(proposal_id as usize).end_block))
+    }
+
+    /// Countdown to the end of the current season
+    #[view(getGameTimeRemaining)]
+    fn get_game_time_remaining(&self) -> TimeRemaining {
+        let season_id = self.current_season_id().get();
+        self.time_remaining(self.seasons(season_id).get().end_block)
+    }
+
+    /// Everything `address` could claim for a season right now, with the claimable amount of each.
+    /// Stake refunds and prediction payouts are listed for up to `size` proposals (capped at
+    /// `MAX_PAGE_SIZE`) after the first `from`; everything else comes with the first page.
+    /// Comes with the `from` of the next page, or 0 once every proposal has been scanned.
+    #[view(getClaimableRewards)]
+    fn get_claimable_rewards(
+        &self,
+        address: ManagedAddress,
+        from: u32,
+        size: u32,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<u32, MultiValueEncoded<ClaimableReward<Self::Api>>> {
+        let season_id = self.resolve_season(season_id);
+        let current_season_id = self.current_season_id().get();
+        let mut result = MultiValueEncoded::new();
+
+        if from == 0 {
+            self.push_season_claims(&address, season_id, current_season_id, &mut result);
+        }
+
+        let voter_id = self.player_id(&address);
+        let start_id = from.saturating_add(1);
+        let next_proposal_id = self.current_proposal_id(season_id).get();
+        let end_id = core::cmp::min(
+            start_id.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE)),
+            next_proposal_id,
+        );
+        for proposal_id in start_id..end_id {
+            let vote_mapper = self.user_votes(season_id, proposal_id, voter_id);
+            if !vote_mapper.is_empty()
+                && !self.proposal_execution_block(season_id, proposal_id).is_empty()
+                && !self.stake_withdrawn(season_id, proposal_id, voter_id).get()
+            {
+                result.push(ClaimableReward {
+                    kind: ClaimKind::StakeRefund,
+                    season_id,
+                    reference: proposal_id as u64,
+                    token: EgldOrEsdtTokenIdentifier::egld(),
+                    amount: vote_mapper.get().stake_amount,
+                });
+            }
+
+            let stake_mapper = self.prediction_stakes(season_id, proposal_id, &address);
+            if stake_mapper.is_empty() {
+                continue;
+            }
+
+            let pool = self.prediction_pools(season_id, proposal_id).get();
+            if pool.settled {
+                result.push(ClaimableReward {
+                    kind: ClaimKind::Prediction,
+                    season_id,
+                    reference: proposal_id as u64,
+                    token: EgldOrEsdtTokenIdentifier::egld(),
+                    amount: self.prediction_payout(&pool, &stake_mapper.get()),
+                });
+            }
+        }
+
+        let next_from = if end_id < next_proposal_id { end_id - 1 } else { 0 };
+        (next_from, result).into()
+    }
+
+    #[view(getSeason)]
+    fn get_season(&self, season_id: OptionalValue<u32>) -> OptionalValue<Season> {
+        let season_id = self.resolve_season(season_id);
+        let season_mapper = self.seasons(season_id);
+        if season_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(season_mapper.get())
+    }
+
+    #[view(getCurrentSeason)]
+    fn get_current_season(&self) -> SeasonInfo<Self::Api> {
+        self.season_info(self.current_season_id().get())
+    }
+
+    #[view(getSeasonInfo)]
+    fn get_season_info(&self, season_id: u32) -> OptionalValue<SeasonInfo<Self::Api>> {
+        if self.seasons(season_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.season_info(season_id))
+    }
+
+    #[view(getActiveQuests)]
+    fn get_active_quests(&self) -> MultiValueEncoded<MultiValue2<usize, Quest<Self::Api>>> {
+        let season_id = self.current_season_id().get();
+        let current_block = self.blockchain().get_block_nonce();
+        let mut result = MultiValueEncoded::new();
+        for (quest_id, quest) in self.quests(season_id).iter().enumerate() {
+            if quest.start_block <= current_block && current_block <= quest.end_block {
+                result.push((quest_id + 1, quest).into());
+            }
+        }
+        result
+    }
+
+    #[view(getQuestProgress)]
+    fn get_quest_progress(
+        &self,
+        quest_id: usize,
+        player: &ManagedAddress,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<u32, bool> {
+        let season_id = self.resolve_season(season_id);
+        let progress = self.quest_progress(season_id, quest_id, player).get();
+        let claimed = self.quest_claimed(season_id, quest_id).contains(player);
+        (progress, claimed).into()
+    }
+
+    #[view(getCheckInStatus)]
+    fn get_check_in_status(&self, player: &ManagedAddress) -> MultiValue2<u64, u64> {
+        (
+            self.last_check_in_block(player).get(),
+            self.check_in_streak(player).get(),
+        )
+            .into()
+    }
+
+    #[view(getPrizePool)]
+    fn get_prize_pool(&self, season_id: OptionalValue<u32>) -> BigUint {
+        let season_id = self.resolve_season(season_id);
+        self.prize_pool(season_id).get()
+    }
+
+    #[view(getEntryFee)]
+    fn get_entry_fee(&self, season_id: OptionalValue<u32>) -> BigUint {
+        let season_id = self.resolve_season(season_id);
+        self.entry_fee(season_id).get()
+    }
+
+    #[view(hasJoined)]
+    fn has_joined(&self, player: &ManagedAddress, season_id: OptionalValue<u32>) -> bool {
+        let season_id = self.resolve_season(season_id);
+        self.joined_players(season_id).contains(player)
+    }
+
+    /// Quantum events of a season from offset `from`, at most `size` (capped at `MAX_PAGE_SIZE`),
+    /// oldest first. Comes with the offset of the next page, or 0 once the list is exhausted.
+    #[view(getQuantumEvents)]
+    fn get_quantum_events(
+        &self,
+        from: usize,
+        size: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<usize, MultiValueEncoded<QuantumEvent<Self::Api>>> {
+        let season_id = self.resolve_season(season_id);
+        let events_mapper = self.quantum_events(season_id);
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            events_mapper.len(),
+        );
+
+        let mut events = MultiValueEncoded::new();
+        for index in from..end {
+            events.push(events_mapper.get(index + 1));
+        }
+        (self.next_page(end, events_mapper.len()), events).into()
+    }
+
+    #[view(getCurrentSeasonId)]
+    fn get_current_season_id(&self) -> u32 {
+        self.current_season_id().get()
+    }
+
+    /// Aggregate score of all players in a season, compared in DAO wars
+    #[view(getTotalScore)]
+    fn get_total_score(&self, season_id: OptionalValue<u32>) -> u64 {
+        let season_id = self.resolve_season(season_id);
+        self.total_score(season_id).get()
+    }
+
+    #[view(getTournamentPhase)]
+    fn get_tournament_phase(&self, season_id: OptionalValue<u32>) -> u32 {
+        let season_id = self.resolve_season(season_id);
+        self.tournament_phase(season_id).get()
+    }
+
+    /// Participants of a tournament phase with their group and phase score, from offset `from`,
+    /// at most `size` (capped at `MAX_PAGE_SIZE`). Comes with the offset of the next page,
+    /// or 0 once the list is exhausted.
+    #[view(getTournamentParticipants)]
+    fn get_tournament_participants(
+        &self,
+        phase: u32,
+        from: usize,
+        size: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<usize, MultiValueEncoded<MultiValue3<ManagedAddress, u32, u64>>> {
+        let season_id = self.resolve_season(season_id);
+        let participants_mapper = self.tournament_participants(season_id, phase);
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            participants_mapper.len(),
+        );
+
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            let player = participants_mapper.get(index + 1);
+            let group = self.tournament_group(season_id, phase, &player).get();
+            let score = self.tournament_phase_scores(season_id, phase, &player).get();
+            result.push((player, group, score).into());
+        }
+        (self.next_page(end, participants_mapper.len()), result).into()
+    }
+
+    #[view(getTournamentWinner)]
+    fn get_tournament_winner(&self, season_id: OptionalValue<u32>) -> OptionalValue<ManagedAddress> {
+        let season_id = self.resolve_season(season_id);
+        let winner_mapper = self.tournament_winner(season_id);
+        if winner_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(winner_mapper.get())
+        }
+    }
+
+    #[view(getGuild)]
+    fn get_guild(&self, guild_id: u32) -> OptionalValue<MultiValue3<Guild<Self::Api>, usize, BigUint>> {
+        if self.guilds(guild_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(
+            (
+                self.guilds(guild_id).get(),
+                self.guild_members(guild_id).len(),
+                self.guild_treasury(guild_id).get(),
+            )
+                .into(),
+        )
+    }
+
+    #[view(getPlayerGuild)]
+    fn get_player_guild(&self, player: &ManagedAddress) -> u32 {
+        self.player_guild(player).get()
+    }
+
+    #[view(getProfile)]
+    fn get_profile(&self, player: &ManagedAddress) -> OptionalValue<Profile<Self::Api>> {
+        let profile_mapper = self.profiles(player);
+        if profile_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(profile_mapper.get())
+        }
+    }
+
+    #[view(getAddressByNickname)]
+    fn get_address_by_nickname(&self, nickname: &ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        let nickname_owner_mapper = self.nickname_owner(nickname);
+        if nickname_owner_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(nickname_owner_mapper.get())
+        }
+    }
+
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused().get()
+    }
+
+    #[view(getPausedFlows)]
+    fn get_paused_flows(&self) -> MultiValueEncoded<GameFlow> {
+        self.paused_flows().iter().collect()
+    }
+
+    #[view(isWhitelistMode)]
+    fn is_whitelist_mode(&self) -> bool {
+        self.whitelist_mode().get()
+    }
+
+    #[view(isWhitelisted)]
+    fn is_whitelisted(&self, address: ManagedAddress) -> bool {
+        self.whitelist().contains(&address)
+    }
+
+    #[view(getAccessRequests)]
+    fn get_access_requests(&self, offset: usize, limit: usize) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        self.address_page(&self.access_requests(), offset, limit)
+    }
+
+    #[view(getBlacklist)]
+    fn get_blacklist(&self, offset: usize, limit: usize) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        self.address_page(&self.blacklist(), offset, limit)
+    }
+
+    #[view(isBlacklisted)]
+    fn is_blacklisted(&self, address: ManagedAddress) -> bool {
+        self.blacklist().contains(&address)
+    }
+
+    #[view(getAdminMultisig)]
+    fn get_admin_multisig(&self) -> OptionalValue<ManagedAddress> {
+        if self.admin_multisig().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.admin_multisig().get())
+    }
+
+    #[view(getVoteAggregators)]
+    fn get_vote_aggregators(&self, offset: usize, limit: usize) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        self.address_page(&self.vote_aggregators(), offset, limit)
+    }
+
+    #[view(isAdminMultisig)]
+    fn is_admin_multisig(&self, address: ManagedAddress) -> bool {
+        !self.admin_multisig().is_empty() && address == self.admin_multisig().get()
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(
+        &self,
+        role: Role,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        self.address_page(&self.role_members(role), offset, limit)
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        self.address_has_role(role, &address)
+    }
+
+    #[view(getSeasonExtension)]
+    fn get_season_extension(&self, season_id: OptionalValue<u32>) -> MultiValue2<u64, u64> {
+        let season_id = self.resolve_season(season_id);
+        (
+            self.season_extension_blocks(season_id).get(),
+            self.game_config().get().max_game_extension_blocks,
+        )
+            .into()
+    }
+
+    /// Current energy of a player, including regeneration since their last action
+    #[view(getActivityLimits)]
+    fn get_activity_limits(&self, address: ManagedAddress) -> ActivityLimits {
+        let season_id = self.current_season_id().get();
+        let game_config = self.game_config().get();
+        let current_block = self.blockchain().get_block_nonce();
+
+        let cost_divisor = if self.is_booster_active(&address, BoosterType::ExtraEnergy) {
+            2
+        } else {
+            1
+        };
+
+        let authored_mapper = self.creator_proposals(&address);
+        let mut proposals_this_season = 0u32;
+        for index in (1..=authored_mapper.len()).rev() {
+            if authored_mapper.get(index).season_id != season_id {
+                break;
+            }
+            proposals_this_season += 1;
+        }
+
+        let last_check_in_mapper = self.last_check_in_block(&address);
+        let epoch_blocks = game_config.check_in.epoch_blocks;
+        let checked_in_this_epoch = !last_check_in_mapper.is_empty()
+            && last_check_in_mapper.get() / epoch_blocks == current_block / epoch_blocks;
+
+        ActivityLimits {
+            energy: self.current_energy(&address, &game_config.energy),
+            max_energy: game_config.energy.max_energy,
+            proposal_energy_cost: game_config.energy.proposal_cost / cost_divisor,
+            vote_energy_cost: game_config.energy.vote_cost / cost_divisor,
+            proposals_this_season,
+            sudden_death_proposal_used: self.sudden_death_proposers(season_id).contains(&address),
+            checked_in_this_epoch,
+            blacklisted: self.blacklist().contains(&address),
+        }
+    }
+
+    #[view(getEnergy)]
+    fn get_energy(&self, player: &ManagedAddress) -> u64 {
+        self.current_energy(player, &self.game_config().get().energy)
+    }
+
+    #[view(getPredictionPool)]
+    fn get_prediction_pool(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<PredictionPool<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        let pool_mapper = self.prediction_pools(season_id, proposal_id);
+        if pool_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(pool_mapper.get())
+    }
+
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> BigUint {
+        self.treasury().get()
+    }
+
+    /// Treasury, plus the prize pool, fees collected and stake of a season
+    #[view(getPoolBalances)]
+    fn get_pool_balances(&self, season_id: OptionalValue<u32>) -> PoolBalances<Self::Api> {
+        let season_id = self.resolve_season(season_id);
+        PoolBalances {
+            treasury: self.treasury().get(),
+            prize_pool: self.prize_pool(season_id).get(),
+            fees_collected: self.season_fees_collected(season_id).get(),
+            staked: self.season_staked(season_id).get(),
+        }
+    }
+
+    /// Quest rewards of a season still held for future claims, per token
+    #[view(getRewardInventory)]
+    fn get_reward_inventory(
+        &self,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let season_id = self.resolve_season(season_id);
+
+        let mut tokens: ManagedVec<EgldOrEsdtTokenIdentifier> = ManagedVec::new();
+        let mut amounts: ManagedVec<BigUint> = ManagedVec::new();
+        for quest in self.quests(season_id).iter() {
+            let remaining = quest.reward_amount * (quest.max_claims - quest.claims);
+            if remaining == 0 {
+                continue;
+            }
+
+            match tokens.iter().position(|token| *token == quest.reward_token) {
+                Some(index) => {
+                    let total = &*amounts.get(index) + &remaining;
+                    let _ = amounts.set(index, &total);
+                },
+                None => {
+                    tokens.push(quest.reward_token);
+                    amounts.push(remaining);
+                },
+            }
+        }
+
+        let mut result = MultiValueEncoded::new();
+        for (index, token) in tokens.iter().enumerate() {
+            result.push((token.clone_value(), amounts.get(index).clone_value()).into());
+        }
+        result
+    }
+
+    #[view(isSuperpositionProposal)]
+    fn is_superposition_proposal(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> bool {
+        let season_id = self.resolve_season(season_id);
+        self.superposition_proposals(season_id).contains(&proposal_id)
+    }
+
+    /// Total stake committed to a superposition proposal, without revealing its direction
+    #[view(getCommittedStake)]
+    fn get_committed_stake(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> BigUint {
+        let season_id = self.resolve_season(season_id);
+        self.committed_stake(season_id, proposal_id).get()
+    }
+
+    #[view(getEntanglementPartner)]
+    fn get_entanglement_partner(&self, player: &ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let partner_mapper = self.entanglement_partner(player);
+        if partner_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(partner_mapper.get())
+        }
+    }
+
+    /// Entangled pairs with their combined season score, from offset `from`, at most `size`
+    /// (capped at `MAX_PAGE_SIZE`). Comes with the offset of the next page, or 0 once the
+    /// list is exhausted.
+    #[view(getEntangledLeaderboard)]
+    fn get_entangled_leaderboard(
+        &self,
+        from: usize,
+        size: usize,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<usize, MultiValueEncoded<MultiValue3<ManagedAddress, ManagedAddress, u64>>> {
+        let season_id = self.resolve_season(season_id);
+        let pairs_mapper = self.entangled_pairs();
+        let end = core::cmp::min(
+            from.saturating_add(core::cmp::min(size, MAX_PAGE_SIZE as usize)),
+            pairs_mapper.len(),
+        );
+
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            let first = pairs_mapper.get_by_index(index + 1);
+            let second = self.entanglement_partner(&first).get();
+            let combined_score =
+                self.dao_scores(season_id, self.player_id(&first)).get() + self.dao_scores(season_id, self.player_id(&second)).get();
+            result.push((first, second, combined_score).into());
+        }
+        (self.next_page(end, pairs_mapper.len()), result).into()
+    }
+
+    #[view(getBoosterOffer)]
+    fn get_booster_offer(&self, booster_type: BoosterType) -> OptionalValue<BoosterOffer<Self::Api>> {
+        let offer_mapper = self.booster_offers(booster_type);
+        if offer_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(offer_mapper.get())
+        }
+    }
+
+    /// Expiry block of each booster type for a player; zero if never bought
+    #[view(getActiveBoosters)]
+    fn get_active_boosters(&self, player: &ManagedAddress) -> MultiValueEncoded<MultiValue2<BoosterType, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for booster_type in [
+            BoosterType::PointMultiplier,
+            BoosterType::ExtraEnergy,
+            BoosterType::ProposalFeeWaiver,
+        ] {
+            result.push((booster_type, self.active_boosters(player, booster_type).get()).into());
+        }
+        result
+    }
+
+    #[view(getFeeConfig)]
+    fn get_fee_config(&self) -> FeeConfig<Self::Api> {
+        self.game_config().get().fees
+    }
+
+    #[view(getProposalFee)]
+    fn get_proposal_fee(&self) -> BigUint {
+        self.current_proposal_fee()
+    }
+
+    #[view(getSuddenDeathConfig)]
+    fn get_sudden_death_config(&self) -> SuddenDeathConfig {
+        self.game_config().get().sudden_death
+    }
+
+    #[view(getSeasonLeader)]
+    fn get_season_leader(&self, season_id: OptionalValue<u32>) -> OptionalValue<ManagedAddress> {
+        let season_id = self.resolve_season(season_id);
+        let leader_mapper = self.season_leader(season_id);
+        if leader_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(leader_mapper.get())
+        }
+    }
+
+    /// Whether a proposal passed, once it has been executed or closed; for settling external
+    /// prediction markets
+    #[view(getProposalOutcome)]
+    fn get_proposal_outcome(&self, season_id: u32, proposal_id: u32) -> OptionalValue<bool> {
+        if !self.proposal_exists(season_id, proposal_id)
+            || self.proposal_execution_block(season_id, proposal_id).is_empty()
+        {
+            return OptionalValue::None;
+        }
+
+        let status = self.load_proposal(season_id, proposal_id).status;
+        OptionalValue::Some(status == ProposalStatus::Passed || status == ProposalStatus::Funded)
+    }
+
+    /// Top-ranked player of a season, once it has ended; for settling external prediction markets
+    #[view(getSeasonWinner)]
+    fn get_season_winner(&self, season_id: u32) -> OptionalValue<ManagedAddress> {
+        if self.seasons(season_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let season = self.seasons(season_id).get();
+        if !season.archived && self.blockchain().get_block_nonce() <= season.end_block {
+            return OptionalValue::None;
+        }
+
+        match self.leaderboard(season_id).iter().next() {
+            Some(entry) => OptionalValue::Some(entry.player),
+            None => OptionalValue::None,
+        }
+    }
+
+    /// Cutoff block, total staked and frozen winner (zero address until frozen)
+    #[view(getBettingInfo)]
+    fn get_betting_info(&self, season_id: OptionalValue<u32>) -> MultiValue3<u64, BigUint, ManagedAddress> {
+        let season_id = self.resolve_season(season_id);
+        let winner_mapper = self.bet_winner(season_id);
+        let winner = if winner_mapper.is_empty() {
+            ManagedAddress::zero()
+        } else {
+            winner_mapper.get()
+        };
+        (
+            self.betting_cutoff_block(season_id).get(),
+            self.bets_total(season_id).get(),
+            winner,
+        )
+            .into()
+    }
+
+    #[view(getBetsOnCandidate)]
+    fn get_bets_on_candidate(&self, candidate: &ManagedAddress, season_id: OptionalValue<u32>) -> BigUint {
+        let season_id = self.resolve_season(season_id);
+        self.bets_on_candidate(season_id, candidate).get()
+    }
+
+    #[view(getSeasonThemes)]
+    fn get_season_themes(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValue2<ManagedVec<SeasonThemeEntry<Self::Api>>, usize> {
+        let theme_ids = self.theme_ids();
+        let end = self.page_end(offset, limit, theme_ids.len());
+
+        let mut themes = ManagedVec::new();
+        for index in offset..end {
+            let theme_id = theme_ids.get_by_index(index + 1);
+            themes.push(SeasonThemeEntry {
+                theme_id,
+                theme: self.season_themes(theme_id).get(),
+            });
+        }
+        (themes, self.next_page(end, theme_ids.len())).into()
+    }
+
+    /// Theme a season was started from; zero for manually configured seasons
+    #[view(getSeasonTheme)]
+    fn get_season_theme(&self, season_id: OptionalValue<u32>) -> u32 {
+        let season_id = self.resolve_season(season_id);
+        self.season_theme(season_id).get()
+    }
+
+    /// Quorum (minimum total stake) and pass threshold (bps of stake in favour) at current participation
+    #[view(getCurrentDifficulty)]
+    fn get_current_difficulty(&self) -> MultiValue3<BigUint, u64, BigUint> {
+        (
+            self.current_quorum(),
+            self.current_pass_threshold_bps(),
+            self.current_proposal_fee(),
+        )
+            .into()
+    }
+
+    #[view(getDifficultyConfig)]
+    fn get_difficulty_config(&self) -> OptionalValue<DifficultyConfig<Self::Api>> {
+        let config_mapper = self.difficulty_config();
+        if config_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(config_mapper.get())
+        }
+    }
+
+    #[view(getFundingRound)]
+    fn get_funding_round(&self, round_id: OptionalValue<u32>) -> OptionalValue<FundingRound<Self::Api>> {
+        let round_id = round_id.into_option().unwrap_or_else(|| self.current_funding_round_id().get());
+        if self.funding_rounds(round_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.funding_rounds(round_id).get())
+    }
+
+    /// Total donations and current matching weight of a project
+    #[view(getProjectFunding)]
+    fn get_project_funding(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValue2<BigUint, BigUint> {
+        let season_id = self.resolve_season(season_id);
+        (
+            self.project_total(season_id, proposal_id).get(),
+            self.project_match_weight(season_id, proposal_id),
+        )
+            .into()
+    }
+
+    #[view(getRoundProjects)]
+    fn get_round_projects(
+        &self,
+        round_id: u32,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValue2<ManagedVec<u32>, usize> {
+        let projects = self.round_projects(round_id);
+        let end = self.page_end(offset, limit, projects.len());
+
+        let mut page = ManagedVec::new();
+        for index in offset..end {
+            page.push(projects.get(index + 1));
+        }
+        (page, self.next_page(end, projects.len())).into()
+    }
+
+    #[view(getProposalCategory)]
+    fn get_proposal_category(&self, proposal_id: u32, season_id: OptionalValue<u32>) -> ProposalCategory {
+        let season_id = self.resolve_season(season_id);
+        self.proposal_category(season_id, proposal_id).get()
+    }
+
+    /// Pending notifications of `player`, oldest first
+    #[view(getNotifications)]
+    fn get_notifications(
+        &self,
+        player: ManagedAddress,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValue2<ManagedVec<Notification>, usize> {
+        let queue = self.notifications(&player);
+        let end = self.page_end(offset, limit, queue.len());
+
+        let mut page = ManagedVec::new();
+        for notification in queue.iter().skip(offset).take(end.saturating_sub(offset)) {
+            page.push(notification);
+        }
+        (page, self.next_page(end, queue.len())).into()
+    }
+
+    /// Boosted proposals that have not expired, highest total boost first
+    #[view(getFeaturedProposals)]
+    fn get_featured_proposals(
+        &self,
+        season_id: OptionalValue<u32>,
+    ) -> MultiValueEncoded<FeaturedProposal<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        let current_block = self.blockchain().get_block_nonce();
+
+        let mut result = MultiValueEncoded::new();
+        for featured in self.featured_proposals(season_id).iter() {
+            if featured.expires_block >= current_block {
+                result.push(featured);
+            }
+        }
+        result
+    }
+
+    #[view(getContractVersion)]
+    fn get_contract_version(&self) -> u32 {
+        self.contract_version().get()
+    }
+
+    /// Position `migrateStorageBatch` resumes from, if a migration is in progress
+    #[view(getMigrationCursor)]
+    fn get_migration_cursor(&self) -> OptionalValue<MigrationCursor> {
+        if self.migration_cursor().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.migration_cursor().get())
+    }
+
+    #[view(getParameterChange)]
+    fn get_parameter_change(
+        &self,
+        proposal_id: u32,
+        season_id: OptionalValue<u32>,
+    ) -> OptionalValue<ParameterChange<Self::Api>> {
+        let season_id = self.resolve_season(season_id);
+        let parameter_mapper = self.parameter_changes(season_id, proposal_id);
+        if parameter_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(parameter_mapper.get())
+    }
+
+    #[view(getGameConfig)]
+    fn get_game_config(&self) -> GameConfig<Self::Api> {
+        self.game_config().get()
+    }
+
+    #[view(getConfig)]
+    fn get_config(&self) -> ConfigSnapshot<Self::Api> {
+        let power_up_token_id = if self.power_up_token_id().is_empty() {
+            None
+        } else {
+            Some(self.power_up_token_id().get())
+        };
+        let difficulty = if self.difficulty_config().is_empty() {
+            None
+        } else {
+            Some(self.difficulty_config().get())
+        };
+
+        ConfigSnapshot {
+            contract_version: self.contract_version().get(),
+            game_duration_blocks: self.game_duration_blocks().get(),
+            nft_reward_token_id: self.nft_reward_token_id().get(),
+            power_up_token_id,
+            whitelist_mode: self.whitelist_mode().get(),
+            difficulty,
+            game_config: self.game_config().get(),
+        }
+    }
+
+    /// Version, enabled features and compiled modules of this deployment
+    #[view(getContractInfo)]
+    fn get_contract_info(&self) -> ContractInfo<Self::Api> {
+        let game_config = self.game_config().get();
+        let features = ContractFeatures {
+            seasons: true,
+            commit_reveal_voting: game_config.reveal_duration_blocks > 0,
+            superposition_mode: game_config.superposition_mode,
+            quadratic_funding: true,
+            whitelist_mode: self.whitelist_mode().get(),
+            admin_multisig: !self.admin_multisig().is_empty(),
+            league_linked: !self.league_hub_address().is_empty(),
+            council_mode: game_config.council.enabled,
+        };
+
+        let mut modules = ManagedVec::new();
+        for module in CONTRACT_MODULES.iter() {
+            modules.push(ManagedBuffer::from(*module));
+        }
+
+        ContractInfo {
+            version: self.contract_version().get(),
+            game_config_version: game_config.version,
+            features,
+            modules,
+        }
+    }
+
+    #[view(getEmergencyWithdraw)]
+    fn get_emergency_withdraw(&self) -> OptionalValue<EmergencyWithdraw<Self::Api>> {
+        if self.emergency_withdraw().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.emergency_withdraw().get())
+        }
+    }
+
+    #[view(getMaintenanceWindows)]
+    fn get_maintenance_windows(&self) -> MultiValueEncoded<MaintenanceWindow> {
+        let mut result = MultiValueEncoded::new();
+        for window in self.maintenance_windows().iter() {
+            result.push(window);
+        }
+        result
+    }
+
+    #[view(isUnderMaintenance)]
+    fn is_under_maintenance_view(&self) -> bool {
+        self.is_under_maintenance()
+    }
+
+    #[view(getOwner)]
+    fn get_owner(&self) -> ManagedAddress {
+        self.game_owner().get()
+    }
+
+    #[view(getPendingOwner)]
+    fn get_pending_owner(&self) -> OptionalValue<ManagedAddress> {
+        if self.pending_owner().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.pending_owner().get())
+        }
+    }
+
+    #[view(getCouncilMembers)]
+    fn get_council_members(&self, offset: usize, limit: usize) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        self.address_page(&self.council_members(), offset, limit)
+    }
+
+    #[view(getCouncilTermEnd)]
+    fn get_council_term_end(&self) -> u64 {
+        self.council_term_end().get()
+    }
+
+    #[view(getCouncilConfig)]
+    fn get_council_config(&self) -> CouncilConfig {
+        self.game_config().get().council
+    }
+
+    #[view(getCouncilElection)]
+    fn get_council_election(&self) -> OptionalValue<CouncilElection> {
+        if self.council_election().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.council_election().get())
+    }
+
+    /// Candidates of the open election with their vote counts
+    #[view(getCouncilCandidates)]
+    fn get_council_candidates(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut result = MultiValueEncoded::new();
+        if self.council_election().is_empty() {
+            return result;
+        }
+
+        let election = self.council_election().get();
+        for candidate in self
+            .council_candidates(election.season_id, election.proposal_id)
+            .iter()
+        {
+            let votes = self.council_votes(election.season_id, election.proposal_id, &candidate).get();
+            result.push((candidate, votes).into());
+        }
+        result
+    }
+
+    /// Gas recorded for `endpoint`; always empty unless built with `gas-instrumentation`
+    #[view(getGasCounters)]
+    fn get_gas_counters(&self, endpoint: ManagedBuffer) -> GasCounters {
+        self.gas_counters(&endpoint).get()
+    }
+
+    /// Endpoints with recorded gas counters
+    #[view(getInstrumentedEndpoints)]
+    fn get_instrumented_endpoints(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.instrumented_endpoints().iter().collect()
+    }
+
+    // Private functions
+    fn proposal_voter_count(&self, season_id: u32, proposal_id: u32) -> usize {
+        self.proposal_voters(season_id, proposal_id).len() + self.pruned_voter_count(season_id, proposal_id).get()
+    }
+
+    fn season_info(&self, season_id: u32) -> SeasonInfo<Self::Api> {
+        let season = self.seasons(season_id).get();
+        let ended = season.archived || self.blockchain().get_block_nonce() > season.end_block;
+
+        let mut winners = ManagedVec::new();
+        if ended {
+            for entry in self.leaderboard(season_id).iter().take(SEASON_WINNERS_SHOWN) {
+                winners.push(entry.player);
+            }
+        }
+
+        SeasonInfo {
+            season,
+            theme_id: self.season_theme(season_id).get(),
+            entry_fee: self.entry_fee(season_id).get(),
+            prize_pool: self.prize_pool(season_id).get(),
+            ended,
+            winners,
+        }
+    }
+
+    /// Claims of `address` that are not tied to a proposal: rewards of the season, plus quests
+    /// and grants vested in the vesting contract, which are only listed with the current season
+    fn push_season_claims(
+        &self,
+        address: &ManagedAddress,
+        season_id: u32,
+        current_season_id: u32,
+        result: &mut MultiValueEncoded<ClaimableReward<Self::Api>>,
+    ) {
+        if season_id == current_season_id {
+            for (index, quest) in self.quests(season_id).iter().enumerate() {
+                let quest_id = index + 1;
+                if quest.claims < quest.max_claims
+                    && self.quest_progress(season_id, quest_id, address).get() >= quest.target
+                    && !self.quest_claimed(season_id, quest_id).contains(address)
+                {
+                    result.push(ClaimableReward {
+                        kind: ClaimKind::QuestReward,
+                        season_id,
+                        reference: quest_id as u64,
+                        token: quest.reward_token,
+                        amount: quest.reward_amount,
+                    });
+                }
+            }
+
+
+            let vesting_mapper = self.vesting_address();
+            if !vesting_mapper.is_empty() {
+                let grants = self
+                    .vesting_proxy(vesting_mapper.get())
+                    .get_claimable_grants(address.clone())
+                    .execute_on_dest_context::<MultiValueEncoded<MultiValue3<u64, EgldOrEsdtTokenIdentifier, BigUint>>>();
+                for grant in grants {
+                    let (grant_id, token, amount) = grant.into_tuple();
+                    result.push(ClaimableReward {
+                        kind: ClaimKind::Vested,
+                        season_id,
+                        reference: grant_id,
+                        token,
+                        amount,
+                    });
+                }
+            }
+        }
+
+        let season_ended = season_id < current_season_id || !self.is_game_active();
+        if season_ended
+            && self.nft_claimed(season_id, self.player_id(address)).is_empty()
+            && self.dao_scores(season_id, self.player_id(address)).get() > 0
+            && self.is_eligible_for_reward(season_id, address)
+        {
+            result.push(ClaimableReward {
+                kind: ClaimKind::NftReward,
+                season_id,
+                reference: 0,
+                token: EgldOrEsdtTokenIdentifier::esdt(self.nft_reward_token_id().get()),
+                amount: BigUint::from(1u32),
+            });
+        }
+
+        if season_ended
+            && self.is_betting_settled(season_id)
+            && self.joined_players(season_id).contains(address)
+            && !self.prize_claimed(season_id).contains(address)
+            && !self.prize_pool_swept(season_id).get()
+            && self.dao_scores(season_id, self.player_id(address)).get() > 0
+        {
+            result.push(ClaimableReward {
+                kind: ClaimKind::Prize,
+                season_id,
+                reference: 0,
+                token: EgldOrEsdtTokenIdentifier::egld(),
+                amount: self.prize_share(season_id, address),
+            });
+        }
+
+        let bet_mapper = self.season_bets(season_id, address);
+        if !bet_mapper.is_empty() && !self.bet_winner(season_id).is_empty() {
+            result.push(ClaimableReward {
+                kind: ClaimKind::Bet,
+                season_id,
+                reference: 0,
+                token: EgldOrEsdtTokenIdentifier::egld(),
+                amount: self.bet_payout(season_id, &bet_mapper.get()),
+            });
+        }
+    }
+
+    /// Offset of the page after one ending at `end`, or 0 once a list of `len` items is exhausted
+    fn page_end(&self, offset: usize, limit: usize, len: usize) -> usize {
+        core::cmp::min(offset.saturating_add(self.iteration_limit(limit)), len)
+    }
+
+    fn address_page(
+        &self,
+        mapper: &UnorderedSetMapper<ManagedAddress>,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValue2<ManagedVec<ManagedAddress>, usize> {
+        let end = self.page_end(offset, limit, mapper.len());
+
+        let mut addresses = ManagedVec::new();
+        for index in offset..end {
+            addresses.push(mapper.get_by_index(index + 1));
+        }
+        (addresses, self.next_page(end, mapper.len())).into()
+    }
+
+    fn next_page(&self, end: usize, len: usize) -> usize {
+        if end < len {
+            end
+        } else {
+            0
+        }
+    }
+
+    fn time_remaining(&self, end_block: u64) -> TimeRemaining {
+        let blocks_remaining = end_block.saturating_sub(self.blockchain().get_block_nonce());
+        TimeRemaining {
+            end_block,
+            blocks_remaining,
+            estimated_seconds: blocks_remaining * ESTIMATED_SECONDS_PER_BLOCK,
+        }
+    }
+}
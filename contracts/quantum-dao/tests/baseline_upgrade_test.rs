@@ -0,0 +1,292 @@
+use multiversx_sc::types::{Address, BigUint, ManagedBuffer, MultiValueEncoded, TokenIdentifier};
+use multiversx_sc_scenario::{managed_address, scenario_model::*, *};
+use quantum_dao::{
+    admin::AdminModule, constants::*, governance::GovernanceModule, migration::MigrationModule,
+    storage::StorageModule, types::*, QuantumDaoGame,
+};
+
+const QUANTUM_DAO_PATH_EXPR: &str = "file:output/quantum-dao.wasm";
+const OWNER: &str = "address:owner";
+const ALICE: &str = "address:alice";
+const BOB: &str = "address:bob";
+const GAME: &str = "sc:quantum-dao";
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+const GAME_START_BLOCK: u64 = 1;
+const GAME_DURATION_BLOCKS: u64 = 1_000;
+const UPGRADE_BLOCK: u64 = 200;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("quantum-dao");
+    world.register_contract(QUANTUM_DAO_PATH_EXPR, quantum_dao::ContractBuilder);
+    world
+}
+
+fn address(expr: &str) -> Address {
+    AddressValue::from(expr).to_address()
+}
+
+/// A game account holding storage exactly as the original single-season contract wrote it:
+/// Alice created proposal 1, which passed and was executed on Alice's 2 EGLD vote; Bob created
+/// proposal 2 and voted 1 EGLD against it, so it never passed. Alice has claimed her NFT.
+fn deploy_baseline(
+    world: &mut ScenarioWorld,
+    game: &WhiteboxContract<quantum_dao::ContractObj<DebugApi>>,
+) {
+    let code = world.code_expression(QUANTUM_DAO_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1))
+            .put_account(ALICE, Account::new().nonce(1).balance(10 * ONE_EGLD))
+            .put_account(BOB, Account::new().nonce(1).balance(10 * ONE_EGLD))
+            .put_account(
+                GAME,
+                Account::new().code(code).owner(OWNER).balance(3 * ONE_EGLD),
+            )
+            .current_block(BlockInfo::new().block_nonce(UPGRADE_BLOCK)),
+    );
+
+    world.whitebox_call(game, ScCallStep::new().from(OWNER), |sc| {
+        let alice = managed_address!(&address(ALICE));
+        let bob = managed_address!(&address(BOB));
+
+        sc.game_duration_blocks().set(GAME_DURATION_BLOCKS);
+        sc.game_start_block().set(GAME_START_BLOCK);
+        sc.nft_reward_token_id()
+            .set(TokenIdentifier::from("QDAO-123456"));
+        sc.baseline_current_proposal_id().set(3u32);
+
+        sc.baseline_proposals(1).set(LegacyProposal {
+            id: 1,
+            creator: alice.clone(),
+            title: ManagedBuffer::from("Fund the guild"),
+            description: ManagedBuffer::new(),
+            votes_for: BigUint::from(2 * ONE_EGLD),
+            votes_against: BigUint::zero(),
+            start_block: 10,
+            end_block: 100,
+            executed: true,
+        });
+        sc.baseline_proposals(2).set(LegacyProposal {
+            id: 2,
+            creator: bob.clone(),
+            title: ManagedBuffer::from("Double the rewards"),
+            description: ManagedBuffer::new(),
+            votes_for: BigUint::zero(),
+            votes_against: BigUint::from(ONE_EGLD),
+            start_block: 20,
+            end_block: 120,
+            executed: false,
+        });
+
+        sc.baseline_user_votes(1, &alice).set(Vote {
+            voter: alice.clone(),
+            proposal_id: 1,
+            vote_for: true,
+            stake_amount: BigUint::from(2 * ONE_EGLD),
+            block_number: 50,
+        });
+        sc.baseline_user_votes(2, &bob).set(Vote {
+            voter: bob.clone(),
+            proposal_id: 2,
+            vote_for: false,
+            stake_amount: BigUint::from(ONE_EGLD),
+            block_number: 60,
+        });
+
+        // Creation (10), a 2 EGLD vote (4) and the executed proposal bonus (50)
+        sc.baseline_dao_scores(&alice).set(64u64);
+        // Creation (10) and a 1 EGLD vote (2)
+        sc.baseline_dao_scores(&bob).set(12u64);
+        sc.baseline_nft_claimed(&alice).set(true);
+    });
+}
+
+fn migrate_batch(
+    world: &mut ScenarioWorld,
+    game: &WhiteboxContract<quantum_dao::ContractObj<DebugApi>>,
+) {
+    world.whitebox_call(game, ScCallStep::new().from(OWNER), |sc| {
+        sc.migrate_storage_batch(MAX_ITERATIONS_PER_CALL);
+    });
+}
+
+fn migrate_votes(
+    world: &mut ScenarioWorld,
+    game: &WhiteboxContract<quantum_dao::ContractObj<DebugApi>>,
+    proposal_id: u32,
+    voters: &[&str],
+) {
+    let voters: Vec<Address> = voters.iter().map(|voter| address(voter)).collect();
+    world.whitebox_call(game, ScCallStep::new().from(OWNER), |sc| {
+        let mut encoded = MultiValueEncoded::new();
+        for voter in voters.iter() {
+            encoded.push(managed_address!(voter));
+        }
+        sc.migrate_baseline_votes(proposal_id, encoded);
+    });
+}
+
+#[test]
+fn upgrade_opens_season_one_and_continues_proposal_ids() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    deploy_baseline(&mut world, &game);
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+
+    world.whitebox_query(&game, |sc| {
+        assert_eq!(sc.current_season_id().get(), BASELINE_SEASON_ID);
+        assert_eq!(sc.current_proposal_id(BASELINE_SEASON_ID).get(), 3);
+        assert!(sc.paused().get());
+        assert!(!sc.migration_cursor().is_empty());
+
+        let season = sc.seasons(BASELINE_SEASON_ID).get();
+        assert_eq!(season.start_block, GAME_START_BLOCK);
+        assert_eq!(season.end_block, GAME_START_BLOCK + GAME_DURATION_BLOCKS);
+    });
+}
+
+#[test]
+fn baseline_proposals_scores_and_votes_readable_after_migration() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    deploy_baseline(&mut world, &game);
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+    migrate_batch(&mut world, &game);
+
+    // Proposals are in, but scores wait for every vote
+    world.whitebox_query(&game, |sc| {
+        assert!(sc.proposal_exists(BASELINE_SEASON_ID, 1));
+        assert!(sc.proposal_exists(BASELINE_SEASON_ID, 2));
+        assert_eq!(sc.baseline_proposals_awaiting_votes().get(), 2);
+        assert!(!sc.migration_cursor().is_empty());
+    });
+
+    // Bob cast no vote on proposal 1, so he is skipped
+    migrate_votes(&mut world, &game, 1, &[ALICE, BOB]);
+    migrate_votes(&mut world, &game, 2, &[BOB]);
+    migrate_batch(&mut world, &game);
+
+    world.whitebox_query(&game, |sc| {
+        let season_id = BASELINE_SEASON_ID;
+        let alice = managed_address!(&address(ALICE));
+        let bob = managed_address!(&address(BOB));
+        assert!(sc.migration_cursor().is_empty());
+        assert_eq!(sc.contract_version().get(), CONTRACT_VERSION);
+
+        let passed = sc.load_proposal(season_id, 1);
+        assert_eq!(passed.creator, alice);
+        assert_eq!(passed.votes_for, BigUint::from(2 * ONE_EGLD));
+        assert_eq!(passed.status, ProposalStatus::Passed);
+        assert_eq!(sc.proposal_execution_block(season_id, 1).get(), 100);
+
+        let open = sc.load_proposal(season_id, 2);
+        assert_eq!(open.creator, bob);
+        assert_eq!(open.status, ProposalStatus::Active);
+        assert!(sc.active_proposals(season_id).contains(&2));
+
+        let alice_id = sc.player_id(&alice);
+        let bob_id = sc.player_id(&bob);
+        let alice_vote = sc.user_votes(season_id, 1, alice_id).get();
+        assert!(alice_vote.vote_for());
+        assert_eq!(alice_vote.stake_amount, BigUint::from(2 * ONE_EGLD));
+        assert_eq!(alice_vote.block_number, 50);
+        let bob_vote = sc.user_votes(season_id, 2, bob_id).get();
+        assert!(!bob_vote.vote_for());
+        assert!(sc.user_votes(season_id, 1, bob_id).is_empty());
+        assert_eq!(
+            sc.proposal_stake(season_id, 1).get(),
+            BigUint::from(2 * ONE_EGLD)
+        );
+        assert_eq!(sc.total_staked().get(), BigUint::from(3 * ONE_EGLD));
+
+        assert_eq!(sc.dao_scores(season_id, alice_id).get(), 64);
+        assert_eq!(sc.dao_scores(season_id, bob_id).get(), 12);
+        assert_eq!(sc.total_score(season_id).get(), 76);
+        assert_eq!(sc.leaderboard(season_id).get(1).player, alice);
+        assert_eq!(sc.leaderboard_position(season_id, bob_id).get(), 2);
+        assert!(sc.nft_claimed(season_id, alice_id).get());
+        assert!(!sc.nft_claimed(season_id, bob_id).get());
+
+        assert!(sc.baseline_current_proposal_id().is_empty());
+        assert!(sc.baseline_proposals(1).is_empty());
+        assert!(sc.baseline_user_votes(1, &alice).is_empty());
+        assert!(sc.baseline_dao_scores(&alice).is_empty());
+    });
+}
+
+#[test]
+fn migration_waits_for_unmatched_stake() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    deploy_baseline(&mut world, &game);
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+    migrate_batch(&mut world, &game);
+    migrate_votes(&mut world, &game, 1, &[ALICE]);
+    migrate_batch(&mut world, &game);
+
+    // Bob's vote on proposal 2 is still missing
+    world.whitebox_query(&game, |sc| {
+        assert_eq!(sc.baseline_proposals_awaiting_votes().get(), 1);
+        assert_eq!(
+            sc.baseline_unmigrated_stake(2).get(),
+            BigUint::from(ONE_EGLD)
+        );
+        assert!(!sc.migration_cursor().is_empty());
+        assert_eq!(sc.migration_cursor().get().player_index, 0);
+    });
+}
+
+#[test]
+fn new_proposals_continue_baseline_ids() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    deploy_baseline(&mut world, &game);
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+    migrate_batch(&mut world, &game);
+    migrate_votes(&mut world, &game, 1, &[ALICE]);
+    migrate_votes(&mut world, &game, 2, &[BOB]);
+    migrate_batch(&mut world, &game);
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.unpause());
+
+    world.whitebox_call(&game, ScCallStep::new().from(ALICE), |sc| {
+        sc.create_proposal(ManagedBuffer::from("Third"), ManagedBuffer::new(), 100);
+    });
+
+    world.whitebox_query(&game, |sc| {
+        let proposal = sc.load_proposal(BASELINE_SEASON_ID, 3);
+        assert_eq!(proposal.id, 3);
+        assert_eq!(proposal.creator, managed_address!(&address(ALICE)));
+        assert_eq!(sc.current_proposal_id(BASELINE_SEASON_ID).get(), 4);
+        assert!(!sc.proposal_exists(0, 1));
+    });
+}
+
+#[test]
+fn already_current_layout_upgrade_keeps_state() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    let code = world.code_expression(QUANTUM_DAO_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1))
+            .new_address(OWNER, 1, GAME),
+    );
+    world.whitebox_deploy(&game, ScDeployStep::new().from(OWNER).code(code), |sc| {
+        sc.init(GAME_DURATION_BLOCKS, TokenIdentifier::from("QDAO-123456"));
+    });
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+
+    world.whitebox_query(&game, |sc| {
+        assert_eq!(sc.current_season_id().get(), 1);
+        assert_eq!(sc.current_proposal_id(1).get(), 1);
+        assert!(sc.migration_cursor().is_empty());
+        assert!(!sc.paused().get());
+    });
+}
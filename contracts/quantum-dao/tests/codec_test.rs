@@ -0,0 +1,209 @@
+use multiversx_sc::{
+    codec::{
+        test_util::{check_top_decode, check_top_encode, check_top_encode_decode},
+        TopDecode,
+    },
+    derive_imports::*,
+    types::{BigUint, ManagedAddress, ManagedBuffer},
+};
+use multiversx_sc_scenario::api::StaticApi;
+use quantum_dao::{constants::*, types::*};
+
+fn voter() -> ManagedAddress<StaticApi> {
+    ManagedAddress::from([7u8; 32])
+}
+
+fn legacy_proposal(executed: bool) -> LegacyProposal<StaticApi> {
+    LegacyProposal {
+        id: 3,
+        creator: voter(),
+        title: ManagedBuffer::from("Title"),
+        description: ManagedBuffer::new(),
+        votes_for: BigUint::from(2_000u64),
+        votes_against: BigUint::from(1_000u64),
+        start_block: 10,
+        end_block: 100,
+        executed,
+    }
+}
+
+/// `MigrationCursor` as stored before votes were found through players
+#[derive(TopEncode, TopDecode)]
+struct MigrationCursorV6 {
+    season_id: u32,
+    proposal_id: u32,
+    voter_index: usize,
+    player_index: usize,
+}
+
+#[test]
+fn vote_record_layout() {
+    let record = VoteRecord::<StaticApi>::new(true, false, BigUint::from(1_000u64), 0x10);
+
+    let mut expected = vec![VOTE_FLAG_FOR];
+    expected.extend_from_slice(&0x10u64.to_be_bytes());
+    // The stake comes last but is still length prefixed, as nested in the struct
+    expected.extend_from_slice(&[0, 0, 0, 2, 0x03, 0xe8]);
+    check_top_encode_decode(record, &expected);
+}
+
+#[test]
+fn vote_record_flags_round_trip() {
+    for (vote_for, hidden) in [(false, false), (true, false), (false, true), (true, true)] {
+        let record = VoteRecord::<StaticApi>::new(vote_for, hidden, BigUint::from(5u64), 42);
+        let decoded: VoteRecord<StaticApi> = check_top_decode(&check_top_encode(&record));
+        assert_eq!(decoded, record);
+        assert_eq!(decoded.vote_for(), vote_for);
+        let expected_choice = match (hidden, vote_for) {
+            (true, _) => VoteChoice::Hidden,
+            (false, true) => VoteChoice::For,
+            (false, false) => VoteChoice::Against,
+        };
+        assert_eq!(decoded.choice(), expected_choice);
+    }
+}
+
+#[test]
+fn revealed_record_drops_the_hidden_flag() {
+    let mut record = VoteRecord::<StaticApi>::new(false, true, BigUint::from(5u64), 42);
+    record.reveal(true);
+    assert_eq!(record.flags, VOTE_FLAG_FOR);
+    assert_eq!(record.choice(), VoteChoice::For);
+}
+
+#[test]
+fn full_vote_converts_to_record_and_back() {
+    let vote = Vote {
+        voter: voter(),
+        proposal_id: 9,
+        vote_for: true,
+        stake_amount: BigUint::<StaticApi>::from(1_000u64),
+        block_number: 77,
+    };
+    let encoded = check_top_encode(&vote);
+    let decoded: Vote<StaticApi> = check_top_decode(&encoded);
+    assert_eq!(decoded, vote);
+
+    let record = VoteRecord::new(
+        decoded.vote_for,
+        false,
+        decoded.stake_amount.clone(),
+        decoded.block_number,
+    );
+    assert_eq!(record.into_vote(voter(), 9), vote);
+}
+
+#[test]
+fn full_vote_does_not_decode_as_record() {
+    let vote = Vote {
+        voter: voter(),
+        proposal_id: 9,
+        vote_for: true,
+        stake_amount: BigUint::<StaticApi>::from(1_000u64),
+        block_number: 77,
+    };
+    let encoded = check_top_encode(&vote);
+    assert!(VoteRecord::<StaticApi>::top_decode(&encoded[..]).is_err());
+}
+
+#[test]
+fn legacy_proposal_round_trip() {
+    for executed in [false, true] {
+        let proposal = legacy_proposal(executed);
+        let decoded: LegacyProposal<StaticApi> = check_top_decode(&check_top_encode(&proposal));
+        assert_eq!(decoded, proposal);
+    }
+}
+
+/// The original `executed` flag shares its byte with the status discriminant, so only
+/// `Active` and `Passed` can come out of a legacy proposal read with the current layout
+#[test]
+fn legacy_proposal_reads_as_active_or_passed() {
+    for (executed, status) in [
+        (false, ProposalStatus::Active),
+        (true, ProposalStatus::Passed),
+    ] {
+        let encoded = check_top_encode(&legacy_proposal(executed));
+        let decoded: Proposal<StaticApi> = check_top_decode(&encoded);
+        assert_eq!(decoded.id, 3);
+        assert_eq!(decoded.votes_for, BigUint::from(2_000u64));
+        assert_eq!(decoded.end_block, 100);
+        assert_eq!(decoded.status, status);
+    }
+}
+
+#[test]
+fn proposal_info_and_tally_round_trip() {
+    let info = ProposalInfo {
+        id: 3,
+        creator: voter(),
+        title: ManagedBuffer::<StaticApi>::from("Title"),
+        description: ManagedBuffer::from("Description"),
+        start_block: 10,
+        end_block: 100,
+    };
+    let decoded: ProposalInfo<StaticApi> = check_top_decode(&check_top_encode(&info));
+    assert_eq!(decoded, info);
+
+    let tally = ProposalTally {
+        votes_for: BigUint::<StaticApi>::from(2_000u64),
+        votes_against: BigUint::zero(),
+        status: ProposalStatus::Funded,
+    };
+    let decoded: ProposalTally<StaticApi> = check_top_decode(&check_top_encode(&tally));
+    assert_eq!(decoded, tally);
+}
+
+#[test]
+fn migration_cursor_reads_the_previous_layout() {
+    let old_cursor = MigrationCursorV6 {
+        season_id: 2,
+        proposal_id: 5,
+        voter_index: 3,
+        player_index: 4,
+    };
+    let cursor: MigrationCursor = check_top_decode(&check_top_encode(&old_cursor));
+    assert_eq!(cursor.season_id, 2);
+    assert_eq!(cursor.proposal_id, 5);
+    assert_eq!(cursor.player_proposal_id, 3);
+    assert_eq!(cursor.player_index, 4);
+}
+
+#[test]
+fn game_config_v1_converts() {
+    let current = GameConfig::<StaticApi>::default();
+    let v1 = GameConfigV1 {
+        version: 1,
+        scoring: current.scoring.clone(),
+        quantum_events: current.quantum_events.clone(),
+        check_in: current.check_in.clone(),
+        energy: current.energy.clone(),
+        entanglement: current.entanglement.clone(),
+        sudden_death: current.sudden_death.clone(),
+        fees: current.fees.clone(),
+        superposition_mode: true,
+        reveal_duration_blocks: 20,
+        boost_duration_blocks: current.boost_duration_blocks,
+        max_game_extension_blocks: 30,
+    };
+    let encoded = check_top_encode(&v1);
+    assert_eq!(encoded[0], 1);
+    assert!(GameConfig::<StaticApi>::top_decode(&encoded[..]).is_err());
+
+    let converted: GameConfig<StaticApi> =
+        check_top_decode::<GameConfigV1<StaticApi>>(&encoded).into();
+    assert_eq!(converted.version, GAME_CONFIG_VERSION);
+    assert!(converted.superposition_mode);
+    assert_eq!(converted.reveal_duration_blocks, 20);
+    assert_eq!(converted.max_game_extension_blocks, 30);
+    assert_eq!(converted.council, current.council);
+}
+
+#[test]
+fn game_config_round_trip() {
+    let config = GameConfig::<StaticApi>::default();
+    let encoded = check_top_encode(&config);
+    assert_eq!(encoded[0], GAME_CONFIG_VERSION);
+    let decoded: GameConfig<StaticApi> = check_top_decode(&encoded);
+    assert_eq!(decoded, config);
+}
@@ -0,0 +1,121 @@
+use multiversx_sc::types::{Address, BigUint, ManagedBuffer, TokenIdentifier};
+use multiversx_sc_scenario::{managed_address, scenario_model::*, *};
+use quantum_dao::{
+    admin::AdminModule, constants::*, migration::MigrationModule, storage::StorageModule, types::*,
+    QuantumDaoGame,
+};
+
+const QUANTUM_DAO_PATH_EXPR: &str = "file:output/quantum-dao.wasm";
+const OWNER: &str = "address:owner";
+const ALICE: &str = "address:alice";
+const BOB: &str = "address:bob";
+const GAME: &str = "sc:quantum-dao";
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("quantum-dao");
+    world.register_contract(QUANTUM_DAO_PATH_EXPR, quantum_dao::ContractBuilder);
+    world
+}
+
+fn address(expr: &str) -> Address {
+    AddressValue::from(expr).to_address()
+}
+
+/// Storage as contract version 4 wrote it: whole proposals per season, address-keyed votes and
+/// scores, and no voter list per proposal. Alice and Bob both voted on proposal 1; only Bob
+/// voted on proposal 2.
+#[test]
+fn version_four_votes_found_through_players() {
+    let mut world = world();
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    let code = world.code_expression(QUANTUM_DAO_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1))
+            .put_account(
+                GAME,
+                Account::new().code(code).owner(OWNER).balance(4 * ONE_EGLD),
+            )
+            .current_block(BlockInfo::new().block_nonce(200)),
+    );
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| {
+        let alice = managed_address!(&address(ALICE));
+        let bob = managed_address!(&address(BOB));
+        let season_id = 1u32;
+
+        sc.game_duration_blocks().set(1_000u64);
+        sc.game_start_block().set(1u64);
+        sc.nft_reward_token_id()
+            .set(TokenIdentifier::from("QDAO-123456"));
+        sc.contract_version().set(4u32);
+        sc.current_season_id().set(season_id);
+        sc.current_proposal_id(season_id).set(3u32);
+
+        for (proposal_id, creator) in [(1u32, &alice), (2u32, &bob)] {
+            sc.season_proposals(season_id).push(&Proposal {
+                id: proposal_id,
+                creator: creator.clone(),
+                title: ManagedBuffer::from("Proposal"),
+                description: ManagedBuffer::new(),
+                votes_for: BigUint::from(ONE_EGLD),
+                votes_against: BigUint::from(ONE_EGLD),
+                start_block: 10,
+                end_block: 100,
+                status: ProposalStatus::Active,
+            });
+        }
+
+        let votes = [
+            (1u32, &alice, true),
+            (1u32, &bob, false),
+            (2u32, &bob, true),
+        ];
+        for (proposal_id, voter, vote_for) in votes {
+            sc.legacy_user_votes(season_id, proposal_id, voter)
+                .set(Vote {
+                    voter: voter.clone(),
+                    proposal_id,
+                    vote_for,
+                    stake_amount: BigUint::from(ONE_EGLD),
+                    block_number: 50,
+                });
+        }
+
+        sc.players(season_id).insert(alice.clone());
+        sc.players(season_id).insert(bob.clone());
+        sc.legacy_dao_scores(season_id, &alice).set(30u64);
+        sc.legacy_dao_scores(season_id, &bob).set(20u64);
+        sc.legacy_top_players(season_id).push(&alice);
+        sc.legacy_top_players(season_id).push(&bob);
+    });
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| sc.upgrade());
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| {
+        sc.migrate_storage_batch(MAX_ITERATIONS_PER_CALL);
+    });
+
+    world.whitebox_query(&game, |sc| {
+        let season_id = 1u32;
+        let alice = managed_address!(&address(ALICE));
+        let bob = managed_address!(&address(BOB));
+        assert!(sc.migration_cursor().is_empty());
+        assert_eq!(sc.contract_version().get(), CONTRACT_VERSION);
+        assert_eq!(sc.load_proposal(season_id, 2).creator, bob);
+
+        let alice_id = sc.player_id(&alice);
+        let bob_id = sc.player_id(&bob);
+        assert!(sc.user_votes(season_id, 1, alice_id).get().vote_for());
+        assert!(!sc.user_votes(season_id, 1, bob_id).get().vote_for());
+        assert!(sc.user_votes(season_id, 2, bob_id).get().vote_for());
+        assert!(sc.user_votes(season_id, 2, alice_id).is_empty());
+        assert!(sc.legacy_user_votes(season_id, 1, &alice).is_empty());
+
+        assert_eq!(sc.dao_scores(season_id, alice_id).get(), 30);
+        assert_eq!(sc.dao_scores(season_id, bob_id).get(), 20);
+        assert_eq!(sc.leaderboard_position(season_id, bob_id).get(), 2);
+    });
+}
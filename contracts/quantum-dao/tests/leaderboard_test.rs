@@ -0,0 +1,155 @@
+use multiversx_sc::types::{Address, TokenIdentifier};
+use multiversx_sc_scenario::{managed_address, scenario_model::*, *};
+use quantum_dao::{
+    admin::AdminModule, constants::*, scoring::ScoringModule, storage::StorageModule,
+    QuantumDaoGame,
+};
+
+const QUANTUM_DAO_PATH_EXPR: &str = "file:output/quantum-dao.wasm";
+const OWNER: &str = "address:owner";
+const GAME: &str = "sc:quantum-dao";
+const SEASON_ID: u32 = 1;
+
+type GameContract = WhiteboxContract<quantum_dao::ContractObj<DebugApi>>;
+
+fn setup() -> (ScenarioWorld, GameContract) {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("quantum-dao");
+    world.register_contract(QUANTUM_DAO_PATH_EXPR, quantum_dao::ContractBuilder);
+
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    let code = world.code_expression(QUANTUM_DAO_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1))
+            .new_address(OWNER, 1, GAME),
+    );
+    world.whitebox_deploy(&game, ScDeployStep::new().from(OWNER).code(code), |sc| {
+        sc.init(1_000, TokenIdentifier::from("QDAO-123456"));
+    });
+
+    (world, game)
+}
+
+fn player(index: usize) -> Address {
+    AddressValue::from(format!("address:player{index:03}").as_str()).to_address()
+}
+
+/// Credits `points` to each `(player index, points)` entry, in order
+fn credit(world: &mut ScenarioWorld, game: &GameContract, credits: &[(usize, u64)]) {
+    let credits: Vec<(Address, u64)> = credits
+        .iter()
+        .map(|(index, points)| (player(*index), *points))
+        .collect();
+    world.whitebox_call(game, ScCallStep::new().from(OWNER), |sc| {
+        for (address, points) in credits.iter() {
+            sc.credit_points(SEASON_ID, &managed_address!(address), *points);
+        }
+    });
+}
+
+/// Fills the leaderboard with players 1..=TOP_PLAYERS_SIZE, player `i` scoring `10 * i`
+fn fill_leaderboard(world: &mut ScenarioWorld, game: &GameContract) {
+    let credits: Vec<(usize, u64)> = (1..=TOP_PLAYERS_SIZE)
+        .map(|index| (index, 10 * index as u64))
+        .collect();
+    credit(world, game, &credits);
+}
+
+fn position(world: &mut ScenarioWorld, game: &GameContract, index: usize) -> usize {
+    let address = player(index);
+    let mut position = 0;
+    world.whitebox_query(game, |sc| {
+        let player_id = sc.player_id(&managed_address!(&address));
+        position = sc.leaderboard_position(SEASON_ID, player_id).get();
+    });
+    position
+}
+
+fn is_eligible(world: &mut ScenarioWorld, game: &GameContract, index: usize) -> bool {
+    let address = player(index);
+    let mut eligible = false;
+    world.whitebox_query(game, |sc| {
+        eligible = sc.is_eligible_for_reward(SEASON_ID, &managed_address!(&address));
+    });
+    eligible
+}
+
+#[test]
+fn players_enter_in_score_order() {
+    let (mut world, game) = setup();
+    credit(&mut world, &game, &[(1, 10), (2, 30), (3, 20)]);
+
+    assert_eq!(position(&mut world, &game, 2), 1);
+    assert_eq!(position(&mut world, &game, 3), 2);
+    assert_eq!(position(&mut world, &game, 1), 3);
+
+    credit(&mut world, &game, &[(1, 25)]);
+    assert_eq!(position(&mut world, &game, 1), 1);
+    assert_eq!(position(&mut world, &game, 2), 2);
+    assert_eq!(position(&mut world, &game, 3), 3);
+}
+
+#[test]
+fn newcomer_past_the_cutoff_evicts_the_last_player() {
+    let (mut world, game) = setup();
+    fill_leaderboard(&mut world, &game);
+    assert_eq!(position(&mut world, &game, 1), TOP_PLAYERS_SIZE);
+
+    let newcomer = TOP_PLAYERS_SIZE + 1;
+    credit(&mut world, &game, &[(newcomer, 15)]);
+
+    assert_eq!(position(&mut world, &game, 1), 0);
+    assert_eq!(position(&mut world, &game, newcomer), TOP_PLAYERS_SIZE);
+    world.whitebox_query(&game, |sc| {
+        assert_eq!(sc.leaderboard(SEASON_ID).len(), TOP_PLAYERS_SIZE);
+    });
+}
+
+#[test]
+fn newcomer_tied_with_the_cutoff_stays_out() {
+    let (mut world, game) = setup();
+    fill_leaderboard(&mut world, &game);
+
+    let newcomer = TOP_PLAYERS_SIZE + 1;
+    credit(&mut world, &game, &[(newcomer, 10)]);
+
+    assert_eq!(position(&mut world, &game, newcomer), 0);
+    assert_eq!(position(&mut world, &game, 1), TOP_PLAYERS_SIZE);
+}
+
+#[test]
+fn reward_goes_to_the_top_players_only() {
+    let (mut world, game) = setup();
+    fill_leaderboard(&mut world, &game);
+
+    // Player `i` ranks `TOP_PLAYERS_SIZE + 1 - i`
+    let last_winner = TOP_PLAYERS_SIZE + 1 - NFT_REWARD_WINNERS;
+    assert!(is_eligible(&mut world, &game, TOP_PLAYERS_SIZE));
+    assert!(is_eligible(&mut world, &game, last_winner));
+    assert!(!is_eligible(&mut world, &game, last_winner - 1));
+    assert!(!is_eligible(&mut world, &game, TOP_PLAYERS_SIZE + 1));
+
+    // Overtaking the last winner pushes them out of the rewarded places
+    credit(&mut world, &game, &[(last_winner - 1, 11)]);
+    assert!(is_eligible(&mut world, &game, last_winner - 1));
+    assert!(!is_eligible(&mut world, &game, last_winner));
+}
+
+#[test]
+fn tie_at_the_reward_cutoff_goes_to_the_first_to_reach_it() {
+    let (mut world, game) = setup();
+    fill_leaderboard(&mut world, &game);
+
+    // Matching the last winner's score is not enough to pass them
+    let last_winner = TOP_PLAYERS_SIZE + 1 - NFT_REWARD_WINNERS;
+    credit(&mut world, &game, &[(last_winner - 1, 10)]);
+
+    assert_eq!(position(&mut world, &game, last_winner), NFT_REWARD_WINNERS);
+    assert_eq!(
+        position(&mut world, &game, last_winner - 1),
+        NFT_REWARD_WINNERS + 1
+    );
+    assert!(is_eligible(&mut world, &game, last_winner));
+    assert!(!is_eligible(&mut world, &game, last_winner - 1));
+}
@@ -0,0 +1,28 @@
+//! Gas budgets of the hot path and of full batches, run on the Go VM, which meters gas.
+//! Each scenario sets its budget as the transaction `gasLimit`, so a regression past it fails.
+
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    ScenarioWorld::vm_go()
+}
+
+#[test]
+fn vote_gas_go() {
+    world().run("scenarios/vote_gas.scen.json");
+}
+
+#[test]
+fn aggregated_votes_gas_go() {
+    world().run("scenarios/aggregated_votes_gas.scen.json");
+}
+
+#[test]
+fn whitelist_batch_gas_go() {
+    world().run("scenarios/whitelist_batch_gas.scen.json");
+}
+
+#[test]
+fn migration_batch_gas_go() {
+    world().run("scenarios/migration_batch_gas.scen.json");
+}
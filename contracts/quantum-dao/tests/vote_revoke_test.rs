@@ -0,0 +1,135 @@
+use multiversx_sc::types::{Address, ManagedBuffer, TokenIdentifier};
+use multiversx_sc_scenario::{managed_address, scenario_model::*, *};
+use quantum_dao::{
+    admin::AdminModule, governance::GovernanceModule, rewards::RewardsModule,
+    scoring::ScoringModule, staking::StakingModule, storage::StorageModule, types::*,
+    QuantumDaoGame,
+};
+
+const QUANTUM_DAO_PATH_EXPR: &str = "file:output/quantum-dao.wasm";
+const OWNER: &str = "address:owner";
+const ALICE: &str = "address:alice";
+const BOB: &str = "address:bob";
+const GAME: &str = "sc:quantum-dao";
+const SEASON_ID: u32 = 1;
+const PROPOSAL_ID: u32 = 1;
+const QUEST_ID: usize = 1;
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+const STAKE: u64 = 10 * ONE_EGLD;
+
+type GameContract = WhiteboxContract<quantum_dao::ContractObj<DebugApi>>;
+
+fn address(expr: &str) -> Address {
+    AddressValue::from(expr).to_address()
+}
+
+/// Alice and Bob are entangled, a vote quest is running and proposal 1 is open
+fn setup() -> (ScenarioWorld, GameContract) {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("quantum-dao");
+    world.register_contract(QUANTUM_DAO_PATH_EXPR, quantum_dao::ContractBuilder);
+
+    let game = WhiteboxContract::new(GAME, quantum_dao::contract_obj);
+    let code = world.code_expression(QUANTUM_DAO_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1))
+            .put_account(ALICE, Account::new().nonce(1).balance(3 * STAKE))
+            .put_account(BOB, Account::new().nonce(1))
+            .new_address(OWNER, 1, GAME)
+            .current_block(BlockInfo::new().block_nonce(10)),
+    );
+    world.whitebox_deploy(&game, ScDeployStep::new().from(OWNER).code(code), |sc| {
+        sc.init(1_000, TokenIdentifier::from("QDAO-123456"));
+    });
+
+    world.whitebox_call(&game, ScCallStep::new().from(OWNER), |sc| {
+        sc.create_quest(QuestKind::CastVotes, 5, 0, 1_000, 0, 1);
+        sc.store_new_proposal(
+            SEASON_ID,
+            &managed_address!(&address(OWNER)),
+            ManagedBuffer::from("Proposal"),
+            ManagedBuffer::from("Description"),
+            100,
+        );
+    });
+    world.whitebox_call(&game, ScCallStep::new().from(ALICE), |sc| {
+        sc.request_entanglement(managed_address!(&address(BOB)));
+    });
+    world.whitebox_call(&game, ScCallStep::new().from(BOB), |sc| {
+        sc.accept_entanglement(managed_address!(&address(ALICE)));
+    });
+
+    (world, game)
+}
+
+fn vote(world: &mut ScenarioWorld, game: &GameContract) {
+    world.whitebox_call(
+        game,
+        ScCallStep::new().from(ALICE).egld_value(STAKE),
+        |sc| {
+            sc.vote(PROPOSAL_ID, true);
+        },
+    );
+}
+
+fn revoke(world: &mut ScenarioWorld, game: &GameContract) {
+    world.whitebox_call(game, ScCallStep::new().from(ALICE), |sc| {
+        sc.revoke_vote(PROPOSAL_ID);
+    });
+}
+
+fn score(world: &mut ScenarioWorld, game: &GameContract, expr: &str) -> u64 {
+    let player = address(expr);
+    let mut score = 0;
+    world.whitebox_query(game, |sc| {
+        score = sc
+            .dao_scores(SEASON_ID, sc.player_id(&managed_address!(&player)))
+            .get();
+    });
+    score
+}
+
+fn quest_progress(world: &mut ScenarioWorld, game: &GameContract) -> u32 {
+    let alice = address(ALICE);
+    let mut progress = 0;
+    world.whitebox_query(game, |sc| {
+        progress = sc
+            .quest_progress(SEASON_ID, QUEST_ID, &managed_address!(&alice))
+            .get();
+    });
+    progress
+}
+
+#[test]
+fn revoke_takes_back_the_partner_share() {
+    let (mut world, game) = setup();
+
+    vote(&mut world, &game);
+    assert!(score(&mut world, &game, BOB) > 0);
+
+    revoke(&mut world, &game);
+    assert_eq!(score(&mut world, &game, ALICE), 0);
+    assert_eq!(score(&mut world, &game, BOB), 0);
+}
+
+#[test]
+fn revoking_and_voting_again_earns_nothing_extra() {
+    let (mut world, game) = setup();
+
+    vote(&mut world, &game);
+    let alice_score = score(&mut world, &game, ALICE);
+    let bob_score = score(&mut world, &game, BOB);
+    let progress = quest_progress(&mut world, &game);
+    assert_eq!(progress, 1);
+
+    for _ in 0..2 {
+        revoke(&mut world, &game);
+        vote(&mut world, &game);
+    }
+
+    assert_eq!(score(&mut world, &game, ALICE), alice_score);
+    assert_eq!(score(&mut world, &game, BOB), bob_score);
+    assert_eq!(quest_progress(&mut world, &game), progress);
+}
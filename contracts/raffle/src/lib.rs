@@ -0,0 +1,237 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on winners per draw
+pub const MAX_WINNERS: usize = 10;
+
+/// Raffle Smart Contract
+/// Registered game instances report the tickets their players earned by voting in a season.
+/// Once the season's tickets are in, the owner draws winners weighted by tickets from the block
+/// random seed and the prize, funded by the treasury or anyone else, is split between them.
+#[multiversx_sc::contract]
+pub trait Raffle {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(addGame)]
+    fn add_game(&self, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        require!(self.games().insert(game.clone()), "Game already added");
+
+        self.game_added_event(&game);
+    }
+
+    #[only_owner]
+    #[endpoint(removeGame)]
+    fn remove_game(&self, game: ManagedAddress) {
+        require!(self.games().swap_remove(&game), "Game not added");
+
+        self.game_removed_event(&game);
+    }
+
+    /// Credit tickets to players in the calling game's raffle for `season_id`
+    #[endpoint(addTickets)]
+    fn add_tickets(&self, season_id: u32, tickets: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) {
+        let game = self.blockchain().get_caller();
+        require!(self.games().contains(&game), "Game not added");
+        require!(!self.drawn(&game, season_id).get(), "Raffle already drawn");
+
+        let mut total = self.total_tickets(&game, season_id).get();
+        for entry in tickets {
+            let (player, count) = entry.into_tuple();
+            if count == 0 {
+                continue;
+            }
+
+            total += count;
+            self.ticket_ranges(&game, season_id).push(&TicketRange {
+                player: player.clone(),
+                end: total,
+            });
+            self.player_tickets(&game, season_id, &player).update(|tickets| *tickets += count);
+        }
+        self.total_tickets(&game, season_id).set(total);
+
+        self.tickets_added_event(&game, season_id, total);
+    }
+
+    /// Add the EGLD sent with the call to the prize of `game`'s raffle for `season_id`
+    #[payable("EGLD")]
+    #[endpoint(fundPrize)]
+    fn fund_prize(&self, game: ManagedAddress, season_id: u32) {
+        let amount = self.call_value().egld_value().clone_value();
+        require!(amount > 0, "Must send EGLD");
+        require!(!self.drawn(&game, season_id).get(), "Raffle already drawn");
+
+        self.prize(&game, season_id).update(|prize| *prize += &amount);
+        self.total_allocated().update(|total| *total += &amount);
+
+        self.prize_funded_event(&game, season_id, &amount);
+    }
+
+    /// Assign EGLD received without `fundPrize`, such as an executed treasury spend, to the
+    /// prize of `game`'s raffle for `season_id`
+    #[only_owner]
+    #[endpoint(allocatePrize)]
+    fn allocate_prize(&self, game: ManagedAddress, season_id: u32, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        require!(amount <= self.get_unallocated_balance(), "Not enough unallocated balance");
+        require!(!self.drawn(&game, season_id).get(), "Raffle already drawn");
+
+        self.prize(&game, season_id).update(|prize| *prize += &amount);
+        self.total_allocated().update(|total| *total += &amount);
+
+        self.prize_funded_event(&game, season_id, &amount);
+    }
+
+    /// Draw `winner_count` tickets of `game`'s raffle for `season_id` and split the prize
+    /// equally between their holders. A player holding several drawn tickets wins several
+    /// shares.
+    #[only_owner]
+    #[endpoint(draw)]
+    fn draw(&self, game: ManagedAddress, season_id: u32, winner_count: usize) {
+        require!(winner_count > 0 && winner_count <= MAX_WINNERS, "Invalid winner count");
+        require!(!self.drawn(&game, season_id).get(), "Raffle already drawn");
+        let total_tickets = self.total_tickets(&game, season_id).get();
+        require!(total_tickets > 0, "No tickets");
+        let prize = self.prize(&game, season_id).get();
+        require!(prize > 0, "No prize");
+
+        self.drawn(&game, season_id).set(true);
+        self.total_allocated().update(|total| *total -= &prize);
+
+        let share = prize.clone() / winner_count as u64;
+        let remainder = prize - share.clone() * winner_count as u64;
+        let mut rand_source = RandomnessSource::new();
+        for i in 0..winner_count {
+            let ticket = rand_source.next_u64_in_range(0, total_tickets);
+            let winner = self.ticket_holder(&game, season_id, ticket);
+            let payout = if i == 0 { &share + &remainder } else { share.clone() };
+
+            self.winners(&game, season_id).push(&winner);
+            self.send().direct_egld(&winner, &payout);
+
+            self.winner_drawn_event(&game, season_id, &winner, &payout);
+        }
+    }
+
+    // View functions
+    #[view(getRaffle)]
+    fn get_raffle(&self, game: ManagedAddress, season_id: u32) -> RaffleInfo<Self::Api> {
+        RaffleInfo {
+            total_tickets: self.total_tickets(&game, season_id).get(),
+            prize: self.prize(&game, season_id).get(),
+            drawn: self.drawn(&game, season_id).get(),
+        }
+    }
+
+    #[view(getTickets)]
+    fn get_tickets(&self, game: ManagedAddress, season_id: u32, player: ManagedAddress) -> u64 {
+        self.player_tickets(&game, season_id, &player).get()
+    }
+
+    #[view(getWinners)]
+    fn get_winners(&self, game: ManagedAddress, season_id: u32) -> MultiValueEncoded<ManagedAddress> {
+        self.winners(&game, season_id).iter().collect()
+    }
+
+    #[view(getGames)]
+    fn get_games(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.games().iter().collect()
+    }
+
+    /// EGLD held but not yet assigned to any raffle's prize
+    #[view(getUnallocatedBalance)]
+    fn get_unallocated_balance(&self) -> BigUint {
+        self.blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0)
+            - self.total_allocated().get()
+    }
+
+    // Private functions
+    /// Holder of ticket number `ticket`, found by binary search over the cumulative ranges
+    fn ticket_holder(&self, game: &ManagedAddress, season_id: u32, ticket: u64) -> ManagedAddress {
+        let ranges = self.ticket_ranges(game, season_id);
+        let mut low = 1;
+        let mut high = ranges.len();
+        while low < high {
+            let middle = (low + high) / 2;
+            if ranges.get(middle).end > ticket {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        ranges.get(low).player
+    }
+
+    // Storage
+    #[storage_mapper("games")]
+    fn games(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Tickets in reporting order; each range ends where the next one starts
+    #[storage_mapper("ticket_ranges")]
+    fn ticket_ranges(&self, game: &ManagedAddress, season_id: u32) -> VecMapper<TicketRange<Self::Api>>;
+
+    #[storage_mapper("total_tickets")]
+    fn total_tickets(&self, game: &ManagedAddress, season_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("player_tickets")]
+    fn player_tickets(
+        &self,
+        game: &ManagedAddress,
+        season_id: u32,
+        player: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("prize")]
+    fn prize(&self, game: &ManagedAddress, season_id: u32) -> SingleValueMapper<BigUint>;
+
+    /// Sum of the prizes of raffles not drawn yet
+    #[storage_mapper("total_allocated")]
+    fn total_allocated(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("drawn")]
+    fn drawn(&self, game: &ManagedAddress, season_id: u32) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("winners")]
+    fn winners(&self, game: &ManagedAddress, season_id: u32) -> VecMapper<ManagedAddress>;
+
+    // Events
+    #[event("game_added")]
+    fn game_added_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("game_removed")]
+    fn game_removed_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("tickets_added")]
+    fn tickets_added_event(&self, #[indexed] game: &ManagedAddress, #[indexed] season_id: u32, total_tickets: u64);
+
+    #[event("prize_funded")]
+    fn prize_funded_event(&self, #[indexed] game: &ManagedAddress, #[indexed] season_id: u32, amount: &BigUint);
+
+    #[event("winner_drawn")]
+    fn winner_drawn_event(
+        &self,
+        #[indexed] game: &ManagedAddress,
+        #[indexed] season_id: u32,
+        #[indexed] winner: &ManagedAddress,
+        payout: &BigUint,
+    );
+}
+
+/// Tickets `[previous range end, end)` belong to `player`
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct TicketRange<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub end: u64,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct RaffleInfo<M: ManagedTypeApi> {
+    pub total_tickets: u64,
+    pub prize: BigUint<M>,
+    pub drawn: bool,
+}
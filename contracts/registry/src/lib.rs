@@ -0,0 +1,99 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Registry Smart Contract
+/// Maps logical names (treasury, token, marketplace, game #N) to the current address of each
+/// suite deployment. Every change bumps the entry's version and is kept in its history, so
+/// contracts resolving addresses here follow coordinated upgrades without redeploying.
+#[multiversx_sc::contract]
+pub trait Registry {
+    #[init]
+    fn init(&self) {}
+
+    /// Point `name` at `address`, bumping its version
+    #[only_owner]
+    #[endpoint(setEntry)]
+    fn set_entry(&self, name: ManagedBuffer, address: ManagedAddress) {
+        require!(!name.is_empty(), "Name required");
+        require!(self.blockchain().is_smart_contract(&address), "Address must be a contract");
+
+        let version = self.entry_history(&name).len() as u32 + 1;
+        let entry = RegistryEntry {
+            address,
+            version,
+            updated_block: self.blockchain().get_block_nonce(),
+        };
+        self.names().insert(name.clone());
+        self.entries(&name).set(&entry);
+        self.entry_history(&name).push(&entry);
+
+        self.entry_set_event(&name, version, &entry.address);
+    }
+
+    /// Stop resolving `name`; its history is kept and a later `setEntry` continues the versioning
+    #[only_owner]
+    #[endpoint(removeEntry)]
+    fn remove_entry(&self, name: ManagedBuffer) {
+        require!(self.names().swap_remove(&name), "Name not registered");
+        self.entries(&name).clear();
+
+        self.entry_removed_event(&name);
+    }
+
+    // View functions
+    #[view(getAddress)]
+    fn get_address(&self, name: ManagedBuffer) -> ManagedAddress {
+        require!(self.names().contains(&name), "Name not registered");
+        self.entries(&name).get().address
+    }
+
+    #[view(getEntry)]
+    fn get_entry(&self, name: ManagedBuffer) -> OptionalValue<RegistryEntry<Self::Api>> {
+        if !self.names().contains(&name) {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.entries(&name).get())
+    }
+
+    /// Every address `name` has pointed at, oldest first
+    #[view(getEntryHistory)]
+    fn get_entry_history(&self, name: ManagedBuffer) -> MultiValueEncoded<RegistryEntry<Self::Api>> {
+        self.entry_history(&name).iter().collect()
+    }
+
+    #[view(getNames)]
+    fn get_names(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.names().iter().collect()
+    }
+
+    // Storage
+    #[storage_mapper("names")]
+    fn names(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("entries")]
+    fn entries(&self, name: &ManagedBuffer) -> SingleValueMapper<RegistryEntry<Self::Api>>;
+
+    #[storage_mapper("entry_history")]
+    fn entry_history(&self, name: &ManagedBuffer) -> VecMapper<RegistryEntry<Self::Api>>;
+
+    // Events
+    #[event("entry_set")]
+    fn entry_set_event(
+        &self,
+        #[indexed] name: &ManagedBuffer,
+        #[indexed] version: u32,
+        address: &ManagedAddress,
+    );
+
+    #[event("entry_removed")]
+    fn entry_removed_event(&self, #[indexed] name: &ManagedBuffer);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct RegistryEntry<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub version: u32,
+    pub updated_block: u64,
+}
@@ -0,0 +1,241 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on holders credited in one call
+pub const MAX_ENTRIES_PER_CALL: usize = 50;
+
+/// Reputation SBT Smart Contract
+/// Tracks each player's reputation across seasons and game instances. Registered games add
+/// reputation for what players do and deduct it for misconduct; a game can only deduct what it
+/// granted itself, so no single game can erase reputation earned elsewhere. Any suite contract
+/// reads the score through `getReputation` to gate or weight actions.
+///
+/// Players with reputation can claim one soulbound NFT from the collection this contract issues.
+/// Only this contract holds the collection's transfer role, so holders cannot move the token.
+/// Attributes of an NFT in a player's wallet cannot be updated, so the score kept here is the
+/// source of truth and the token serves as the holder's on-chain badge.
+#[multiversx_sc::contract]
+pub trait ReputationSbt {
+    #[init]
+    fn init(&self) {}
+
+    /// Issue the SBT collection with this contract holding every NFT role.
+    /// The payment covers the protocol issue cost.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueCollection)]
+    fn issue_collection(&self, display_name: ManagedBuffer, ticker: ManagedBuffer) {
+        require!(self.collection().is_empty(), "Collection already issued");
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.collection().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            display_name,
+            ticker,
+            0,
+            None,
+        );
+    }
+
+    /// Give this contract the transfer role, which restricts transfers of the collection to
+    /// role holders and so makes the tokens soulbound. Call once the collection is issued.
+    #[only_owner]
+    #[endpoint(restrictTransfers)]
+    fn restrict_transfers(&self) {
+        require!(!self.collection().is_empty(), "Collection not issued");
+
+        self.collection().set_local_roles(&[EsdtLocalRole::Transfer], None);
+    }
+
+    #[only_owner]
+    #[endpoint(addGame)]
+    fn add_game(&self, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        require!(self.games().insert(game.clone()), "Game already added");
+
+        self.game_added_event(&game);
+    }
+
+    /// Stop a game from updating reputation; what it granted so far stays
+    #[only_owner]
+    #[endpoint(removeGame)]
+    fn remove_game(&self, game: ManagedAddress) {
+        require!(self.games().swap_remove(&game), "Game not added");
+
+        self.game_removed_event(&game);
+    }
+
+    /// Add reputation for the calling game, one `(holder, points)` entry per holder
+    #[endpoint(addReputation)]
+    fn add_reputation(&self, entries: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) {
+        let game = self.require_game();
+        require!(entries.len() <= MAX_ENTRIES_PER_CALL, "Too many entries");
+
+        for entry in entries {
+            let (holder, points) = entry.into_tuple();
+            if points == 0 {
+                continue;
+            }
+
+            self.game_reputation(&game, &holder).update(|granted| *granted += points);
+            let score = self.reputation(&holder).update(|score| {
+                *score += points;
+                *score
+            });
+
+            self.reputation_added_event(&holder, &game, points, score);
+        }
+    }
+
+    /// Deduct up to `points` of the reputation the calling game granted `holder`
+    #[endpoint(deductReputation)]
+    fn deduct_reputation(&self, holder: ManagedAddress, points: u64) -> u64 {
+        let game = self.require_game();
+
+        let granted_mapper = self.game_reputation(&game, &holder);
+        let deducted = core::cmp::min(points, granted_mapper.get());
+        require!(deducted > 0, "Nothing to deduct");
+
+        granted_mapper.update(|granted| *granted -= deducted);
+        let score = self.reputation(&holder).update(|score| {
+            *score -= deducted;
+            *score
+        });
+
+        self.reputation_deducted_event(&holder, &game, deducted, score);
+
+        deducted
+    }
+
+    /// Mint the caller's soulbound token; requires some reputation and at most one per holder
+    #[endpoint(claimSbt)]
+    fn claim_sbt(&self) -> u64 {
+        require!(!self.collection().is_empty(), "Collection not issued");
+        let caller = self.blockchain().get_caller();
+        require!(self.sbt_nonce(&caller).is_empty(), "SBT already claimed");
+        require!(self.reputation(&caller).get() > 0, "No reputation");
+
+        let token_id = self.collection().get_token_id();
+        let attributes = SbtAttributes {
+            holder: caller.clone(),
+            issued_block: self.blockchain().get_block_nonce(),
+        };
+        let nonce = self.send().esdt_nft_create(
+            &token_id,
+            &BigUint::from(1u32),
+            &ManagedBuffer::from(b"Reputation"),
+            &BigUint::zero(),
+            &ManagedBuffer::new(),
+            &attributes,
+            &ManagedVec::new(),
+        );
+        self.sbt_nonce(&caller).set(nonce);
+
+        self.send()
+            .direct_esdt(&caller, &token_id, nonce, &BigUint::from(1u32));
+
+        self.sbt_claimed_event(&caller, nonce);
+
+        nonce
+    }
+
+    // View functions
+    /// Reputation of `holder` summed across games and seasons
+    #[view(getReputation)]
+    fn get_reputation(&self, holder: ManagedAddress) -> u64 {
+        self.reputation(&holder).get()
+    }
+
+    /// Reputation `game` has granted `holder`, net of its deductions
+    #[view(getGameReputation)]
+    fn get_game_reputation(&self, game: ManagedAddress, holder: ManagedAddress) -> u64 {
+        self.game_reputation(&game, &holder).get()
+    }
+
+    #[view(hasReputation)]
+    fn has_reputation(&self, holder: ManagedAddress, min_reputation: u64) -> bool {
+        self.reputation(&holder).get() >= min_reputation
+    }
+
+    #[view(getSbtNonce)]
+    fn get_sbt_nonce(&self, holder: ManagedAddress) -> OptionalValue<u64> {
+        if self.sbt_nonce(&holder).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.sbt_nonce(&holder).get())
+    }
+
+    #[view(getCollection)]
+    fn get_collection(&self) -> OptionalValue<TokenIdentifier> {
+        if self.collection().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.collection().get_token_id())
+    }
+
+    #[view(getGames)]
+    fn get_games(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.games().iter().collect()
+    }
+
+    // Private functions
+    fn require_game(&self) -> ManagedAddress {
+        let game = self.blockchain().get_caller();
+        require!(self.games().contains(&game), "Game not added");
+        game
+    }
+
+    // Storage
+    #[storage_mapper("collection")]
+    fn collection(&self) -> NonFungibleTokenMapper<Self::Api>;
+
+    #[storage_mapper("games")]
+    fn games(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("reputation")]
+    fn reputation(&self, holder: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("game_reputation")]
+    fn game_reputation(&self, game: &ManagedAddress, holder: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sbt_nonce")]
+    fn sbt_nonce(&self, holder: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Events
+    #[event("game_added")]
+    fn game_added_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("game_removed")]
+    fn game_removed_event(&self, #[indexed] game: &ManagedAddress);
+
+    #[event("reputation_added")]
+    fn reputation_added_event(
+        &self,
+        #[indexed] holder: &ManagedAddress,
+        #[indexed] game: &ManagedAddress,
+        points: u64,
+        score: u64,
+    );
+
+    #[event("reputation_deducted")]
+    fn reputation_deducted_event(
+        &self,
+        #[indexed] holder: &ManagedAddress,
+        #[indexed] game: &ManagedAddress,
+        points: u64,
+        score: u64,
+    );
+
+    #[event("sbt_claimed")]
+    fn sbt_claimed_event(&self, #[indexed] holder: &ManagedAddress, #[indexed] nonce: u64);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SbtAttributes<M: ManagedTypeApi> {
+    pub holder: ManagedAddress<M>,
+    pub issued_block: u64,
+}
@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+
+/// Endpoints a quantum-dao game exposes to its trusted router
+#[multiversx_sc::proxy]
+pub trait Game {
+    #[payable("EGLD")]
+    #[endpoint(voteFor)]
+    fn vote_for(&self, player: ManagedAddress, proposal_id: u32, vote_for: bool);
+
+    #[endpoint(claimPrizeFor)]
+    fn claim_prize_for(&self, player: ManagedAddress, season_id: u32);
+}
@@ -0,0 +1,81 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_proxy;
+
+/// Upper bound on steps in one routed call
+pub const MAX_STEPS: usize = 10;
+
+/// Router Smart Contract
+/// Runs several game actions for the caller in one transaction, such as votes on a set of
+/// proposals or prize claims for several seasons. Games must name this contract their trusted
+/// router. Every step is a synchronous call, so the game has to live in the router's shard;
+/// in exchange a failing step reverts the whole transaction, including the steps before it.
+#[multiversx_sc::contract]
+pub trait Router {
+    #[init]
+    fn init(&self) {}
+
+    /// Cast one vote per `(proposal_id, vote_for, stake)` entry, splitting the EGLD sent, which
+    /// must equal the sum of the stakes
+    #[payable("EGLD")]
+    #[endpoint(voteBatch)]
+    fn vote_batch(&self, game: ManagedAddress, votes: MultiValueEncoded<MultiValue3<u32, bool, BigUint>>) {
+        self.require_routable(&game, votes.len());
+
+        let caller = self.blockchain().get_caller();
+        let mut total_stake = BigUint::zero();
+        let steps = votes.len();
+        for entry in votes {
+            let (proposal_id, vote_for, stake) = entry.into_tuple();
+            total_stake += &stake;
+            self.game_proxy(game.clone())
+                .vote_for(caller.clone(), proposal_id, vote_for)
+                .with_egld_transfer(stake)
+                .execute_on_dest_context::<()>();
+        }
+        require!(
+            total_stake == *self.call_value().egld_value(),
+            "Payment must equal the total stake"
+        );
+
+        self.routed_event(&caller, &game, steps);
+    }
+
+    /// Claim the caller's prize share of every season in `season_ids`; shares are paid to the
+    /// caller by the game directly
+    #[endpoint(claimPrizes)]
+    fn claim_prizes(&self, game: ManagedAddress, season_ids: MultiValueEncoded<u32>) {
+        self.require_routable(&game, season_ids.len());
+
+        let caller = self.blockchain().get_caller();
+        let steps = season_ids.len();
+        for season_id in season_ids {
+            self.game_proxy(game.clone())
+                .claim_prize_for(caller.clone(), season_id)
+                .execute_on_dest_context::<()>();
+        }
+
+        self.routed_event(&caller, &game, steps);
+    }
+
+    // Private functions
+    fn require_routable(&self, game: &ManagedAddress, steps: usize) {
+        require!(steps > 0, "No steps");
+        require!(steps <= MAX_STEPS, "Too many steps");
+        require!(self.blockchain().is_smart_contract(game), "Game must be a contract");
+        require!(
+            self.blockchain().get_shard_of_address(game)
+                == self.blockchain().get_shard_of_address(&self.blockchain().get_sc_address()),
+            "Game must be in the router's shard"
+        );
+    }
+
+    #[proxy]
+    fn game_proxy(&self, sc_address: ManagedAddress) -> game_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("routed")]
+    fn routed_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] game: &ManagedAddress, steps: usize);
+}
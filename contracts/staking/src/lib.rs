@@ -0,0 +1,364 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Fixed-point scale of `acc_reward_per_share`
+pub const REWARD_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// Staking Smart Contract
+/// Players stake the suite's governance token for a share of a per-block reward stream funded
+/// by the treasury or the owner. Game instances read `getVotingPowerAt` to weight votes by the
+/// stake held before a proposal opened instead of by the payment sent with each vote, so tokens
+/// unstaked and staked again from another address cannot vote twice on the same proposal.
+/// Unstaked tokens stop earning immediately and stay locked for the unbonding period.
+#[multiversx_sc::contract]
+pub trait Staking {
+    #[init]
+    fn init(&self, staking_token: TokenIdentifier, reward_per_block: BigUint, unbonding_blocks: u64) {
+        require!(staking_token.is_valid_esdt_identifier(), "Invalid staking token");
+        self.staking_token().set(&staking_token);
+        self.reward_per_block().set(&reward_per_block);
+        self.unbonding_blocks().set(unbonding_blocks);
+        self.last_reward_block().set(self.blockchain().get_block_nonce());
+    }
+
+    #[only_owner]
+    #[endpoint(setRewardPerBlock)]
+    fn set_reward_per_block(&self, reward_per_block: BigUint) {
+        self.update_pool();
+        self.reward_per_block().set(&reward_per_block);
+
+        self.reward_per_block_set_event(&reward_per_block);
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondingBlocks)]
+    fn set_unbonding_blocks(&self, unbonding_blocks: u64) {
+        self.unbonding_blocks().set(unbonding_blocks);
+    }
+
+    /// Add staking tokens to the reserve the per-block rewards are paid from
+    #[payable("*")]
+    #[endpoint(fundRewards)]
+    fn fund_rewards(&self) {
+        let amount = self.require_staking_token_payment();
+        self.update_pool();
+        self.reward_reserve().update(|reserve| *reserve += &amount);
+
+        self.rewards_funded_event(&self.blockchain().get_caller(), &amount);
+    }
+
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake(&self) {
+        let amount = self.require_staking_token_payment();
+        let caller = self.blockchain().get_caller();
+        self.update_pool();
+        self.settle_rewards(&caller);
+
+        let staked = self.staked(&caller).update(|staked| {
+            *staked += &amount;
+            staked.clone()
+        });
+        self.total_staked().update(|total| *total += &amount);
+        self.record_checkpoint(&caller, &(&staked - &amount), &staked);
+        self.reset_reward_debt(&caller, &staked);
+
+        self.staked_event(&caller, &amount);
+    }
+
+    /// Stop staking `amount`; it no longer earns rewards or voting power and can be withdrawn
+    /// once the unbonding period has passed. Unstaking again restarts the period for the
+    /// whole unbonding balance.
+    #[endpoint(unstake)]
+    fn unstake(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let staked_mapper = self.staked(&caller);
+        let staked = staked_mapper.get();
+        require!(amount > 0, "Amount must be positive");
+        require!(amount <= staked, "Not enough staked");
+
+        self.update_pool();
+        self.settle_rewards(&caller);
+
+        let old_staked = staked.clone();
+        let staked = staked - &amount;
+        staked_mapper.set(&staked);
+        self.total_staked().update(|total| *total -= &amount);
+        self.record_checkpoint(&caller, &old_staked, &staked);
+        self.reset_reward_debt(&caller, &staked);
+
+        let unlock_block = self.blockchain().get_block_nonce() + self.unbonding_blocks().get();
+        let unbonding_mapper = self.unbonding(&caller);
+        let unbonding_amount = if unbonding_mapper.is_empty() {
+            amount.clone()
+        } else {
+            unbonding_mapper.get().amount + &amount
+        };
+        unbonding_mapper.set(Unbonding {
+            amount: unbonding_amount,
+            unlock_block,
+        });
+
+        self.unstaked_event(&caller, &amount, unlock_block);
+    }
+
+    /// Withdraw the unbonded balance once its unlock block has passed
+    #[endpoint(withdraw)]
+    fn withdraw(&self) {
+        let caller = self.blockchain().get_caller();
+        let unbonding_mapper = self.unbonding(&caller);
+        require!(!unbonding_mapper.is_empty(), "Nothing to withdraw");
+
+        let unbonding = unbonding_mapper.get();
+        require!(
+            self.blockchain().get_block_nonce() >= unbonding.unlock_block,
+            "Still unbonding"
+        );
+        unbonding_mapper.clear();
+
+        self.send()
+            .direct_esdt(&caller, &self.staking_token().get(), 0, &unbonding.amount);
+
+        self.withdrawn_event(&caller, &unbonding.amount);
+    }
+
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+        self.update_pool();
+        self.settle_rewards(&caller);
+        self.reset_reward_debt(&caller, &self.staked(&caller).get());
+
+        let rewards = self.pending_rewards(&caller).take();
+        require!(rewards > 0, "No rewards to claim");
+
+        self.send()
+            .direct_esdt(&caller, &self.staking_token().get(), 0, &rewards);
+
+        self.rewards_claimed_event(&caller, &rewards);
+    }
+
+    // View functions
+    /// Currently staked balance of `address`
+    #[view(getVotingPower)]
+    fn get_voting_power(&self, address: ManagedAddress) -> BigUint {
+        self.staked(&address).get()
+    }
+
+    /// Weight of `address` in game votes: its staked balance at the end of `block`
+    #[view(getVotingPowerAt)]
+    fn get_voting_power_at(&self, address: ManagedAddress, block: u64) -> BigUint {
+        let checkpoints = self.checkpoints(&address);
+        // Balances that have not changed since checkpoints were introduced are held from the start
+        if checkpoints.is_empty() {
+            return self.staked(&address).get();
+        }
+        if checkpoints.get(1).block > block {
+            return BigUint::zero();
+        }
+
+        // Last checkpoint at or before `block`
+        let mut low = 1;
+        let mut high = checkpoints.len();
+        while low < high {
+            let mid = (low + high + 1) / 2;
+            if checkpoints.get(mid).block <= block {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        checkpoints.get(low).amount
+    }
+
+    #[view(getTotalStaked)]
+    fn get_total_staked(&self) -> BigUint {
+        self.total_staked().get()
+    }
+
+    #[view(getStakeInfo)]
+    fn get_stake_info(&self, address: ManagedAddress) -> StakeInfo<Self::Api> {
+        let unbonding_mapper = self.unbonding(&address);
+        let (unbonding, unlock_block) = if unbonding_mapper.is_empty() {
+            (BigUint::zero(), 0)
+        } else {
+            let unbonding = unbonding_mapper.get();
+            (unbonding.amount, unbonding.unlock_block)
+        };
+        StakeInfo {
+            staked: self.staked(&address).get(),
+            pending_rewards: self.get_pending_rewards(address),
+            unbonding,
+            unlock_block,
+        }
+    }
+
+    /// Rewards `address` could claim now, including those accrued since the last pool update
+    #[view(getPendingRewards)]
+    fn get_pending_rewards(&self, address: ManagedAddress) -> BigUint {
+        let acc_reward_per_share = self.acc_reward_per_share().get() + self.unaccounted_reward_per_share();
+        let accrued = self.staked(&address).get() * &acc_reward_per_share / REWARD_PRECISION;
+        self.pending_rewards(&address).get() + accrued - self.reward_debt(&address).get()
+    }
+
+    #[view(getRewardReserve)]
+    fn get_reward_reserve(&self) -> BigUint {
+        self.reward_reserve().get()
+    }
+
+    // Private functions
+    fn require_staking_token_payment(&self) -> BigUint {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.staking_token().get() && payment.token_nonce == 0,
+            "Wrong token"
+        );
+        require!(payment.amount > 0, "Must send tokens");
+        payment.amount
+    }
+
+    /// Records `player`'s balance from the current block on. A balance held from before the first
+    /// checkpoint is recorded as held from block 0; changes within a block keep only the last.
+    fn record_checkpoint(&self, player: &ManagedAddress, old_staked: &BigUint, staked: &BigUint) {
+        let mut checkpoints = self.checkpoints(player);
+        if checkpoints.is_empty() && *old_staked > 0 {
+            checkpoints.push(&Checkpoint {
+                block: 0,
+                amount: old_staked.clone(),
+            });
+        }
+
+        let checkpoint = Checkpoint {
+            block: self.blockchain().get_block_nonce(),
+            amount: staked.clone(),
+        };
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).block == checkpoint.block {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            checkpoints.push(&checkpoint);
+        }
+    }
+
+    /// Reward per staked token accrued since `last_reward_block`, limited by the reserve
+    fn unaccounted_reward_per_share(&self) -> BigUint {
+        let total_staked = self.total_staked().get();
+        if total_staked == 0 {
+            return BigUint::zero();
+        }
+
+        self.unaccounted_reward() * REWARD_PRECISION / total_staked
+    }
+
+    fn unaccounted_reward(&self) -> BigUint {
+        let blocks = self.blockchain().get_block_nonce() - self.last_reward_block().get();
+        let reward = self.reward_per_block().get() * blocks;
+        core::cmp::min(reward, self.reward_reserve().get())
+    }
+
+    /// Moves rewards accrued since the last update from the reserve into `acc_reward_per_share`.
+    /// Nothing accrues while nobody is staking.
+    fn update_pool(&self) {
+        if self.total_staked().get() > 0 {
+            let reward = self.unaccounted_reward();
+            let reward_per_share = reward.clone() * REWARD_PRECISION / self.total_staked().get();
+            self.reward_reserve().update(|reserve| *reserve -= &reward);
+            self.acc_reward_per_share().update(|acc| *acc += reward_per_share);
+        }
+        self.last_reward_block().set(self.blockchain().get_block_nonce());
+    }
+
+    /// Credits the rewards `player`'s current stake earned since its reward debt was last reset
+    fn settle_rewards(&self, player: &ManagedAddress) {
+        let accrued = self.staked(player).get() * self.acc_reward_per_share().get() / REWARD_PRECISION;
+        let earned = accrued - self.reward_debt(player).get();
+        if earned > 0 {
+            self.pending_rewards(player).update(|pending| *pending += earned);
+        }
+    }
+
+    fn reset_reward_debt(&self, player: &ManagedAddress, staked: &BigUint) {
+        self.reward_debt(player)
+            .set(staked * &self.acc_reward_per_share().get() / REWARD_PRECISION);
+    }
+
+    // Storage
+    #[storage_mapper("staking_token")]
+    fn staking_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("reward_per_block")]
+    fn reward_per_block(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unbonding_blocks")]
+    fn unbonding_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("reward_reserve")]
+    fn reward_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("last_reward_block")]
+    fn last_reward_block(&self) -> SingleValueMapper<u64>;
+
+    /// Rewards per staked token since deployment, scaled by `REWARD_PRECISION`
+    #[storage_mapper("acc_reward_per_share")]
+    fn acc_reward_per_share(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_staked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staked")]
+    fn staked(&self, player: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("reward_debt")]
+    fn reward_debt(&self, player: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pending_rewards")]
+    fn pending_rewards(&self, player: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unbonding")]
+    fn unbonding(&self, player: &ManagedAddress) -> SingleValueMapper<Unbonding<Self::Api>>;
+
+    /// Staked balance history of a player, oldest first
+    #[storage_mapper("checkpoints")]
+    fn checkpoints(&self, player: &ManagedAddress) -> VecMapper<Checkpoint<Self::Api>>;
+
+    // Events
+    #[event("reward_per_block_set")]
+    fn reward_per_block_set_event(&self, reward_per_block: &BigUint);
+
+    #[event("rewards_funded")]
+    fn rewards_funded_event(&self, #[indexed] funder: &ManagedAddress, amount: &BigUint);
+
+    #[event("staked")]
+    fn staked_event(&self, #[indexed] player: &ManagedAddress, amount: &BigUint);
+
+    #[event("unstaked")]
+    fn unstaked_event(&self, #[indexed] player: &ManagedAddress, amount: &BigUint, unlock_block: u64);
+
+    #[event("withdrawn")]
+    fn withdrawn_event(&self, #[indexed] player: &ManagedAddress, amount: &BigUint);
+
+    #[event("rewards_claimed")]
+    fn rewards_claimed_event(&self, #[indexed] player: &ManagedAddress, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Unbonding<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlock_block: u64,
+}
+
+/// Staked balance held from `block` until the next checkpoint
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Checkpoint<M: ManagedTypeApi> {
+    pub block: u64,
+    pub amount: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct StakeInfo<M: ManagedTypeApi> {
+    pub staked: BigUint<M>,
+    pub pending_rewards: BigUint<M>,
+    pub unbonding: BigUint<M>,
+    pub unlock_block: u64,
+}
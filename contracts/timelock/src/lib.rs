@@ -0,0 +1,300 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// About 30 days at 6 second blocks
+pub const MAX_DELAY_BLOCKS: u64 = 432_000;
+/// About a week at 6 second blocks; a ready operation not executed within it goes stale
+pub const GRACE_PERIOD_BLOCKS: u64 = 100_800;
+pub const MAX_ARGUMENTS: usize = 32;
+
+/// Timelock Smart Contract
+/// Meant to own the other suite contracts so that every admin change is public before it takes
+/// effect. Proposers queue encoded calls that may run only once their delay has passed;
+/// anyone can then execute them within the grace period. Calls are async, so targets can live
+/// in any shard; a call that fails goes back to the queue and can be retried.
+///
+/// The timelock governs itself the same way: the minimum delay and the proposer set only change
+/// through operations queued against this contract's own endpoints.
+#[multiversx_sc::contract]
+pub trait Timelock {
+    #[init]
+    fn init(&self, min_delay_blocks: u64, proposers: MultiValueEncoded<ManagedAddress>) {
+        require!(min_delay_blocks <= MAX_DELAY_BLOCKS, "Delay too long");
+        require!(!proposers.is_empty(), "No proposers");
+        self.min_delay_blocks().set(min_delay_blocks);
+        for proposer in proposers {
+            self.proposers().insert(proposer);
+        }
+    }
+
+    /// Queue a call of `endpoint` on `target` with `arguments`, sending `egld_value` from this
+    /// contract's balance, to run no earlier than `delay_blocks` from now
+    #[endpoint(queue)]
+    fn queue(
+        &self,
+        target: ManagedAddress,
+        egld_value: BigUint,
+        endpoint: ManagedBuffer,
+        delay_blocks: u64,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        let proposer = self.require_proposer();
+        require!(!endpoint.is_empty(), "Endpoint required");
+        require!(
+            delay_blocks >= self.min_delay_blocks().get() && delay_blocks <= MAX_DELAY_BLOCKS,
+            "Delay out of bounds"
+        );
+        require!(arguments.len() <= MAX_ARGUMENTS, "Too many arguments");
+
+        let operation_id = self.last_operation_id().get() + 1;
+        let operation = Operation {
+            id: operation_id,
+            proposer,
+            target,
+            egld_value,
+            endpoint,
+            arguments: arguments.to_vec(),
+            eta_block: self.blockchain().get_block_nonce() + delay_blocks,
+            status: OperationStatus::Queued,
+        };
+        self.operations(operation_id).set(&operation);
+        self.queued_operations().insert(operation_id);
+        self.last_operation_id().set(operation_id);
+
+        self.operation_queued_event(
+            operation_id,
+            &operation.target,
+            &operation.endpoint,
+            operation.eta_block,
+            &operation.egld_value,
+            &operation.arguments,
+        );
+
+        operation_id
+    }
+
+    #[endpoint(cancel)]
+    fn cancel(&self, operation_id: u64) {
+        self.require_proposer();
+        let mut operation = self.queued_operation(operation_id);
+
+        operation.status = OperationStatus::Cancelled;
+        self.operations(operation_id).set(&operation);
+        self.queued_operations().swap_remove(&operation_id);
+
+        self.operation_cancelled_event(operation_id);
+    }
+
+    /// Run a queued operation whose delay has passed; open to anyone
+    #[endpoint(execute)]
+    fn execute(&self, operation_id: u64) {
+        let mut operation = self.queued_operation(operation_id);
+        let current_block = self.blockchain().get_block_nonce();
+        require!(current_block >= operation.eta_block, "Operation not ready");
+        require!(
+            current_block < operation.eta_block + GRACE_PERIOD_BLOCKS,
+            "Operation stale"
+        );
+        require!(
+            operation.egld_value
+                <= self
+                    .blockchain()
+                    .get_balance(&self.blockchain().get_sc_address()),
+            "Insufficient balance"
+        );
+
+        operation.status = OperationStatus::Executing;
+        self.operations(operation_id).set(&operation);
+        self.queued_operations().swap_remove(&operation_id);
+
+        let mut arguments = ManagedArgBuffer::new();
+        for argument in operation.arguments.iter() {
+            arguments.push_arg_raw(argument.clone_value());
+        }
+        self.send()
+            .contract_call::<IgnoreValue>(operation.target, operation.endpoint)
+            .with_raw_arguments(arguments)
+            .with_egld_transfer(operation.egld_value)
+            .async_call()
+            .with_callback(self.callbacks().execute_callback(operation_id))
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn execute_callback(&self, operation_id: u64, #[call_result] result: ManagedAsyncCallResult<IgnoreValue>) {
+        let mut operation = self.operations(operation_id).get();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                operation.status = OperationStatus::Executed;
+                self.operations(operation_id).set(&operation);
+
+                self.operation_executed_event(operation_id);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                operation.status = OperationStatus::Queued;
+                self.operations(operation_id).set(&operation);
+                self.queued_operations().insert(operation_id);
+
+                self.operation_failed_event(operation_id, &err.err_msg);
+            },
+        }
+    }
+
+    /// Fund the EGLD sent with queued operations
+    #[payable("EGLD")]
+    #[endpoint(deposit)]
+    fn deposit(&self) {}
+
+    /// Only reachable through a queued operation
+    #[endpoint(setMinDelay)]
+    fn set_min_delay(&self, min_delay_blocks: u64) {
+        self.require_self();
+        require!(min_delay_blocks <= MAX_DELAY_BLOCKS, "Delay too long");
+        self.min_delay_blocks().set(min_delay_blocks);
+
+        self.min_delay_set_event(min_delay_blocks);
+    }
+
+    /// Only reachable through a queued operation
+    #[endpoint(addProposer)]
+    fn add_proposer(&self, proposer: ManagedAddress) {
+        self.require_self();
+        require!(self.proposers().insert(proposer.clone()), "Already a proposer");
+
+        self.proposer_added_event(&proposer);
+    }
+
+    /// Only reachable through a queued operation
+    #[endpoint(removeProposer)]
+    fn remove_proposer(&self, proposer: ManagedAddress) {
+        self.require_self();
+        require!(self.proposers().len() > 1, "Cannot remove the last proposer");
+        require!(self.proposers().swap_remove(&proposer), "Not a proposer");
+
+        self.proposer_removed_event(&proposer);
+    }
+
+    // View functions
+    #[view(getOperation)]
+    fn get_operation(&self, operation_id: u64) -> OptionalValue<Operation<Self::Api>> {
+        if self.operations(operation_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.operations(operation_id).get())
+    }
+
+    #[view(getQueuedOperations)]
+    fn get_queued_operations(&self) -> MultiValueEncoded<u64> {
+        self.queued_operations().iter().collect()
+    }
+
+    #[view(isOperationReady)]
+    fn is_operation_ready(&self, operation_id: u64) -> bool {
+        if !self.queued_operations().contains(&operation_id) {
+            return false;
+        }
+
+        let eta_block = self.operations(operation_id).get().eta_block;
+        let current_block = self.blockchain().get_block_nonce();
+        current_block >= eta_block && current_block < eta_block + GRACE_PERIOD_BLOCKS
+    }
+
+    #[view(getMinDelay)]
+    fn get_min_delay(&self) -> u64 {
+        self.min_delay_blocks().get()
+    }
+
+    #[view(getProposers)]
+    fn get_proposers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.proposers().iter().collect()
+    }
+
+    // Private functions
+    fn require_proposer(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(self.proposers().contains(&caller), "Only proposers");
+        caller
+    }
+
+    fn require_self(&self) {
+        require!(
+            self.blockchain().get_caller() == self.blockchain().get_sc_address(),
+            "Only through the timelock"
+        );
+    }
+
+    fn queued_operation(&self, operation_id: u64) -> Operation<Self::Api> {
+        require!(self.queued_operations().contains(&operation_id), "Operation not queued");
+        self.operations(operation_id).get()
+    }
+
+    // Storage
+    #[storage_mapper("min_delay_blocks")]
+    fn min_delay_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposers")]
+    fn proposers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("last_operation_id")]
+    fn last_operation_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("operations")]
+    fn operations(&self, operation_id: u64) -> SingleValueMapper<Operation<Self::Api>>;
+
+    #[storage_mapper("queued_operations")]
+    fn queued_operations(&self) -> UnorderedSetMapper<u64>;
+
+    // Events
+    #[event("operation_queued")]
+    fn operation_queued_event(
+        &self,
+        #[indexed] operation_id: u64,
+        #[indexed] target: &ManagedAddress,
+        #[indexed] endpoint: &ManagedBuffer,
+        #[indexed] eta_block: u64,
+        egld_value: &BigUint,
+        arguments: &ManagedVec<ManagedBuffer>,
+    );
+
+    #[event("operation_cancelled")]
+    fn operation_cancelled_event(&self, #[indexed] operation_id: u64);
+
+    #[event("operation_executed")]
+    fn operation_executed_event(&self, #[indexed] operation_id: u64);
+
+    #[event("operation_failed")]
+    fn operation_failed_event(&self, #[indexed] operation_id: u64, err_msg: &ManagedBuffer);
+
+    #[event("min_delay_set")]
+    fn min_delay_set_event(&self, min_delay_blocks: u64);
+
+    #[event("proposer_added")]
+    fn proposer_added_event(&self, #[indexed] proposer: &ManagedAddress);
+
+    #[event("proposer_removed")]
+    fn proposer_removed_event(&self, #[indexed] proposer: &ManagedAddress);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OperationStatus {
+    Queued,
+    /// Call sent, waiting for its callback
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Operation<M: ManagedTypeApi> {
+    pub id: u64,
+    pub proposer: ManagedAddress<M>,
+    pub target: ManagedAddress<M>,
+    pub egld_value: BigUint<M>,
+    pub endpoint: ManagedBuffer<M>,
+    /// Top-encoded arguments, passed to the endpoint as they are
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub eta_block: u64,
+    pub status: OperationStatus,
+}
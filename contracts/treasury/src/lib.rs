@@ -0,0 +1,328 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Treasury Smart Contract
+/// Shared vault for the suite: game instances send their fees here in EGLD or any fungible
+/// ESDT. Allowlisted spenders file spend requests, which only the governance contract can
+/// execute, and each token's payouts are capped per period.
+#[multiversx_sc::contract]
+pub trait Treasury {
+    #[init]
+    fn init(&self, governance: ManagedAddress, cap_period_blocks: u64) {
+        require!(cap_period_blocks > 0, "Cap period must be positive");
+        self.governance().set(&governance);
+        self.cap_period_blocks().set(cap_period_blocks);
+    }
+
+    #[only_owner]
+    #[endpoint(setGovernance)]
+    fn set_governance(&self, governance: ManagedAddress) {
+        self.governance().set(&governance);
+
+        self.governance_set_event(&governance);
+    }
+
+    /// Allow `spender` to file spend requests
+    #[endpoint(addSpender)]
+    fn add_spender(&self, spender: ManagedAddress) {
+        self.require_owner_or_governance();
+        require!(self.spenders().insert(spender.clone()), "Already a spender");
+
+        self.spender_added_event(&spender);
+    }
+
+    #[endpoint(removeSpender)]
+    fn remove_spender(&self, spender: ManagedAddress) {
+        self.require_owner_or_governance();
+        require!(self.spenders().swap_remove(&spender), "Not a spender");
+
+        self.spender_removed_event(&spender);
+    }
+
+    /// Most of `token` that executed spends may pay out per period; tokens without a cap
+    /// cannot be spent
+    #[endpoint(setSpendingCap)]
+    fn set_spending_cap(&self, token: EgldOrEsdtTokenIdentifier, cap: BigUint) {
+        self.require_owner_or_governance();
+        self.spending_cap(&token).set(&cap);
+
+        self.spending_cap_set_event(&token, &cap);
+    }
+
+    /// Receive fees from a game instance or any other source
+    #[payable("*")]
+    #[endpoint(depositFees)]
+    fn deposit_fees(&self) {
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0, "Only fungible tokens accepted");
+        require!(amount > 0, "Must send tokens");
+
+        let source = self.blockchain().get_caller();
+        self.tokens().insert(token.clone());
+        self.total_received(&token).update(|total| *total += &amount);
+        self.received_from(&source, &token).update(|total| *total += &amount);
+
+        self.fees_received_event(&token, &source, &amount);
+    }
+
+    /// File a request to pay `amount` of `token` to `recipient`, pending until governance
+    /// executes or rejects it
+    #[endpoint(requestSpend)]
+    fn request_spend(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        recipient: ManagedAddress,
+        description: ManagedBuffer,
+    ) -> u32 {
+        let caller = self.blockchain().get_caller();
+        require!(self.spenders().contains(&caller), "Not a spender");
+        require!(amount > 0, "Amount must be positive");
+
+        let request_id = self.last_request_id().get() + 1;
+        let request = SpendRequest {
+            id: request_id,
+            spender: caller,
+            token,
+            amount,
+            recipient,
+            description,
+            requested_block: self.blockchain().get_block_nonce(),
+            status: SpendStatus::Pending,
+        };
+        self.spend_requests(request_id).set(&request);
+        self.pending_requests().insert(request_id);
+        self.last_request_id().set(request_id);
+
+        self.spend_requested_event(request_id, &request.spender, &request.token, &request.amount);
+
+        request_id
+    }
+
+    /// Pay out a pending request within the token's cap for the current period
+    #[endpoint(executeSpend)]
+    fn execute_spend(&self, request_id: u32) {
+        self.require_governance();
+        let mut request = self.pending_request(request_id);
+
+        let cap_mapper = self.spending_cap(&request.token);
+        require!(!cap_mapper.is_empty(), "No spending cap for token");
+        let period = self.current_period();
+        let spent_mapper = self.period_spent(&request.token, period);
+        let spent = spent_mapper.get() + &request.amount;
+        require!(spent <= cap_mapper.get(), "Spending cap exceeded");
+        require!(request.amount <= self.balance(&request.token), "Insufficient balance");
+
+        spent_mapper.set(&spent);
+        self.total_spent(&request.token).update(|total| *total += &request.amount);
+        self.pending_requests().swap_remove(&request_id);
+        request.status = SpendStatus::Executed;
+        self.spend_requests(request_id).set(&request);
+
+        self.send().direct(&request.recipient, &request.token, 0, &request.amount);
+
+        self.spend_executed_event(request_id, &request.recipient, &request.amount);
+    }
+
+    #[endpoint(rejectSpend)]
+    fn reject_spend(&self, request_id: u32) {
+        self.require_governance();
+        let mut request = self.pending_request(request_id);
+
+        self.pending_requests().swap_remove(&request_id);
+        request.status = SpendStatus::Rejected;
+        self.spend_requests(request_id).set(&request);
+
+        self.spend_rejected_event(request_id);
+    }
+
+    // View functions
+    #[view(getGovernance)]
+    fn get_governance(&self) -> ManagedAddress {
+        self.governance().get()
+    }
+
+    #[view(getTokenAccounting)]
+    fn get_token_accounting(&self, token: EgldOrEsdtTokenIdentifier) -> TokenAccounting<Self::Api> {
+        let period = self.current_period();
+        TokenAccounting {
+            balance: self.balance(&token),
+            total_received: self.total_received(&token).get(),
+            total_spent: self.total_spent(&token).get(),
+            spending_cap: self.spending_cap(&token).get(),
+            spent_this_period: self.period_spent(&token, period).get(),
+        }
+    }
+
+    /// Every token the treasury has received
+    #[view(getTokens)]
+    fn get_tokens(&self) -> MultiValueEncoded<EgldOrEsdtTokenIdentifier> {
+        self.tokens().iter().collect()
+    }
+
+    #[view(getReceivedFrom)]
+    fn get_received_from(&self, source: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.received_from(&source, &token).get()
+    }
+
+    #[view(getSpenders)]
+    fn get_spenders(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.spenders().iter().collect()
+    }
+
+    #[view(getSpendRequest)]
+    fn get_spend_request(&self, request_id: u32) -> OptionalValue<SpendRequest<Self::Api>> {
+        if self.spend_requests(request_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.spend_requests(request_id).get())
+    }
+
+    #[view(getPendingRequests)]
+    fn get_pending_requests(&self) -> MultiValueEncoded<u32> {
+        self.pending_requests().iter().collect()
+    }
+
+    // Private functions
+    fn require_governance(&self) {
+        require!(
+            self.blockchain().get_caller() == self.governance().get(),
+            "Only governance"
+        );
+    }
+
+    fn require_owner_or_governance(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.governance().get() || caller == self.blockchain().get_owner_address(),
+            "Only owner or governance"
+        );
+    }
+
+    fn pending_request(&self, request_id: u32) -> SpendRequest<Self::Api> {
+        require!(!self.spend_requests(request_id).is_empty(), "Request does not exist");
+        let request = self.spend_requests(request_id).get();
+        require!(request.status == SpendStatus::Pending, "Request not pending");
+        request
+    }
+
+    fn balance(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.blockchain().get_sc_balance(token, 0)
+    }
+
+    fn current_period(&self) -> u64 {
+        self.blockchain().get_block_nonce() / self.cap_period_blocks().get()
+    }
+
+    // Storage
+    #[storage_mapper("governance")]
+    fn governance(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("cap_period_blocks")]
+    fn cap_period_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("spenders")]
+    fn spenders(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("tokens")]
+    fn tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("total_received")]
+    fn total_received(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("received_from")]
+    fn received_from(
+        &self,
+        source: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_spent")]
+    fn total_spent(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("spending_cap")]
+    fn spending_cap(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("period_spent")]
+    fn period_spent(&self, token: &EgldOrEsdtTokenIdentifier, period: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("last_request_id")]
+    fn last_request_id(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("spend_requests")]
+    fn spend_requests(&self, request_id: u32) -> SingleValueMapper<SpendRequest<Self::Api>>;
+
+    #[storage_mapper("pending_requests")]
+    fn pending_requests(&self) -> UnorderedSetMapper<u32>;
+
+    // Events
+    #[event("governance_set")]
+    fn governance_set_event(&self, #[indexed] governance: &ManagedAddress);
+
+    #[event("spender_added")]
+    fn spender_added_event(&self, #[indexed] spender: &ManagedAddress);
+
+    #[event("spender_removed")]
+    fn spender_removed_event(&self, #[indexed] spender: &ManagedAddress);
+
+    #[event("spending_cap_set")]
+    fn spending_cap_set_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier, cap: &BigUint);
+
+    #[event("fees_received")]
+    fn fees_received_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] source: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("spend_requested")]
+    fn spend_requested_event(
+        &self,
+        #[indexed] request_id: u32,
+        #[indexed] spender: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("spend_executed")]
+    fn spend_executed_event(
+        &self,
+        #[indexed] request_id: u32,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("spend_rejected")]
+    fn spend_rejected_event(&self, #[indexed] request_id: u32);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpendStatus {
+    Pending,
+    Executed,
+    Rejected,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct SpendRequest<M: ManagedTypeApi> {
+    pub id: u32,
+    pub spender: ManagedAddress<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub recipient: ManagedAddress<M>,
+    pub description: ManagedBuffer<M>,
+    pub requested_block: u64,
+    pub status: SpendStatus,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct TokenAccounting<M: ManagedTypeApi> {
+    pub balance: BigUint<M>,
+    pub total_received: BigUint<M>,
+    pub total_spent: BigUint<M>,
+    pub spending_cap: BigUint<M>,
+    pub spent_this_period: BigUint<M>,
+}
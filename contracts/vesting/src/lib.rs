@@ -0,0 +1,253 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Upper bound on grants created in one call
+pub const MAX_GRANTS_PER_CALL: usize = 50;
+
+/// Vesting Smart Contract
+/// Locks governance tokens for the team and long-vested game prizes in per-beneficiary grants
+/// that unlock linearly after a cliff. The owner and allowlisted grantors, such as game
+/// instances paying out prizes, fund grants in bulk. Revocable grants can be cut short by their
+/// grantor, who gets back whatever has not vested yet.
+#[multiversx_sc::contract]
+pub trait Vesting {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(addGrantor)]
+    fn add_grantor(&self, grantor: ManagedAddress) {
+        require!(self.grantors().insert(grantor.clone()), "Already a grantor");
+
+        self.grantor_added_event(&grantor);
+    }
+
+    #[only_owner]
+    #[endpoint(removeGrantor)]
+    fn remove_grantor(&self, grantor: ManagedAddress) {
+        require!(self.grantors().swap_remove(&grantor), "Not a grantor");
+
+        self.grantor_removed_event(&grantor);
+    }
+
+    /// Create one grant per `(beneficiary, amount, start_block, cliff_blocks, duration_blocks)`
+    /// entry, funded by the payment, which must equal the sum of the amounts. Nothing vests
+    /// before `start_block + cliff_blocks`; then the grant is vested pro rata from `start_block`
+    /// until fully vested at `start_block + duration_blocks`.
+    #[payable("*")]
+    #[endpoint(createGrants)]
+    fn create_grants(
+        &self,
+        revocable: bool,
+        grants: MultiValueEncoded<MultiValue5<ManagedAddress, BigUint, u64, u64, u64>>,
+    ) -> MultiValueEncoded<u64> {
+        let grantor = self.blockchain().get_caller();
+        require!(
+            grantor == self.blockchain().get_owner_address() || self.grantors().contains(&grantor),
+            "Only owner or grantors"
+        );
+        require!(!grants.is_empty(), "No grants");
+        require!(grants.len() <= MAX_GRANTS_PER_CALL, "Too many grants in one call");
+
+        let (token, nonce, payment) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0, "Only fungible tokens accepted");
+
+        let mut total = BigUint::zero();
+        let mut grant_ids = MultiValueEncoded::new();
+        for entry in grants {
+            let (beneficiary, amount, start_block, cliff_blocks, duration_blocks) = entry.into_tuple();
+            require!(amount > 0, "Amount must be positive");
+            require!(duration_blocks > 0, "Duration must be positive");
+            require!(cliff_blocks <= duration_blocks, "Cliff longer than duration");
+
+            total += &amount;
+            let grant_id = self.last_grant_id().get() + 1;
+            let grant = Grant {
+                id: grant_id,
+                beneficiary: beneficiary.clone(),
+                grantor: grantor.clone(),
+                token: token.clone(),
+                amount,
+                claimed: BigUint::zero(),
+                start_block,
+                cliff_block: start_block + cliff_blocks,
+                end_block: start_block + duration_blocks,
+                revocable,
+                revoked: false,
+            };
+            self.grants(grant_id).set(&grant);
+            self.beneficiary_grants(&beneficiary).insert(grant_id);
+            self.last_grant_id().set(grant_id);
+
+            self.grant_created_event(grant_id, &beneficiary, &grantor, &grant.amount);
+            grant_ids.push(grant_id);
+        }
+        require!(payment == total, "Payment must equal the granted total");
+
+        grant_ids
+    }
+
+    /// Transfer everything vested and not yet claimed to the beneficiary
+    #[endpoint(claim)]
+    fn claim(&self, grant_id: u64) -> BigUint {
+        let mut grant = self.existing_grant(grant_id);
+        require!(
+            self.blockchain().get_caller() == grant.beneficiary,
+            "Only the beneficiary"
+        );
+
+        let claimable = self.vested_amount(&grant) - &grant.claimed;
+        require!(claimable > 0, "Nothing to claim");
+        grant.claimed += &claimable;
+        self.grants(grant_id).set(&grant);
+
+        self.send().direct(&grant.beneficiary, &grant.token, 0, &claimable);
+
+        self.claimed_event(grant_id, &grant.beneficiary, &claimable);
+
+        claimable
+    }
+
+    /// Stop a revocable grant: what has vested stays claimable by the beneficiary and the rest
+    /// returns to the grantor
+    #[endpoint(revokeGrant)]
+    fn revoke_grant(&self, grant_id: u64) {
+        let mut grant = self.existing_grant(grant_id);
+        require!(
+            self.blockchain().get_caller() == grant.grantor,
+            "Only the grantor"
+        );
+        require!(grant.revocable, "Grant not revocable");
+        require!(!grant.revoked, "Grant already revoked");
+
+        let vested = self.vested_amount(&grant);
+        let unvested = &grant.amount - &vested;
+        grant.amount = vested;
+        grant.revoked = true;
+        self.grants(grant_id).set(&grant);
+
+        if unvested > 0 {
+            self.send().direct(&grant.grantor, &grant.token, 0, &unvested);
+        }
+
+        self.grant_revoked_event(grant_id, &unvested);
+    }
+
+    // View functions
+    #[view(getGrant)]
+    fn get_grant(&self, grant_id: u64) -> OptionalValue<Grant<Self::Api>> {
+        if self.grants(grant_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.grants(grant_id).get())
+    }
+
+    #[view(getGrantIds)]
+    fn get_grant_ids(&self, beneficiary: ManagedAddress) -> MultiValueEncoded<u64> {
+        self.beneficiary_grants(&beneficiary).iter().collect()
+    }
+
+    #[view(getClaimable)]
+    fn get_claimable(&self, grant_id: u64) -> BigUint {
+        let grant = self.existing_grant(grant_id);
+        self.vested_amount(&grant) - &grant.claimed
+    }
+
+    /// Grants of `beneficiary` with something vested and not yet claimed, as
+    /// `(grant_id, token, claimable)`
+    #[view(getClaimableGrants)]
+    fn get_claimable_grants(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<u64, EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for grant_id in self.beneficiary_grants(&beneficiary).iter() {
+            let grant = self.grants(grant_id).get();
+            let claimable = self.vested_amount(&grant) - &grant.claimed;
+            if claimable > 0 {
+                result.push((grant_id, grant.token, claimable).into());
+            }
+        }
+        result
+    }
+
+    #[view(getGrantors)]
+    fn get_grantors(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.grantors().iter().collect()
+    }
+
+    // Private functions
+    fn existing_grant(&self, grant_id: u64) -> Grant<Self::Api> {
+        require!(!self.grants(grant_id).is_empty(), "Grant does not exist");
+        self.grants(grant_id).get()
+    }
+
+    /// Amount of `grant` vested at the current block. Revoking freezes the amount at what had
+    /// vested, so a revoked grant is fully vested.
+    fn vested_amount(&self, grant: &Grant<Self::Api>) -> BigUint {
+        let current_block = self.blockchain().get_block_nonce();
+        if grant.revoked || current_block >= grant.end_block {
+            return grant.amount.clone();
+        }
+        if current_block < grant.cliff_block {
+            return BigUint::zero();
+        }
+
+        let elapsed = current_block - grant.start_block;
+        let duration = grant.end_block - grant.start_block;
+        &grant.amount * &BigUint::from(elapsed) / duration
+    }
+
+    // Storage
+    #[storage_mapper("grantors")]
+    fn grantors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("last_grant_id")]
+    fn last_grant_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("grants")]
+    fn grants(&self, grant_id: u64) -> SingleValueMapper<Grant<Self::Api>>;
+
+    #[storage_mapper("beneficiary_grants")]
+    fn beneficiary_grants(&self, beneficiary: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    // Events
+    #[event("grantor_added")]
+    fn grantor_added_event(&self, #[indexed] grantor: &ManagedAddress);
+
+    #[event("grantor_removed")]
+    fn grantor_removed_event(&self, #[indexed] grantor: &ManagedAddress);
+
+    #[event("grant_created")]
+    fn grant_created_event(
+        &self,
+        #[indexed] grant_id: u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        #[indexed] grantor: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("claimed")]
+    fn claimed_event(&self, #[indexed] grant_id: u64, #[indexed] beneficiary: &ManagedAddress, amount: &BigUint);
+
+    #[event("grant_revoked")]
+    fn grant_revoked_event(&self, #[indexed] grant_id: u64, returned: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Grant<M: ManagedTypeApi> {
+    pub id: u64,
+    pub beneficiary: ManagedAddress<M>,
+    pub grantor: ManagedAddress<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    /// Granted total; once revoked, the part that had vested
+    pub amount: BigUint<M>,
+    pub claimed: BigUint<M>,
+    pub start_block: u64,
+    pub cliff_block: u64,
+    pub end_block: u64,
+    pub revocable: bool,
+    pub revoked: bool,
+}
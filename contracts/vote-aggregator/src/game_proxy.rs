@@ -0,0 +1,9 @@
+multiversx_sc::imports!();
+
+/// Endpoint a quantum-dao game exposes to its vote aggregators
+#[multiversx_sc::proxy]
+pub trait Game {
+    #[payable("EGLD")]
+    #[endpoint(submitAggregatedVotes)]
+    fn submit_aggregated_votes(&self, votes: MultiValueEncoded<MultiValue4<ManagedAddress, u32, bool, BigUint>>);
+}
@@ -0,0 +1,230 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+pub mod game_proxy;
+
+/// Upper bound on votes forwarded in one batch, within the game's own batch limit
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// Vote Aggregator Smart Contract
+/// Deployed on a shard other than the game's, so players living there vote with a local,
+/// intra-shard transaction. Votes and their stakes queue here until anyone forwards them to the
+/// game in one async call per batch; the game must list this contract as a vote aggregator.
+///
+/// The game records every vote for its player, so stakes and prizes later go to players
+/// directly. A batch the game rejects is rejected whole: its EGLD comes back and each player's
+/// stake becomes refundable here.
+#[multiversx_sc::contract]
+pub trait VoteAggregator {
+    #[init]
+    fn init(&self, game: ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(&game), "Game must be a contract");
+        self.game().set(&game);
+    }
+
+    /// Queue a vote on `proposal_id` with the EGLD sent as its stake
+    #[payable("EGLD")]
+    #[endpoint(castVote)]
+    fn cast_vote(&self, proposal_id: u32, vote_for: bool) {
+        let player = self.blockchain().get_caller();
+        let stake = self.call_value().egld_value().clone_value();
+        require!(stake > 0, "Must stake EGLD to vote");
+        require!(
+            self.pending_proposals(&player).insert(proposal_id),
+            "Vote already pending"
+        );
+
+        self.pending_votes().push_back(AggregatedVote {
+            player: player.clone(),
+            proposal_id,
+            vote_for,
+            stake: stake.clone(),
+        });
+
+        self.vote_queued_event(&player, proposal_id, vote_for, &stake);
+    }
+
+    /// Send up to `MAX_BATCH_SIZE` queued votes to the game, oldest first; open to anyone
+    #[endpoint(forwardVotes)]
+    fn forward_votes(&self) {
+        let mut pending_votes = self.pending_votes();
+        require!(!pending_votes.is_empty(), "No pending votes");
+
+        let batch_id = self.last_batch_id().get() + 1;
+        let mut votes = MultiValueEncoded::new();
+        let mut total_stake = BigUint::zero();
+        let mut vote_count = 0usize;
+        while vote_count < MAX_BATCH_SIZE {
+            let vote = match pending_votes.pop_front() {
+                Some(vote) => vote,
+                None => break,
+            };
+
+            total_stake += &vote.stake;
+            votes.push((vote.player.clone(), vote.proposal_id, vote.vote_for, vote.stake.clone()).into());
+            self.batch_votes(batch_id).push(&vote);
+            vote_count += 1;
+        }
+        self.batches(batch_id).set(Batch {
+            id: batch_id,
+            vote_count,
+            total_stake: total_stake.clone(),
+            status: BatchStatus::InFlight,
+        });
+        self.last_batch_id().set(batch_id);
+
+        self.batch_forwarded_event(batch_id, vote_count, &total_stake);
+
+        self.game_proxy(self.game().get())
+            .submit_aggregated_votes(votes)
+            .with_egld_transfer(total_stake)
+            .async_call()
+            .with_callback(self.callbacks().forward_callback(batch_id))
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn forward_callback(&self, batch_id: u64, #[call_result] result: ManagedAsyncCallResult<()>) {
+        let mut batch = self.batches(batch_id).get();
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                batch.status = BatchStatus::Accepted;
+                self.batch_accepted_event(batch_id);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                batch.status = BatchStatus::Rejected;
+                self.batch_rejected_event(batch_id, &err.err_msg);
+            },
+        }
+        self.batches(batch_id).set(&batch);
+
+        for vote in self.batch_votes(batch_id).iter() {
+            self.pending_proposals(&vote.player).swap_remove(&vote.proposal_id);
+            if batch.status == BatchStatus::Rejected {
+                self.refunds(&vote.player).update(|refund| *refund += &vote.stake);
+            }
+        }
+    }
+
+    /// Withdraw the caller's stakes from batches the game rejected
+    #[endpoint(withdrawRefund)]
+    fn withdraw_refund(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let refund = self.refunds(&caller).take();
+        require!(refund > 0, "Nothing to refund");
+
+        self.send().direct_egld(&caller, &refund);
+
+        self.refund_withdrawn_event(&caller, &refund);
+
+        refund
+    }
+
+    // View functions
+    #[view(getGame)]
+    fn get_game(&self) -> ManagedAddress {
+        self.game().get()
+    }
+
+    #[view(getPendingVoteCount)]
+    fn get_pending_vote_count(&self) -> usize {
+        self.pending_votes().len()
+    }
+
+    /// Proposals `player` has a vote queued or in flight on
+    #[view(getPendingProposals)]
+    fn get_pending_proposals(&self, player: ManagedAddress) -> MultiValueEncoded<u32> {
+        self.pending_proposals(&player).iter().collect()
+    }
+
+    #[view(getBatch)]
+    fn get_batch(&self, batch_id: u64) -> OptionalValue<Batch<Self::Api>> {
+        if self.batches(batch_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.batches(batch_id).get())
+    }
+
+    #[view(getBatchVotes)]
+    fn get_batch_votes(&self, batch_id: u64) -> MultiValueEncoded<AggregatedVote<Self::Api>> {
+        self.batch_votes(batch_id).iter().collect()
+    }
+
+    #[view(getRefund)]
+    fn get_refund(&self, player: ManagedAddress) -> BigUint {
+        self.refunds(&player).get()
+    }
+
+    // Storage
+    #[storage_mapper("game")]
+    fn game(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pending_votes")]
+    fn pending_votes(&self) -> QueueMapper<AggregatedVote<Self::Api>>;
+
+    #[storage_mapper("pending_proposals")]
+    fn pending_proposals(&self, player: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("last_batch_id")]
+    fn last_batch_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("batches")]
+    fn batches(&self, batch_id: u64) -> SingleValueMapper<Batch<Self::Api>>;
+
+    #[storage_mapper("batch_votes")]
+    fn batch_votes(&self, batch_id: u64) -> VecMapper<AggregatedVote<Self::Api>>;
+
+    #[storage_mapper("refunds")]
+    fn refunds(&self, player: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[proxy]
+    fn game_proxy(&self, sc_address: ManagedAddress) -> game_proxy::Proxy<Self::Api>;
+
+    // Events
+    #[event("vote_queued")]
+    fn vote_queued_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] proposal_id: u32,
+        #[indexed] vote_for: bool,
+        stake: &BigUint,
+    );
+
+    #[event("batch_forwarded")]
+    fn batch_forwarded_event(&self, #[indexed] batch_id: u64, #[indexed] vote_count: usize, total_stake: &BigUint);
+
+    #[event("batch_accepted")]
+    fn batch_accepted_event(&self, #[indexed] batch_id: u64);
+
+    #[event("batch_rejected")]
+    fn batch_rejected_event(&self, #[indexed] batch_id: u64, err_msg: &ManagedBuffer);
+
+    #[event("refund_withdrawn")]
+    fn refund_withdrawn_event(&self, #[indexed] player: &ManagedAddress, amount: &BigUint);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchStatus {
+    /// Sent to the game, waiting for its callback
+    InFlight,
+    Accepted,
+    Rejected,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct AggregatedVote<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub proposal_id: u32,
+    pub vote_for: bool,
+    pub stake: BigUint<M>,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct Batch<M: ManagedTypeApi> {
+    pub id: u64,
+    pub vote_count: usize,
+    pub total_stake: BigUint<M>,
+    pub status: BatchStatus,
+}